@@ -99,6 +99,14 @@ pub enum OutputFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum StatuslineFormat {
+    /// Single styled line
+    Text,
+    /// JSON object, as for the branch in `wt list --format=json`
+    Json,
+}
+
 #[derive(Parser)]
 #[command(name = "wt")]
 #[command(about = "Git worktree management", long_about = None)]
@@ -403,14 +411,15 @@ pub enum CacheCommand {
 
 - **Default branch**: Cached result of querying remote for default branch
 - **CI status**: Cached GitHub/GitLab CI status per branch (30s TTL)
+- **Statusline**: Status per branch for `--cached`, written by its background refresh
 
 CI cache entries show status, age, and the commit SHA they were fetched for."#)]
     Show,
 
     /// Clear cached data
     Clear {
-        /// Cache type: 'ci', 'default-branch', or 'logs' (default: all)
-        #[arg(value_parser = ["ci", "default-branch", "logs"])]
+        /// Cache type: 'ci', 'default-branch', 'statusline', or 'logs' (default: all)
+        #[arg(value_parser = ["ci", "default-branch", "statusline", "logs"])]
         cache_type: Option<String>,
     },
 
@@ -563,6 +572,13 @@ pub enum StepCommand {
         /// What to stage before committing [default: all]
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
+
+        /// Review the generated message before committing
+        ///
+        /// Accept it, edit it, regenerate it, or abort. Also enabled by
+        /// `commit.review = true` in the user config.
+        #[arg(long)]
+        edit: bool,
     },
 
     /// Squash commits down to target
@@ -589,12 +605,56 @@ pub enum StepCommand {
         /// What to stage before committing [default: all]
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
+
+        /// Review the generated message before committing
+        ///
+        /// Accept it, edit it, regenerate it, or abort. Also enabled by
+        /// `commit.review = true` in the user config.
+        #[arg(long)]
+        edit: bool,
+    },
+
+    /// Write a PR description with an LLM
+    ///
+    /// Generates a pull request title and markdown body (summary, testing,
+    /// risks) from the commits and diff since the target branch. Prints it,
+    /// writes it to a file, or opens the PR with `gh pr create`.
+    #[command(after_long_help = r#"## Examples
+
+Print the title and description:
+```console
+wt step describe
+```
+
+Write them to a file, or open the pull request with the GitHub CLI:
+```console
+wt step describe --output pr.md
+wt step describe --pr
+```
+
+The prompt comes from `pr-template` in `[commit-generation]`; see [LLM Commit Messages](@/llm-commits.md#pr-descriptions)."#)]
+    Describe {
+        /// Target branch
+        ///
+        /// Defaults to default branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        target: Option<String>,
+
+        /// Write the description to a file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<std::path::PathBuf>,
+
+        /// Open a pull request with `gh pr create`
+        #[arg(long)]
+        pr: bool,
     },
 
     /// Push changes to local target branch
     ///
     /// Automatically stashes non-conflicting edits in the target worktree before
     /// the push and restores them afterward so other agents' changes stay intact.
+    /// Runs pre-push hooks right before the target branch is updated unless
+    /// `--no-verify` is passed.
     Push {
         /// Target branch
         ///
@@ -605,6 +665,14 @@ pub enum StepCommand {
         /// Allow merge commits
         #[arg(long)]
         allow_merge_commits: bool,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        force: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
     },
 
     /// Rebase onto target
@@ -615,6 +683,66 @@ pub enum StepCommand {
         #[arg(add = crate::completion::branch_value_completer())]
         target: Option<String>,
     },
+
+    /// Show or change the sparse checkout
+    ///
+    /// Without a subcommand, lists the directories checked out in the current
+    /// worktree. New worktrees get the patterns from the `[sparse]` config.
+    #[command(after_long_help = r#"## Examples
+
+Show the checked-out directories:
+```console
+wt step sparse
+```
+
+Widen, then narrow:
+```console
+wt step sparse add libs/common
+wt step sparse remove services/legacy
+```
+
+Go back to the configured patterns, or to a full checkout:
+```console
+wt step sparse reset
+wt step sparse disable
+```
+
+See [Sparse checkout](@/step.md#sparse-checkout) for the configuration."#)]
+    Sparse {
+        #[command(subcommand)]
+        action: Option<SparseCommand>,
+    },
+}
+
+/// Change the sparse checkout of the current worktree
+#[derive(Subcommand)]
+pub enum SparseCommand {
+    /// Check out more directories
+    Add {
+        /// Directories to add
+        #[arg(required = true)]
+        paths: Vec<String>,
+    },
+
+    /// Stop checking out directories
+    Remove {
+        /// Directories to remove
+        #[arg(required = true)]
+        paths: Vec<String>,
+    },
+
+    /// Check out only these directories
+    Set {
+        /// Directories to check out
+        #[arg(required = true)]
+        paths: Vec<String>,
+    },
+
+    /// Apply the configured patterns again
+    Reset,
+
+    /// Check out all files
+    Disable,
 }
 
 /// Run hooks independently
@@ -626,7 +754,7 @@ pub enum HookCommand {
     /// Project hooks show approval status (❓ = needs approval).
     Show {
         /// Hook type to show (default: all)
-        #[arg(value_parser = ["post-create", "post-start", "pre-commit", "pre-merge", "post-merge", "pre-remove"])]
+        #[arg(value_parser = ["pre-create", "post-create", "post-start", "pre-switch", "post-switch", "pre-commit", "pre-merge", "pre-push", "post-merge", "pre-remove", "post-remove"])]
         hook_type: Option<String>,
 
         /// Show expanded commands with current variables
@@ -634,6 +762,98 @@ pub enum HookCommand {
         expanded: bool,
     },
 
+    /// Show logs of background commands
+    ///
+    /// Lists logs of post-start commands and background removals per branch,
+    /// with size and age. Each run writes a new log; previous runs are kept
+    /// according to the `[logs]` user config.
+    #[command(after_long_help = r#"## Examples
+
+List all logs:
+```console
+wt hook logs
+```
+
+Logs of one command on a branch:
+```console
+wt hook logs npm --branch feature
+```
+
+Follow the latest log of a command on the current branch:
+```console
+wt hook logs dev-server --follow
+```
+
+## Retention
+
+Logs live in `.git/wt-logs/`. By default, five runs per command are kept
+(the latest as `{branch}-{operation}.log`, older ones as `.log.1`, `.log.2`, …),
+capped at 10MB of previous runs. Configure in the user config:
+
+```toml
+[logs]
+keep = 10
+max-size = "50MB"
+```
+
+Clear all logs with `wt config cache clear logs`."#)]
+    Logs {
+        /// Only show logs of this command (e.g. `npm`, `remove`)
+        name: Option<String>,
+
+        /// Only show logs for this branch
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+
+        /// Print the log and keep printing new output
+        #[arg(short, long)]
+        follow: bool,
+    },
+
+    /// Stop a running background command
+    ///
+    /// Sends SIGTERM to a post-start command (and everything it started) on the
+    /// current branch, or on `--branch`.
+    #[command(after_long_help = r#"## Examples
+
+Stop the dev server of the current worktree:
+```console
+wt hook stop dev-server
+```
+
+Stop it on another branch:
+```console
+wt hook stop dev-server --branch feature
+```
+
+## Status
+
+Background commands record their PID, start time and exit code next to their
+log in `.git/wt-logs/`. `wt hook logs` shows each command's status (running,
+finished, failed with its exit code, or stopped), and `wt list` summarizes them
+in its Jobs column."#)]
+    Stop {
+        /// Command name (e.g. `npm`)
+        name: String,
+
+        /// Branch whose command to stop (default: current)
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+
+    /// Run pre-create hooks
+    ///
+    /// Executes validation commands before worktree creation.
+    PreCreate {
+        /// Run only this command from hook config
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Run post-create hooks
     ///
     /// Executes blocking commands after worktree creation.
@@ -660,6 +880,32 @@ pub enum HookCommand {
         force: bool,
     },
 
+    /// Run pre-switch hooks
+    ///
+    /// Executes commands before switching worktrees.
+    PreSwitch {
+        /// Run only this command from hook config
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Run post-switch hooks
+    ///
+    /// Executes commands after switching worktrees.
+    PostSwitch {
+        /// Run only this command from hook config
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Run pre-commit hooks
     ///
     /// Executes validation commands before committing.
@@ -686,6 +932,19 @@ pub enum HookCommand {
         force: bool,
     },
 
+    /// Run pre-push hooks
+    ///
+    /// Executes validation commands before pushing to the target branch.
+    PrePush {
+        /// Run only this command from hook config
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Run post-merge hooks
     ///
     /// Executes commands after successful merge.
@@ -712,6 +971,19 @@ pub enum HookCommand {
         force: bool,
     },
 
+    /// Run post-remove hooks
+    ///
+    /// Executes cleanup commands after worktree removal.
+    PostRemove {
+        /// Run only this command from hook config
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Manage command approvals
     #[command(after_long_help = r#"## How Approvals Work
 
@@ -763,13 +1035,40 @@ pub enum ListSubcommand {
     ///
     /// Designed for shell prompts, starship, or editor integrations.
     /// Uses same collection infrastructure as `wt list`.
+    /// Set `[statusline] template` in user config to choose the fields.
+    /// For synchronous prompts, `--cached --format json` returns immediately.
     Statusline {
-        /// Claude Code mode: read context from stdin, add directory and model
+        /// Claude Code mode: same as `--context claude-code`
         ///
         /// Reads JSON from stdin with `.workspace.current_dir` and `.model.display_name`.
         /// Output: `dir  branch  status  ±working  commits  upstream  ci  | model`
-        #[arg(long)]
+        ///
+        /// The branch is omitted when the directory is the branch's `worktree-path`.
+        #[arg(long, conflicts_with = "context")]
         claude_code: bool,
+
+        /// Agent mode: read context from stdin with this reader, add directory and model
+        ///
+        /// Built-in reader: `claude-code`. Map other agents' payloads under
+        /// `[statusline.context.<name>]` in user config.
+        #[arg(long, value_name = "READER")]
+        context: Option<String>,
+
+        /// Print the last cached status, refreshing it in the background
+        ///
+        /// Returns without running git or CI lookups, for prompts in large repos.
+        /// A cache older than 10 seconds is refreshed in the background, and
+        /// shows on the next render.
+        #[arg(long)]
+        cached: bool,
+
+        /// Collect the status and cache it for `--cached` (run by the background refresh)
+        #[arg(long, hide = true, conflicts_with = "cached")]
+        refresh: bool,
+
+        /// Output format (text, json)
+        #[arg(long, value_enum, default_value = "text", hide_possible_values = true)]
+        format: StatuslineFormat,
     },
 }
 
@@ -860,6 +1159,7 @@ remotes = true   # --remotes
 ```toml
 [commit]
 stage = "all"    # "all" (default), "tracked", or "none"
+review = false   # Review generated messages before committing (like --edit)
 ```
 
 **`wt merge`:**
@@ -873,9 +1173,36 @@ remove = false  # Keep worktree after merge (--no-remove)
 verify = false  # Skip hooks (--no-verify)
 ```
 
+**`wt select`:**
+
+```toml
+[select]
+preview = "log"                        # First tab (default: "uncommitted")
+layout = "down"                        # "auto" (default), "right", or "down"
+height = "100%"                        # Rows or percentage (default: "90%")
+colors = "current_bg:237,current:252"  # Skim colors over the defaults
+
+[select.keys]
+remove = "ctrl-x"                      # Any key of the selector's legend
+```
+
+**`wt list statusline`:**
+
+```toml
+[statusline]
+# minijinja template replacing the fixed layout. Variables: branch, status,
+# working, ahead, behind, commits, upstream, ci, dir, model (empty when missing)
+template = "{{ branch }}{% if working %} @{{ working }}{% endif %}{% if ci %} {{ ci }}{% endif %}"
+
+# Reader for `--context my-agent`: JSONPath-style paths into the agent's stdin JSON
+[statusline.context.my-agent]
+dir = "$.workspace.cwd"
+model = "$.model.name"
+```
+
 ### LLM commit messages
 
-Configure automatic commit message generation. Requires an external tool like [llm](https://llm.datasette.io/):
+Configure automatic commit message generation. Uses an external tool like [llm](https://llm.datasette.io/):
 
 ```toml
 [commit-generation]
@@ -883,6 +1210,15 @@ command = "llm"
 args = ["-m", "claude-haiku-4.5"]
 ```
 
+Or calls an OpenAI-compatible or Anthropic-style API directly, such as a local Ollama server:
+
+```toml
+[commit-generation]
+provider = "openai-compatible"
+endpoint = "http://localhost:11434/v1"
+model = "qwen2.5-coder"
+```
+
 See [LLM Commit Messages](@/llm-commits.md) for setup details and template customization.
 
 ### Approved commands
@@ -970,6 +1306,7 @@ For nested config sections, use double underscores to separate levels:
 | `worktree-path` | `WORKTRUNK_WORKTREE_PATH` |
 | `commit-generation.command` | `WORKTRUNK_COMMIT_GENERATION__COMMAND` |
 | `commit-generation.args` | `WORKTRUNK_COMMIT_GENERATION__ARGS` |
+| `commit-generation.model` | `WORKTRUNK_COMMIT_GENERATION__MODEL` |
 
 Note the single underscore after `WORKTRUNK` and double underscores between nested keys.
 
@@ -1022,6 +1359,17 @@ Commit with LLM-generated message:
 wt step commit
 ```
 
+Review the message before committing — accept it, edit it in your editor,
+regenerate it (optionally with a hint for the LLM), or abort:
+
+```console
+wt step commit --edit
+```
+
+An aborted commit leaves the message in `.git/WT_COMMIT_EDITMSG`
+(`.git/WT_SQUASH_EDITMSG` for squashes); the next review of the same kind
+starts from it, unless HEAD has moved since.
+
 Manual merge workflow with review between steps:
 
 ```console
@@ -1032,12 +1380,57 @@ wt step rebase
 wt step push
 ```
 
+Draft the pull request description for a branch:
+
+```console
+wt step describe --output pr.md
+```
+
 ## Operations
 
 - `commit` — Stage and commit with [LLM-generated message](@/llm-commits.md)
 - `squash` — Squash all branch commits into one with [LLM-generated message](@/llm-commits.md)
+- `describe` — Write a [PR title and description](@/llm-commits.md#pr-descriptions) for the branch
 - `rebase` — Rebase onto target branch
-- `push` — Push to target branch (default: main)
+- `push` — Push to target branch (default: main), running pre-push hooks first
+- `sparse` — Show or change the [sparse checkout](#sparse-checkout) of the worktree
+
+## Sparse checkout
+
+In large monorepos, new worktrees can check out only some directories. Configure
+them in the project config (`.config/wt.toml`):
+
+```toml
+[sparse]
+paths = ["services/api", "libs/common"]
+
+# Branches matching a pattern get other directories; the first match wins.
+# An empty list checks out everything.
+[sparse.branches]
+"web/*" = ["apps/web", "libs/common"]
+"release-*" = []
+```
+
+`wt switch --create` then adds the worktree without a checkout, applies the
+patterns, and checks out only those directories. In a partial clone
+(`git clone --filter=blob:none`), files outside them aren't downloaded either.
+Patterns are [cone-mode](https://git-scm.com/docs/git-sparse-checkout#_internalscone_mode_handling)
+directories; top-level files and `.config` are always checked out.
+
+The same settings under `[projects."<project>".sparse]` in the user config take
+precedence over the project's.
+
+Change a worktree's directories later with `wt step sparse`:
+
+```console
+wt step sparse                   # Show the checked-out directories
+wt step sparse add libs/common   # Widen
+wt step sparse remove services/legacy  # Narrow
+wt step sparse reset             # Back to the configured patterns
+wt step sparse disable           # Full checkout
+```
+
+[`wt list`](@/list.md) marks sparse worktrees with `⊡`.
 
 ## See also
 
@@ -1068,16 +1461,34 @@ wt hook pre-merge --force   # Skip approval prompts (for CI)
 
 | Hook | When | Blocking | Fail-fast |
 |------|------|----------|-----------|
+| `pre-create` | Before worktree created | Yes | Yes |
 | `post-create` | After worktree created | Yes | No |
 | `post-start` | After worktree created | No (background) | No |
+| `pre-switch` | Before every switch | Yes | Yes |
+| `post-switch` | After every switch | Yes | No |
 | `pre-commit` | Before commit during merge | Yes | Yes |
 | `pre-merge` | Before merging to target | Yes | Yes |
+| `pre-push` | Before target branch updated | Yes | Yes |
 | `post-merge` | After successful merge | Yes | No |
 | `pre-remove` | Before worktree removed | Yes | Yes |
+| `post-remove` | After worktree removed | With `--no-background` | No |
 
 **Blocking**: Command waits for hook to complete before continuing.
 **Fail-fast**: First failure aborts the operation.
 
+### pre-create
+
+Runs before a new worktree is created, in the **current worktree**, **fail-fast**. A failing command vetoes the creation: `git worktree add` never runs.
+
+`{{ worktree }}` is the path the worktree will be created at, and `{{ base }}` the base of a new branch (with `--create`).
+
+**Use cases**: Enforcing branch naming rules, checking quotas or prerequisites.
+
+```toml
+[pre-create]
+naming = "echo {{ branch }} | grep -Eq '^(feature|fix|chore)/'"
+```
+
 ### post-create
 
 Runs after worktree creation, **blocks until complete**. The worktree switch doesn't finish until these commands succeed.
@@ -1103,7 +1514,33 @@ build = "npm run build"
 server = "npm run dev"
 ```
 
-Output logged to `.git/wt-logs/{branch}-{source}-post-start-{name}.log` (source is `user` or `project`).
+Output logged to `.git/wt-logs/{branch}-{source}-post-start-{name}.log` (source is `user` or `project`). Browse logs with `wt hook logs`.
+
+### pre-switch
+
+Runs before every `wt switch` to another worktree, in the **worktree being left**, **fail-fast**. A failing command aborts the switch (and, with `--create`, the worktree creation).
+
+**Use cases**: Refusing to leave uncommitted work behind, saving editor or terminal state.
+
+```toml
+[pre-switch]
+clean = "git diff --quiet"
+```
+
+### post-switch
+
+Runs after every `wt switch` to another worktree, in the **worktree switched to**, **blocking**. Failures are logged but don't abort. On `--create`, runs after post-create and once post-start commands are spawned.
+
+**Use cases**: Refreshing `.env` symlinks, `direnv allow`, renaming the tmux window.
+
+```toml
+[post-switch]
+env = "ln -sf {{ new_worktree }}/.env ~/.config/myapp/.env"
+direnv = "direnv allow"
+tmux = "tmux rename-window {{ new_branch }}"
+```
+
+Both switch hooks get `{{ previous_branch }}` and `{{ previous_worktree }}` (the worktree being left) and `{{ new_branch }}` and `{{ new_worktree }}` (the worktree switched to). Neither runs when already in the target worktree.
 
 ### pre-commit
 
@@ -1129,6 +1566,19 @@ test = "cargo test"
 build = "cargo build --release"
 ```
 
+### pre-push
+
+Runs right before the target branch is updated, **fail-fast**. All commands must exit 0 for the push to proceed. Unlike `pre-merge`, it also runs for `wt step push`.
+
+Besides `{{ target }}`, commands get `{{ commit_range }}` (the commits being pushed, as `<target sha>..<head sha>`) and `{{ commit_count }}`.
+
+**Use cases**: Checking commit messages, limiting the size of a push.
+
+```toml
+[pre-push]
+no-wip = "! git log --format=%s {{ commit_range }} | grep -q '^WIP'"
+```
+
 ### post-merge
 
 Runs after successful merge in the **main worktree**, **best-effort**. Failures are logged but don't abort.
@@ -1150,11 +1600,26 @@ Runs before worktree removal during `wt remove`, **fail-fast**. All commands mus
 cleanup = "rm -rf /tmp/cache/{{ branch }}"
 ```
 
+### post-remove
+
+Runs after the worktree directory is gone, in the **main worktree**, **best-effort**. Failures are logged but don't abort. `{{ branch }}` and `{{ worktree }}` refer to the removed worktree.
+
+With background removal (the default), the commands run in the background once the removal succeeds; output is logged to `.git/wt-logs/{branch}-{source}-post-remove-{name}.log`. With `--no-background`, they run right after the removal.
+
+**Use cases**: Dropping per-branch databases, releasing allocated ports.
+
+```toml
+[post-remove]
+db = "dropdb --if-exists myapp_{{ branch }}"
+```
+
 ### Timing during merge
 
 - **pre-commit** — After staging, before squash commit
 - **pre-merge** — After rebase, before merge to target
+- **pre-push** — Right before the target branch is updated
 - **pre-remove** — Before removing worktree during cleanup
+- **post-remove** — After the worktree is removed (in the background, with the removal)
 - **post-merge** — After cleanup completes
 
 See [wt merge](@/merge.md#pipeline) for the complete pipeline.
@@ -1173,6 +1638,63 @@ test = "cargo test"
 build = "cargo build --release"
 ```
 
+### Parallel commands
+
+Blocking hooks run their commands one after another by default. Set `parallel = true` to run all of a hook's commands concurrently, or mark individual commands with the table form:
+
+```toml
+[pre-merge]
+parallel = true
+lint = "cargo clippy"
+test = "cargo test"
+typecheck = "npm run typecheck"
+
+[post-create]
+install = "npm ci"
+assets = { command = "npm run assets", parallel = true }
+docs = { command = "npm run docs", parallel = true }
+```
+
+Adjacent parallel commands run together; a command without `parallel` waits for everything before it. Output from parallel commands is captured and shown grouped by command once they finish. Failures are reported in declaration order — for fail-fast hooks, no later commands start.
+
+### Dependencies
+
+Commands can declare which other commands in the same hook must finish first with `needs`. A hook using `needs` runs as a dependency graph: each command starts once everything it needs has succeeded, and independent commands run concurrently.
+
+```toml
+[post-create]
+install = "npm ci"
+build = { command = "npm run build", needs = ["install"] }
+codegen = { command = "npm run codegen", needs = ["install"] }
+env = "cp .env.example .env"
+```
+
+Here `env` runs alongside `install`, then `build` and `codegen` run together. If a command fails, commands that need it are skipped. `needs` also works for `post-start`, where background commands wait for their dependencies; a dependency that is stopped or killed counts as failed. Ordering background commands requires a POSIX shell. Unknown names and cycles are reported when the config loads.
+
+### Conditions
+
+A command with `if` only runs when its expression is true. Expressions use the [template variables](#template-variables) below, unescaped, except that `branch` is the branch name as is (`hotfix/urgent`) and `changed_files` is a list of paths:
+
+```toml
+[pre-merge]
+migrate = { command = "npm run db:migrate", if = "changed_files | select('startingwith', 'migrations/')" }
+lockfile = { command = "npm ci", if = "'package-lock.json' in changed_files" }
+deploy = { command = "./deploy.sh", if = "branch is not startingwith('hotfix/')" }
+```
+
+Skipped commands are noted in the output. A command that another command `needs` counts as satisfied when skipped.
+
+### Timeouts and retries
+
+Blocking commands run until they exit unless given a `timeout` (seconds, or a duration like `"90s"`, `"5m"`, `"1h"`). When it expires, the command and everything it started are killed. `retries` re-runs a failed or timed-out command up to that many extra times (at most 100):
+
+```toml
+[post-create]
+install = { command = "npm ci", timeout = "5m", retries = 2 }
+```
+
+A timeout is handled like any other failure: fail-fast hooks (`pre-create`, `pre-switch`, `pre-commit`, `pre-merge`, `pre-push`, `pre-remove`) abort the operation with exit code 124, while `post-create` and `post-merge` warn and continue.
+
 ### Template variables
 
 Hooks can use template variables that expand at runtime:
@@ -1191,6 +1713,7 @@ Hooks can use template variables that expand at runtime:
 | `{{ remote_url }}` | git@github.com:user/repo.git | Remote URL |
 | `{{ upstream }}` | origin/feature | Upstream tracking branch |
 | `{{ target }}` | main | Target branch (merge hooks only) |
+| `{{ changed_files }}` | src/lib.rs | Files changed since the branch diverged from the target (or default) branch, one per line; only provided to commands that use it |
 
 ### JSON context
 
@@ -1385,7 +1908,7 @@ env = "cp {{ repo_root }}/.env.local .env"
 ## See also
 
 - [wt merge](@/merge.md) — Runs hooks automatically during merge
-- [wt switch](@/switch.md) — Runs post-create/post-start hooks on `--create`
+- [wt switch](@/switch.md) — Runs pre-switch/post-switch hooks, and post-create/post-start hooks on `--create`
 - [wt config](@/config.md) — Manage hook approvals
 "#
     )]
@@ -1396,10 +1919,10 @@ env = "cp {{ repo_root }}/.env.local .env"
 
     /// Interactive worktree selector
     ///
-    /// Toggle preview tabs with 1/2/3 keys. Toggle preview visibility with alt-p.
+    /// Toggle preview tabs with 1-5 keys. Toggle preview visibility with alt-p.
     #[cfg(unix)]
     #[command(
-        after_long_help = r#"Interactive worktree picker with live preview. Navigate worktrees with keyboard shortcuts and press Enter to switch.
+        after_long_help = r#"Interactive worktree picker with live preview. Navigate worktrees with keyboard shortcuts and press Enter to switch, or type a new branch name and press Alt-c to create it.
 
 <!-- demo: wt-select.gif -->
 
@@ -1418,6 +1941,8 @@ Toggle between views with number keys:
 1. **HEAD±** — Diff of uncommitted changes
 2. **log** — Recent commits; commits already on main have dimmed hashes
 3. **main…±** — Diff of all changes vs main branch
+4. **CI** — Status of each CI check and the PR link, fetched when the tab opens
+5. **logs** — End of the branch's post-start logs (see `wt hook logs`)
 
 ## Keybindings
 
@@ -1427,9 +1952,40 @@ Toggle between views with number keys:
 | `Enter` | Switch to selected worktree |
 | `Esc` | Cancel |
 | (type) | Filter worktrees |
-| `1`/`2`/`3` | Switch preview tab |
+| `1`–`5` | Switch preview tab |
 | `Alt-p` | Toggle preview panel |
 | `Ctrl-u`/`Ctrl-d` | Scroll preview up/down |
+| `Tab` | Mark/unmark worktree |
+| `Alt-c` | Create a worktree named after the query |
+| `Alt-r` | Remove marked worktrees |
+| `Alt-m` | Merge selected worktree |
+| `Alt-e` | Open marked worktrees in `$EDITOR` |
+| `Alt-y` | Copy marked worktree paths |
+
+## Acting on several worktrees
+
+Mark worktrees with `Tab`, then press an action key. With nothing marked, the action applies to the worktree under the cursor. Each worktree is processed independently — a failure on one doesn't stop the rest — and a per-worktree summary prints at the end. The current worktree is removed last.
+
+## Creating from the query
+
+`Alt-c` creates a new branch and worktree named after the query, like `wt switch --create <query>`, even when something matches. `Enter` with no matches exits without creating anything. Create hooks need approval as usual, and the shell then changes into the new worktree.
+
+## Configuration
+
+The `[select]` section of the user config sets the first preview tab, the preview placement, the height, the colors and the key bindings:
+
+```toml
+[select]
+preview = "log"
+layout = "down"
+colors = "current_bg:237,current:252"  # Darker highlight for dark terminals
+
+[select.keys]
+toggle-preview = "ctrl-p"
+remove = "ctrl-x"
+```
+
+See [wt config](@/config.md#command-settings) for every setting.
 
 ## See also
 
@@ -1485,6 +2041,7 @@ $ wt list --format=json
 | main…± | Line diffs in commits ahead of main (`--full`) |
 | Path | Worktree directory |
 | Remote⇅ | Commits ahead/behind tracking branch |
+| Jobs | Background commands (when any are tracked) |
 | CI | Pipeline status (`--full`) |
 | Commit | Short hash (8 chars) |
 | Age | Time since last commit |
@@ -1505,6 +2062,19 @@ The CI column shows GitHub/GitLab pipeline status:
 
 CI is only checked for branches that track a remote — local-only branches show blank. Any CI dot appears dimmed when there are unpushed local changes (stale status). CI indicators are clickable links to the PR page. Results are cached for 30-60 seconds; use `wt config cache` to view or clear.
 
+### Background jobs
+
+The Jobs column shows post-start commands of each worktree (see [`wt hook stop`](@/hook.md)):
+
+| Indicator | Meaning |
+|-----------|---------|
+| `●` green | Running |
+| `✗` red | Failed |
+| `■` yellow | Stopped before finishing |
+| `✓` gray | Finished |
+
+A count follows the symbol when there's more than one, e.g. `●2 ✓1`. The column only appears once a background command has run in the repository.
+
 ## Status symbols
 
 The Status column has multiple subcolumns. Within each, only the first matching symbol is shown (listed in priority order):
@@ -1521,6 +2091,7 @@ The Status column has multiple subcolumns. Within each, only the first matching
 | | `⚑` | Path doesn't match template |
 | | `⊟` | Prunable (directory missing) |
 | | `⊞` | Locked worktree |
+| | `⊡` | [Sparse checkout](@/step.md#sparse-checkout) |
 | Main | `^` | Is the main branch |
 | | `✗` | Would conflict if merged to main |
 | | `_` | Same commit as main |
@@ -1575,6 +2146,7 @@ wt list --format=json | jq '.[] | select(.main_state == "integrated" or .main_st
 | `is_current` | Current worktree |
 | `is_previous` | Previous worktree from [wt switch](@/switch.md) |
 | `pr` | `{ci, source, stale, url}` — CI status from PR or branch (absent when no CI) |
+| `background_jobs` | `{running, failed, finished, stopped}` — counts of background commands (absent when none) |
 | `statusline` | Pre-formatted status with ANSI colors |
 | `symbols` | Raw status symbols without colors (e.g., `"!?↓"`) |
 
@@ -1637,11 +2209,16 @@ For interactive selection, use [`wt select`](@/select.md).
 
 With `--create`, worktrunk:
 
-1. Creates branch from `--base` (defaults to default branch)
-2. Creates worktree at configured path
-3. Runs [post-create hooks](@/hook.md#post-create) (blocking)
-4. Switches to new directory
-5. Spawns [post-start hooks](@/hook.md#post-start) (background)
+1. Runs [pre-switch hooks](@/hook.md#pre-switch) in the current worktree (blocking)
+2. Runs [pre-create hooks](@/hook.md#pre-create) (blocking; a failure aborts the creation)
+3. Creates branch from `--base` (defaults to default branch)
+4. Creates worktree at configured path
+5. Runs [post-create hooks](@/hook.md#post-create) (blocking)
+6. Switches to new directory
+7. Spawns [post-start hooks](@/hook.md#post-start) (background)
+8. Runs [post-switch hooks](@/hook.md#post-switch) in the new worktree (blocking)
+
+Switching to an existing worktree runs only the pre-switch and post-switch hooks.
 
 ```console
 wt switch --create api-refactor
@@ -1664,6 +2241,36 @@ wt switch ^                      # Main worktree
 wt switch --create fix --base=@  # Branch from current HEAD
 ```
 
+## Remote branches
+
+Switching to a branch that exists only on a remote creates a local branch
+tracking it. Name the remote to pick one explicitly, or when several remotes
+have the branch (git's `checkout.defaultRemote` otherwise decides). `--fetch`
+updates the remote branch first:
+
+```console
+wt switch feature                # Tracks origin/feature if that's the only one
+wt switch upstream/feature       # Tracks upstream/feature
+wt switch --fetch feature        # Fetch, then switch
+```
+
+## Pull requests and merge requests
+
+`pr:<number>` switches to a GitHub pull request's branch (through `gh`), and
+`mr:<number>` to a GitLab merge request's (through `glab`). Worktrunk fetches the
+head and creates a local branch tracking it, then switches as for any existing
+branch:
+
+```console
+wt switch pr:1234
+wt switch mr:56
+```
+
+Branches from forks are named `<owner>/<branch>`. When the fork is already a
+remote, the branch tracks the fork's branch; otherwise it's fetched from the
+PR's head ref on the base repository (`refs/pull/<n>/head`), which `git pull`
+keeps following.
+
 ## See also
 
 - [wt select](@/select.md) — Interactive worktree selection
@@ -1674,7 +2281,8 @@ wt switch --create fix --base=@  # Branch from current HEAD
     Switch {
         /// Branch or worktree name
         ///
-        /// Shortcuts: '^' (main), '-' (previous), '@' (current)
+        /// Shortcuts: '^' (main), '-' (previous), '@' (current), 'pr:<n>' (GitHub
+        /// pull request), 'mr:<n>' (GitLab merge request)
         #[arg(add = crate::completion::worktree_branch_completer())]
         branch: String,
 
@@ -1688,6 +2296,13 @@ wt switch --create fix --base=@  # Branch from current HEAD
         #[arg(short = 'b', long, add = crate::completion::branch_value_completer())]
         base: Option<String>,
 
+        /// Fetch the remote branch first
+        ///
+        /// Updates the remote branch being checked out, or with `--create`, a
+        /// remote `--base`.
+        #[arg(long)]
+        fetch: bool,
+
         /// Command to run after switch
         ///
         /// Replaces the wt process with the command after switching, giving
@@ -1764,6 +2379,14 @@ Use `-D` to force-delete branches with unmerged changes. Use `--no-delete-branch
 
 Removal runs in the background by default (returns immediately). Logs are written to `.git/wt-logs/{branch}-remove.log`. Use `--no-background` to run in the foreground.
 
+[post-remove hooks](@/hook.md#post-remove) run once the worktree is gone: in the background after a background removal, otherwise right after it.
+
+## Running processes
+
+Before the worktree is removed (after `pre-remove` hooks), the branch's background `post-start` commands are stopped. They get SIGTERM, and SIGKILL if still running two seconds later. Stopped commands are listed in the output. `--no-stop-jobs` leaves them running.
+
+Other processes whose working directory is inside the worktree — a dev server started by hand, an editor — are listed in a warning but left alone. `--stop-processes` stops them too, the same way. Shells are never stopped.
+
 Arguments resolve by path first, then branch name. [Shortcuts](@/switch.md#shortcuts): `@` (current), `-` (previous), `^` (main worktree).
 
 ## See also
@@ -1793,6 +2416,14 @@ Arguments resolve by path first, then branch name. [Shortcuts](@/switch.md#short
         #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
         verify: bool,
 
+        /// Leave background commands running
+        #[arg(long = "no-stop-jobs", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        stop_jobs: bool,
+
+        /// Also stop other processes running in the worktree
+        #[arg(long, conflicts_with = "stop_jobs")]
+        stop_processes: bool,
+
         /// Skip approval prompts
         #[arg(long)]
         force: bool,
@@ -1843,7 +2474,7 @@ wt merge --no-commit
 1. **Squash** — Stages uncommitted changes, then combines all commits since target into one (like GitHub's "Squash and merge"). Use `--stage` to control what gets staged: `all` (default), `tracked`, or `none`. A backup ref is saved to `refs/wt-backup/<branch>`. With `--no-squash`, uncommitted changes are committed separately and individual commits are preserved.
 2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts abort immediately.
 3. **Pre-merge hooks** — Project commands run after rebase, before merge. Failures abort. See [wt hook](@/hook.md).
-4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected.
+4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected. Pre-push hooks run right before the target branch is updated; failures abort.
 5. **Pre-remove hooks** — Project commands run before removing worktree. Failures abort.
 6. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree.
 7. **Post-merge hooks** — Project commands run after cleanup. Failures are logged but don't abort.
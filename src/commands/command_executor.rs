@@ -1,8 +1,10 @@
+use std::cell::OnceCell;
 use std::collections::HashMap;
 use std::path::Path;
 use worktrunk::HookType;
 use worktrunk::config::{
-    Command, CommandConfig, WorktrunkConfig, expand_template, sanitize_branch_name,
+    Command, CommandConfig, CommandOptions, WorktrunkConfig, condition_uses_variable,
+    evaluate_condition, expand_template, sanitize_branch_name, template_uses_variable,
 };
 use worktrunk::git::Repository;
 
@@ -11,6 +13,9 @@ pub struct PreparedCommand {
     pub name: Option<String>,
     pub expanded: String,
     pub context_json: String,
+    pub options: CommandOptions,
+    /// False when the command's `if` condition evaluated falsy (the command should be skipped)
+    pub condition_met: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -102,15 +107,31 @@ pub fn build_hook_context(
     map
 }
 
+/// Files changed on the branch since it diverged from the target (or default) branch
+///
+/// Costs a merge-base and a diff, so it's only computed for commands that use
+/// `changed_files`. `None` when there's nothing to compare against.
+fn changed_files(
+    ctx: &CommandContext<'_>,
+    context: &HashMap<String, String>,
+) -> Option<Vec<String>> {
+    let base = context
+        .get("target")
+        .or_else(|| context.get("default_branch"))?;
+    let merge_base = ctx.repo.merge_base(base, ctx.branch).ok()?;
+    ctx.repo.changed_files(&merge_base, ctx.branch).ok()
+}
+
 /// Expand commands from a CommandConfig without approval
 ///
 /// This is the canonical command expansion implementation.
-/// Returns cloned commands with their expanded forms filled in, each with per-command JSON context.
+/// Returns cloned commands with their expanded forms filled in, each with per-command JSON
+/// context and whether its `if` condition holds (true for commands without one).
 fn expand_commands(
     commands: &[Command],
     ctx: &CommandContext<'_>,
     extra_vars: &[(&str, &str)],
-) -> anyhow::Result<Vec<(Command, String)>> {
+) -> anyhow::Result<Vec<(Command, String, bool)>> {
     if commands.is_empty() {
         return Ok(Vec::new());
     }
@@ -123,15 +144,31 @@ fn expand_commands(
         .and_then(|n| n.to_str())
         .unwrap_or("unknown");
 
-    // Convert to &str references for expand_template
-    let extras_ref: HashMap<&str, &str> = base_context
-        .iter()
-        .map(|(k, v)| (k.as_str(), v.as_str()))
-        .collect();
-
+    let changed = OnceCell::new();
     let mut result = Vec::new();
 
     for cmd in commands {
+        let condition = cmd.options.condition.as_deref();
+        let files = (template_uses_variable(&cmd.template, "changed_files")
+            || condition.is_some_and(|c| condition_uses_variable(c, "changed_files")))
+        .then(|| {
+            changed
+                .get_or_init(|| changed_files(ctx, &base_context))
+                .as_deref()
+        })
+        .flatten();
+
+        // Templates and the JSON context get the files one per line
+        let mut cmd_context = base_context.clone();
+        if let Some(files) = files {
+            cmd_context.insert("changed_files".into(), files.join("\n"));
+        }
+        // Convert to &str references for expand_template
+        let extras_ref: HashMap<&str, &str> = cmd_context
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
         let expanded_str = expand_template(&cmd.template, repo_name, ctx.branch, &extras_ref)
             .map_err(|e| {
                 anyhow::anyhow!(
@@ -142,7 +179,6 @@ fn expand_commands(
             })?;
 
         // Build per-command JSON with hook_type and hook_name
-        let mut cmd_context = base_context.clone();
         cmd_context.insert("hook_type".into(), cmd.phase.to_string());
         if let Some(ref name) = cmd.name {
             cmd_context.insert("hook_name".into(), name.clone());
@@ -150,14 +186,29 @@ fn expand_commands(
         let context_json = serde_json::to_string(&cmd_context)
             .expect("HashMap<String, String> serialization should never fail");
 
+        let condition_met = match condition {
+            Some(condition) => {
+                let cmd_extras: HashMap<&str, &str> = cmd_context
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect();
+                evaluate_condition(condition, repo_name, ctx.branch, &cmd_extras, files).map_err(
+                    |e| anyhow::anyhow!("Failed to evaluate condition '{}': {}", condition, e),
+                )?
+            }
+            None => true,
+        };
+
         result.push((
             Command::with_expansion(
                 cmd.name.clone(),
                 cmd.template.clone(),
                 expanded_str,
                 cmd.phase,
-            ),
+            )
+            .with_options(cmd.options.clone()),
             context_json,
+            condition_met,
         ));
     }
 
@@ -186,10 +237,12 @@ pub fn prepare_project_commands(
 
     Ok(expanded_with_json
         .into_iter()
-        .map(|(cmd, context_json)| PreparedCommand {
+        .map(|(cmd, context_json, condition_met)| PreparedCommand {
             name: cmd.name,
             expanded: cmd.expanded,
             context_json,
+            options: cmd.options,
+            condition_met,
         })
         .collect())
 }
@@ -219,10 +272,12 @@ pub fn prepare_user_commands(
 
     Ok(expanded_with_json
         .into_iter()
-        .map(|(cmd, context_json)| PreparedCommand {
+        .map(|(cmd, context_json, condition_met)| PreparedCommand {
             name: cmd.name,
             expanded: cmd.expanded,
             context_json,
+            options: cmd.options,
+            condition_met,
         })
         .collect())
 }
@@ -1,10 +1,15 @@
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
 use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::CommitGenerationConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{
-    format_with_gutter, hint_message, info_message, progress_message, success_message,
+    PROMPT_EMOJI, eprint, eprintln, format_with_gutter, hint_message, info_message,
+    progress_message, success_message, warning_message,
 };
 
 use super::command_executor::CommandContext;
@@ -22,6 +27,8 @@ pub struct CommitOptions<'a> {
     pub stage_mode: StageMode,
     pub warn_about_untracked: bool,
     pub show_no_squash_note: bool,
+    /// Review the generated message before committing
+    pub review: bool,
 }
 
 impl<'a> CommitOptions<'a> {
@@ -34,17 +41,177 @@ impl<'a> CommitOptions<'a> {
             stage_mode: StageMode::All,
             warn_about_untracked: true,
             show_no_squash_note: false,
+            review: false,
+        }
+    }
+}
+
+/// Which command a message under review is for
+///
+/// Each has its own scratch file in the worktree's git dir, left behind when the
+/// commit is aborted; the next review for the same command starts from it, as
+/// long as HEAD hasn't moved since.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReviewedCommit {
+    Commit,
+    Squash,
+}
+
+impl ReviewedCommit {
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::Commit => "WT_COMMIT_EDITMSG",
+            Self::Squash => "WT_SQUASH_EDITMSG",
+        }
+    }
+}
+
+/// What to do with a message under review
+#[derive(Debug, PartialEq)]
+enum ReviewChoice {
+    Accept,
+    Edit,
+    Regenerate,
+    Abort,
+}
+
+impl ReviewChoice {
+    fn parse(input: &str, can_regenerate: bool) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" => Some(Self::Accept),
+            "e" | "edit" => Some(Self::Edit),
+            "r" | "regenerate" if can_regenerate => Some(Self::Regenerate),
+            "n" | "no" | "q" => Some(Self::Abort),
+            _ => None,
         }
     }
 }
 
 pub(crate) struct CommitGenerator<'a> {
     config: &'a CommitGenerationConfig,
+    review: bool,
+    reviewed: ReviewedCommit,
 }
 
 impl<'a> CommitGenerator<'a> {
-    pub fn new(config: &'a CommitGenerationConfig) -> Self {
-        Self { config }
+    pub fn new(config: &'a CommitGenerationConfig, review: bool, reviewed: ReviewedCommit) -> Self {
+        Self {
+            config,
+            review,
+            reviewed,
+        }
+    }
+
+    fn review_message_path(&self, repo: &Repository) -> anyhow::Result<PathBuf> {
+        Ok(repo.git_dir()?.join(self.reviewed.file_name()))
+    }
+
+    /// HEAD a saved message was written at, next to the message
+    fn review_head_path(message_path: &Path) -> PathBuf {
+        message_path.with_extension("head")
+    }
+
+    /// Message left by an aborted review, when reviewing and HEAD is where it was
+    pub fn saved_message(&self, repo: &Repository) -> anyhow::Result<Option<String>> {
+        if !self.review {
+            return Ok(None);
+        }
+        let path = self.review_message_path(repo)?;
+        let saved_head = std::fs::read_to_string(Self::review_head_path(&path)).ok();
+        if saved_head.as_deref().map(str::trim) != Some(head(repo).as_str()) {
+            return Ok(None);
+        }
+        let message = std::fs::read_to_string(&path).unwrap_or_default();
+        let message = message.trim();
+        Ok((!message.is_empty()).then(|| message.to_string()))
+    }
+
+    /// Remove the review scratch file once its message is committed
+    pub fn clear_saved_message(&self, repo: &Repository) -> anyhow::Result<()> {
+        let path = self.review_message_path(repo)?;
+        for path in [Self::review_head_path(&path), path] {
+            match std::fs::remove_file(path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                    return Err(e).context("Failed to remove the reviewed commit message");
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Let the user accept, edit, regenerate or abort `message`
+    ///
+    /// Returns the message to commit; without review, that's `message` itself.
+    /// `regenerate` is called with the user's optional hint for the new message.
+    pub fn review_message(
+        &self,
+        repo: &Repository,
+        mut message: String,
+        regenerate: impl Fn(Option<&str>) -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        if !self.review {
+            return Ok(message);
+        }
+
+        let path = self.review_message_path(repo)?;
+        std::fs::write(Self::review_head_path(&path), head(repo))
+            .context("Failed to save the commit message for review")?;
+        // Only an LLM produces a different message on another try
+        let can_regenerate = self.config.is_configured();
+
+        loop {
+            std::fs::write(&path, format!("{}\n", message.trim_end()))
+                .context("Failed to save the commit message for review")?;
+
+            if !io::stdin().is_terminal() {
+                return Err(GitError::CommitReviewNotInteractive.into());
+            }
+
+            let prompt = if can_regenerate {
+                cformat!(
+                    "Commit with this message? <bold>[y]</>es, <bold>[e]</>dit, <bold>[r]</>egenerate, <bold>[n]</>o"
+                )
+            } else {
+                cformat!("Commit with this message? <bold>[y]</>es, <bold>[e]</>dit, <bold>[n]</>o")
+            };
+            let Some(input) = prompt_line(&prompt)? else {
+                return Err(GitError::CommitAborted { message_path: path }.into());
+            };
+            let Some(choice) = ReviewChoice::parse(&input, can_regenerate) else {
+                continue;
+            };
+
+            match choice {
+                ReviewChoice::Accept => return Ok(message),
+                ReviewChoice::Abort => {
+                    return Err(GitError::CommitAborted { message_path: path }.into());
+                }
+                ReviewChoice::Edit => {
+                    run_editor(repo, &path)?;
+                    let edited = std::fs::read_to_string(&path)
+                        .context("Failed to read the edited commit message")?;
+                    let edited = strip_comments(&edited);
+                    if edited.is_empty() {
+                        crate::output::print(warning_message(
+                            "The edited message is empty; keeping the previous one",
+                        ))?;
+                        continue;
+                    }
+                    message = edited;
+                }
+                ReviewChoice::Regenerate => {
+                    let hint = prompt_line("Hint for the new message (Enter for none):")?
+                        .unwrap_or_default();
+                    let hint = hint.trim();
+                    crate::output::print(progress_message("Regenerating commit message..."))?;
+                    message = regenerate((!hint.is_empty()).then_some(hint))?;
+                }
+            }
+
+            let formatted_message = self.format_message_for_display(&message);
+            crate::output::gutter(format_with_gutter(&formatted_message, "", None))?;
+        }
     }
 
     pub fn format_message_for_display(&self, message: &str) -> String {
@@ -94,7 +261,10 @@ impl<'a> CommitGenerator<'a> {
             _ => "changes",
         };
 
-        let action = if self.config.is_configured() {
+        let saved_message = self.saved_message(&repo)?;
+        let action = if saved_message.is_some() {
+            format!("Committing {changes_type} with the message from the aborted review...")
+        } else if self.config.is_configured() {
             format!("Generating commit message and committing {changes_type}...")
         } else {
             format!("Committing {changes_type} with default message...")
@@ -119,14 +289,24 @@ impl<'a> CommitGenerator<'a> {
 
         crate::output::print(progress_message(full_progress_msg))?;
 
-        self.emit_hint_if_needed()?;
-        let commit_message = crate::llm::generate_commit_message(self.config)?;
+        let commit_message = match saved_message {
+            Some(message) => message,
+            None => {
+                self.emit_hint_if_needed()?;
+                crate::llm::generate_commit_message(self.config, None)?
+            }
+        };
 
         let formatted_message = self.format_message_for_display(&commit_message);
         crate::output::gutter(format_with_gutter(&formatted_message, "", None))?;
 
+        let commit_message = self.review_message(&repo, commit_message, |hint| {
+            crate::llm::generate_commit_message(self.config, hint)
+        })?;
+
         repo.run_command(&["commit", "-m", &commit_message])
             .context("Failed to commit")?;
+        self.clear_saved_message(&repo)?;
 
         let commit_hash = repo
             .run_command(&["rev-parse", "--short", "HEAD"])?
@@ -210,7 +390,91 @@ impl CommitOptions<'_> {
             }
         }
 
-        CommitGenerator::new(&self.ctx.config.commit_generation)
-            .commit_staged_changes(self.show_no_squash_note, self.stage_mode)
+        CommitGenerator::new(
+            &self.ctx.config.commit_generation,
+            self.review,
+            ReviewedCommit::Commit,
+        )
+        .commit_staged_changes(self.show_no_squash_note, self.stage_mode)
+    }
+}
+
+/// Current HEAD commit, empty on an unborn branch
+fn head(repo: &Repository) -> String {
+    repo.run_command(&["rev-parse", "--verify", "--quiet", "HEAD"])
+        .map(|out| out.trim().to_string())
+        .unwrap_or_default()
+}
+
+/// Prompt on stderr and read a line; None at end of input
+fn prompt_line(prompt: &str) -> anyhow::Result<Option<String>> {
+    // Stdout may carry directives; flush it before prompting on stderr
+    crate::output::flush_for_stderr_prompt()?;
+    eprint!("{PROMPT_EMOJI} {prompt} ");
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    let read = io::stdin().read_line(&mut input)?;
+    eprintln!();
+    Ok((read > 0).then_some(input))
+}
+
+/// Open `path` in the editor `git commit` would use
+fn run_editor(repo: &Repository, path: &Path) -> anyhow::Result<()> {
+    // GIT_EDITOR, core.editor, VISUAL, EDITOR, then git's default
+    let editor = repo.run_command(&["var", "GIT_EDITOR"])?.trim().to_string();
+
+    // Through the shell so editors with arguments (`code --wait`) work; stdout goes to
+    // stderr since stdout carries directives in directive mode
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$@\""))
+        .arg("sh")
+        .arg(path)
+        .stdout(io::stderr())
+        .status()
+        .with_context(|| format!("Failed to run editor {editor}"))?;
+    if !status.success() {
+        anyhow::bail!("Editor {editor} exited with {status}");
+    }
+    Ok(())
+}
+
+/// Drop `#` comment lines, as `git commit` does, and surrounding whitespace
+fn strip_comments(message: &str) -> String {
+    message
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_review_choice() {
+        assert_eq!(ReviewChoice::parse("y\n", true), Some(ReviewChoice::Accept));
+        assert_eq!(ReviewChoice::parse("Edit", true), Some(ReviewChoice::Edit));
+        assert_eq!(
+            ReviewChoice::parse("r", true),
+            Some(ReviewChoice::Regenerate)
+        );
+        // Regenerating without an LLM gives the same message
+        assert_eq!(ReviewChoice::parse("r", false), None);
+        assert_eq!(ReviewChoice::parse("n", false), Some(ReviewChoice::Abort));
+        assert_eq!(ReviewChoice::parse("", true), None);
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(
+            strip_comments("Fix parser\n\n# Lines starting with '#' are ignored\nDetails\n\n"),
+            "Fix parser\n\nDetails"
+        );
+        assert_eq!(strip_comments("# only a comment\n"), "");
     }
 }
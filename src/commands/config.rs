@@ -15,8 +15,9 @@ use worktrunk::styling::{
 
 use super::configure_shell::{ConfigAction, scan_shell_configs};
 use super::list::ci_status::CachedCiStatus;
+use super::statusline::CachedStatusline;
 use crate::help_pager::show_help_in_pager;
-use crate::llm::test_commit_generation;
+use crate::llm::{describe_provider, test_commit_generation};
 use crate::output;
 
 /// Example user configuration file content (displayed in help with values uncommented)
@@ -157,26 +158,16 @@ fn run_doctor_checks() -> anyhow::Result<()> {
     let config = WorktrunkConfig::load()?;
     let commit_config = &config.commit_generation;
 
-    if !commit_config.is_configured() {
+    let Some(provider_display) = describe_provider(commit_config)? else {
         output::print(warning_message("Commit generation is not configured"))?;
         output::print(hint_message(cformat!(
             "Add <bright-black>[commit-generation]</> section to enable LLM commit messages"
         )))?;
         return Ok(());
-    }
-
-    let command_display = format!(
-        "{}{}",
-        commit_config.command.as_ref().unwrap(),
-        if commit_config.args.is_empty() {
-            String::new()
-        } else {
-            format!(" {}", commit_config.args.join(" "))
-        }
-    );
+    };
 
     output::print(progress_message(cformat!(
-        "Testing commit generation with <bold>{command_display}</>"
+        "Testing commit generation with <bold>{provider_display}</>"
     )))?;
 
     match test_commit_generation(commit_config) {
@@ -667,31 +658,45 @@ pub fn handle_cache_show() -> anyhow::Result<()> {
     // Show CI status cache
     crate::output::print(info_message("CI status cache:"))?;
 
-    let entries = CachedCiStatus::list_all(&repo);
-    if entries.is_empty() {
-        crate::output::gutter(format_with_gutter("(empty)", "", None))?;
-        return Ok(());
-    }
-
     let now_secs = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
 
-    let mut ci_lines = Vec::new();
-    for (branch, cached) in entries {
-        let status = match &cached.status {
-            Some(pr_status) => serde_json::to_string(&pr_status.ci_status)
-                .map(|s| s.trim_matches('"').to_string())
-                .unwrap_or_else(|_| "unknown".to_string()),
-            None => "none".to_string(),
-        };
-        let age = now_secs.saturating_sub(cached.checked_at);
-        let head: String = cached.head.chars().take(8).collect();
+    let entries = CachedCiStatus::list_all(&repo);
+    if entries.is_empty() {
+        crate::output::gutter(format_with_gutter("(empty)", "", None))?;
+    } else {
+        let mut ci_lines = Vec::new();
+        for (branch, cached) in entries {
+            let status = match &cached.status {
+                Some(pr_status) => serde_json::to_string(&pr_status.ci_status)
+                    .map(|s| s.trim_matches('"').to_string())
+                    .unwrap_or_else(|_| "unknown".to_string()),
+                None => "none".to_string(),
+            };
+            let age = now_secs.saturating_sub(cached.checked_at);
+            let head: String = cached.head.chars().take(8).collect();
+
+            ci_lines.push(format!("{branch}: {status} (age: {age}s, head: {head})"));
+        }
+        crate::output::gutter(format_with_gutter(&ci_lines.join("\n"), "", None))?;
+    }
 
-        ci_lines.push(format!("{branch}: {status} (age: {age}s, head: {head})"));
+    // Show statusline cache (only once `wt list statusline` has run)
+    let entries = CachedStatusline::list_all(&repo);
+    if !entries.is_empty() {
+        crate::output::blank()?;
+        crate::output::print(info_message("Statusline cache:"))?;
+        let lines: Vec<String> = entries
+            .into_iter()
+            .map(|(branch, cached)| {
+                let age = now_secs.saturating_sub(cached.checked_at);
+                format!("{branch} (age: {age}s)")
+            })
+            .collect();
+        crate::output::gutter(format_with_gutter(&lines.join("\n"), "", None))?;
     }
-    crate::output::gutter(format_with_gutter(&ci_lines.join("\n"), "", None))?;
 
     Ok(())
 }
@@ -712,6 +717,17 @@ pub fn handle_cache_clear(cache_type: Option<String>) -> anyhow::Result<()> {
                 )))?;
             }
         }
+        Some("statusline") => {
+            let cleared = CachedStatusline::clear_all(&repo);
+            if cleared == 0 {
+                crate::output::print(info_message("No statusline cache entries to clear"))?;
+            } else {
+                crate::output::print(success_message(cformat!(
+                    "Cleared <bold>{cleared}</> statusline cache entr{}",
+                    if cleared == 1 { "y" } else { "ies" }
+                )))?;
+            }
+        }
         Some("default-branch") => {
             if repo
                 .run_command(&["config", "--unset", "worktrunk.defaultBranch"])
@@ -738,16 +754,19 @@ pub fn handle_cache_clear(cache_type: Option<String>) -> anyhow::Result<()> {
                 .run_command(&["config", "--unset", "worktrunk.defaultBranch"])
                 .is_ok();
             let cleared_ci = CachedCiStatus::clear_all(&repo) > 0;
+            let cleared_statusline = CachedStatusline::clear_all(&repo) > 0;
             let cleared_logs = clear_logs(&repo)? > 0;
 
-            if cleared_default || cleared_ci || cleared_logs {
+            if cleared_default || cleared_ci || cleared_statusline || cleared_logs {
                 crate::output::print(success_message("Cleared all caches"))?;
             } else {
                 crate::output::print(info_message("No caches to clear"))?;
             }
         }
         Some(unknown) => {
-            anyhow::bail!("Unknown cache type: {unknown}. Valid types: ci, default-branch, logs");
+            anyhow::bail!(
+                "Unknown cache type: {unknown}. Valid types: ci, default-branch, statusline, logs"
+            );
         }
     }
 
@@ -755,6 +774,10 @@ pub fn handle_cache_clear(cache_type: Option<String>) -> anyhow::Result<()> {
 }
 
 /// Clear all log files from the wt-logs directory
+///
+/// Files of jobs that are still running stay, so `wt hook logs`, `wt hook stop` and
+/// `wt remove` still find them. While any job runs, dependency markers stay too,
+/// since commands waiting on a finished dependency read its `.done` marker.
 fn clear_logs(repo: &Repository) -> anyhow::Result<usize> {
     let git_common_dir = repo.git_common_dir()?;
     let log_dir = git_common_dir.join("wt-logs");
@@ -763,13 +786,50 @@ fn clear_logs(repo: &Repository) -> anyhow::Result<usize> {
         return Ok(0);
     }
 
+    let jobs = super::jobs::find_jobs(&log_dir)?;
+    let processes = if jobs.is_empty() {
+        Vec::new()
+    } else {
+        super::jobs::list_processes()
+    };
+    let running: Vec<String> = jobs
+        .iter()
+        .filter(|job| job.status(&processes) == super::jobs::JobStatus::Running)
+        .map(|job| match job.branch.as_str() {
+            "" => job.operation.clone(),
+            branch => format!("{branch}-{}", job.operation),
+        })
+        .collect();
+
     let mut cleared = 0;
     for entry in std::fs::read_dir(&log_dir)? {
         let entry = entry?;
         let path = entry.path();
-        if path.is_file() && path.extension().is_some_and(|ext| ext == "log") {
-            std::fs::remove_file(&path)?;
-            cleared += 1;
+        if !path.is_file() {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("");
+        if running.iter().any(|running| running == stem) {
+            continue;
+        }
+        let is_run_number = |ext: &str| ext.chars().all(|c| c.is_ascii_digit());
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("log") => {
+                std::fs::remove_file(&path)?;
+                cleared += 1;
+            }
+            // Previous runs (`{branch}-{name}.log.1`, ...)
+            Some(run) if is_run_number(run) && stem.ends_with(".log") => {
+                std::fs::remove_file(&path)?;
+                cleared += 1;
+            }
+            Some("job") => std::fs::remove_file(&path)?,
+            // Completion and PID markers of background commands with `needs`
+            Some("done" | "pid") if running.is_empty() => std::fs::remove_file(&path)?,
+            _ => {}
         }
     }
 
@@ -0,0 +1,190 @@
+//! `wt step describe`: a pull request title and description for the branch.
+//!
+//! Renders `pr-template` with the commits and diff since the target branch,
+//! through the same prompt machinery as squash messages, and sends it to the
+//! `[commit-generation]` LLM. The description goes to stdout, to a file with
+//! `--output`, or to `gh pr create` with `--pr`.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, bail};
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{format_with_gutter, progress_message, success_message};
+
+use super::context::CommandEnv;
+
+/// Body passed to `gh pr create --body-file`, stored in the git dir
+const PR_BODY_FILE: &str = "WT_PR_DESCRIPTION.md";
+
+/// A generated pull request title and markdown body
+#[derive(Debug, PartialEq)]
+struct PrDescription {
+    title: String,
+    body: String,
+}
+
+impl PrDescription {
+    /// Split LLM output into the title (its first line) and the body
+    fn parse(output: &str) -> Self {
+        let output = output.trim();
+        let (title, body) = output.split_once('\n').unwrap_or((output, ""));
+        // Models sometimes format the title as a heading
+        let title = title.trim_start_matches('#').trim();
+        Self {
+            title: title.to_string(),
+            body: body.trim().to_string(),
+        }
+    }
+
+    /// Title, blank line, then body, as for a commit message
+    fn to_text(&self) -> String {
+        if self.body.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{}\n\n{}", self.title, self.body)
+        }
+    }
+}
+
+/// Handle `wt step describe`
+pub fn handle_step_describe(
+    target: Option<&str>,
+    output: Option<&Path>,
+    create_pr: bool,
+) -> anyhow::Result<()> {
+    let env = CommandEnv::for_action("describe")?;
+    let repo = &env.repo;
+    let target_branch = repo.resolve_target_branch(target)?;
+
+    if !env.config.commit_generation.is_configured() {
+        bail!(
+            "Describing a branch needs an LLM. Add [commit-generation] to your config; see `wt config --help`."
+        );
+    }
+    // Check before spending an LLM request
+    if create_pr && which::which("gh").is_err() {
+        bail!("--pr needs the GitHub CLI (gh); use --output to write the description to a file");
+    }
+
+    let merge_base = repo.merge_base("HEAD", &target_branch)?;
+    let subjects = repo.commit_subjects(&format!("{merge_base}..HEAD"))?;
+    if subjects.is_empty() {
+        bail!("{} has no commits ahead of {target_branch}", env.branch);
+    }
+
+    let commit_text = if subjects.len() == 1 {
+        "commit"
+    } else {
+        "commits"
+    };
+    crate::output::print(progress_message(cformat!(
+        "Generating PR description for {} {commit_text} ahead of <bold>{target_branch}</>...",
+        subjects.len()
+    )))?;
+
+    let repo_root = repo.worktree_root()?;
+    let repo_name = repo_root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("repo");
+    let description = PrDescription::parse(&crate::llm::generate_pr_description(
+        &target_branch,
+        &merge_base,
+        &subjects,
+        &env.branch,
+        repo_name,
+        &env.config.commit_generation,
+    )?);
+
+    if !create_pr && output.is_none() {
+        crate::output::data(description.to_text())?;
+        return Ok(());
+    }
+
+    crate::output::gutter(format_with_gutter(&description.to_text(), "", None))?;
+    if let Some(path) = output {
+        std::fs::write(path, description.to_text() + "\n")
+            .with_context(|| format!("Failed to write {}", format_path_for_display(path)))?;
+        crate::output::print(success_message(cformat!(
+            "Wrote the PR description to <bold>{}</>",
+            format_path_for_display(path)
+        )))?;
+    }
+    if create_pr {
+        create_pull_request(repo, &target_branch, &description)?;
+    }
+    Ok(())
+}
+
+/// Open a pull request into `target_branch` with `gh pr create`
+fn create_pull_request(
+    repo: &Repository,
+    target_branch: &str,
+    description: &PrDescription,
+) -> anyhow::Result<()> {
+    let body_path = repo.git_dir()?.join(PR_BODY_FILE);
+    std::fs::write(&body_path, &description.body)
+        .with_context(|| format!("Failed to write {}", format_path_for_display(&body_path)))?;
+
+    crate::output::print(progress_message(cformat!(
+        "Creating a pull request into <bold>{target_branch}</> with gh..."
+    )))?;
+    crate::output::flush()?;
+
+    // gh may prompt for where to push the branch; its stdout (the PR URL) goes
+    // to stderr since stdout carries directives in directive mode
+    let status = Command::new("gh")
+        .args(["pr", "create", "--base", target_branch, "--title"])
+        .arg(&description.title)
+        .arg("--body-file")
+        .arg(&body_path)
+        .current_dir(repo.worktree_root()?)
+        .stdout(io::stderr())
+        .status()
+        .context("Failed to run gh")?;
+    if !status.success() {
+        // gh only read the body from the file; keep the title too
+        std::fs::write(&body_path, description.to_text())
+            .with_context(|| format!("Failed to write {}", format_path_for_display(&body_path)))?;
+        bail!(
+            "gh pr create failed; the description is saved in {}",
+            format_path_for_display(&body_path)
+        );
+    }
+
+    let _ = std::fs::remove_file(&body_path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pr_description() {
+        let description =
+            PrDescription::parse("Add parser\n\n## Summary\n- Parse input\n\n## Risks\nNone\n");
+        assert_eq!(description.title, "Add parser");
+        assert_eq!(
+            description.body,
+            "## Summary\n- Parse input\n\n## Risks\nNone"
+        );
+        assert_eq!(
+            description.to_text(),
+            "Add parser\n\n## Summary\n- Parse input\n\n## Risks\nNone"
+        );
+
+        // A heading as the title
+        let description = PrDescription::parse("# Add parser\n\nBody");
+        assert_eq!(description.title, "Add parser");
+        assert_eq!(description.body, "Body");
+
+        let description = PrDescription::parse("  Add parser  ");
+        assert_eq!(description.title, "Add parser");
+        assert_eq!(description.to_text(), "Add parser");
+    }
+}
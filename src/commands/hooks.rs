@@ -12,19 +12,25 @@
 // The source prefix in filtering (option 2) would need to be used elsewhere too to justify
 // the syntax. Current behavior is reasonable but worth revisiting if users find it confusing.
 
+use std::io::Write;
+
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::{CommandConfig, ProjectConfig};
 use worktrunk::git::WorktrunkError;
-use worktrunk::styling::{format_bash_with_gutter, progress_message, warning_message};
+use worktrunk::styling::{
+    format_bash_with_gutter, info_message, progress_message, warning_message,
+};
 
 use super::command_executor::{
     CommandContext, PreparedCommand, prepare_project_commands, prepare_user_commands,
 };
-use crate::commands::process::spawn_detached;
-use crate::output::execute_command_in_worktree;
+use crate::commands::process::{clear_done_markers, spawn_detached, spawn_detached_after};
+use crate::output::handlers::CommandTimedOut;
+use crate::output::{execute_captured, execute_command_in_worktree};
 
 /// Controls how hook execution should respond to failures.
+#[derive(Clone, Copy)]
 pub enum HookFailureStrategy {
     /// Stop on first failure and surface a `HookCommandFailed` error.
     FailFast,
@@ -85,7 +91,26 @@ impl<'a> HookPipeline<'a> {
                 prepare_project_commands(command_config, &self.ctx, extra_vars, hook_type)?
             }
         };
-        Ok(Self::filter_by_name(commands, name_filter))
+        let commands = Self::filter_by_name(commands, name_filter);
+
+        // Drop commands whose `if` condition doesn't hold, noting each skip
+        let label_prefix = source.format_label(hook_type);
+        let mut runnable = Vec::with_capacity(commands.len());
+        for cmd in commands {
+            if cmd.condition_met {
+                runnable.push(cmd);
+                continue;
+            }
+            let label = match &cmd.name {
+                Some(name) => cformat!("{label_prefix} <bold>{name}</>"),
+                None => label_prefix.clone(),
+            };
+            let condition = cmd.options.condition.as_deref().unwrap_or_default();
+            crate::output::print(info_message(cformat!(
+                "Skipping {label}: condition <bright-black>{condition}</> is false"
+            )))?;
+        }
+        Ok(runnable)
     }
 
     /// Filter commands by name (returns empty vec if name not found - caller decides if that's an error)
@@ -102,7 +127,12 @@ impl<'a> HookPipeline<'a> {
         }
     }
 
-    /// Run hook commands sequentially, using the provided failure strategy.
+    /// Run hook commands in declaration order, using the provided failure strategy.
+    ///
+    /// Adjacent commands marked `parallel` run concurrently as one batch; their output is
+    /// captured and printed grouped by command once the batch finishes. Failures are
+    /// reported in declaration order, and with `FailFast` no later batch starts.
+    ///
     /// Returns the number of commands that were run.
     pub fn run_sequential(
         &self,
@@ -121,57 +151,76 @@ impl<'a> HookPipeline<'a> {
         let command_count = commands.len();
 
         // Track first failure for Warn strategy (to propagate exit code after all commands run)
-        let mut first_failure: Option<(String, Option<String>, i32)> = None;
+        let mut first_failure: Option<WorktrunkError> = None;
 
         let label_prefix = source.format_label(hook_type);
 
-        for prepared in commands {
-            let label =
-                crate::commands::format_command_label(&label_prefix, prepared.name.as_deref());
-            crate::output::print(progress_message(format!("{label}:")))?;
-            crate::output::gutter(format_bash_with_gutter(&prepared.expanded, ""))?;
+        // A hook using `needs` runs as a dependency graph instead of in batches
+        let batches = if commands.iter().any(|cmd| !cmd.options.needs.is_empty()) {
+            vec![commands]
+        } else {
+            Self::batches(commands)
+        };
 
-            if let Err(err) = execute_command_in_worktree(
-                self.ctx.worktree_path,
-                &prepared.expanded,
-                Some(&prepared.context_json),
-            ) {
-                // Extract raw message and exit code from error
-                let (err_msg, exit_code) =
-                    if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
-                        match wt_err {
-                            WorktrunkError::ChildProcessExited { message, code } => {
-                                (message.clone(), Some(*code))
-                            }
-                            _ => (err.to_string(), None),
-                        }
-                    } else {
-                        (err.to_string(), None)
-                    };
+        for batch in batches {
+            let results = if batch.iter().any(|cmd| !cmd.options.needs.is_empty()) {
+                self.run_dependency_graph(&batch, &label_prefix, &failure_strategy)?
+            } else if batch.len() == 1 {
+                let prepared = &batch[0];
+                let label =
+                    crate::commands::format_command_label(&label_prefix, prepared.name.as_deref());
+                crate::output::print(progress_message(format!("{label}:")))?;
+                crate::output::gutter(format_bash_with_gutter(&prepared.expanded, ""))?;
 
-                match &failure_strategy {
-                    HookFailureStrategy::FailFast => {
-                        return Err(WorktrunkError::HookCommandFailed {
-                            hook_type,
-                            command_name: prepared.name.clone(),
-                            error: err_msg,
-                            exit_code,
+                vec![Some(self.run_streamed(prepared)?)]
+            } else {
+                self.run_parallel_batch(&batch, &label_prefix)?
+            };
+
+            for (prepared, result) in batch.iter().zip(results) {
+                let err = match result {
+                    Some(Ok(())) => continue,
+                    Some(Err(err)) => err,
+                    // Not started because a command it needs failed
+                    None => {
+                        if let HookFailureStrategy::Warn = failure_strategy {
+                            let name = prepared.name.as_deref().unwrap_or_default();
+                            crate::output::print(warning_message(cformat!(
+                                "Skipped <bold>{name}</>: a command it needs failed"
+                            )))?;
                         }
-                        .into());
+                        continue;
                     }
+                };
+
+                let detail = failure_detail(&err);
+                let failure = hook_failure(err, hook_type, prepared.name.clone());
+
+                match &failure_strategy {
+                    HookFailureStrategy::FailFast => return Err(failure.into()),
                     HookFailureStrategy::Warn => {
                         let message = match &prepared.name {
-                            Some(name) => {
-                                cformat!("Command <bold>{name}</> failed: {err_msg}")
-                            }
-                            None => format!("Command failed: {err_msg}"),
+                            Some(name) => cformat!("Command <bold>{name}</> failed: {detail}"),
+                            None => format!("Command failed: {detail}"),
                         };
                         crate::output::print(warning_message(message))?;
 
                         // Track first failure to propagate exit code later (only for PostMerge)
                         if first_failure.is_none() && hook_type == HookType::PostMerge {
-                            first_failure =
-                                Some((err_msg, prepared.name.clone(), exit_code.unwrap_or(1)));
+                            first_failure = Some(match failure {
+                                WorktrunkError::HookCommandFailed {
+                                    hook_type,
+                                    command_name,
+                                    error,
+                                    exit_code,
+                                } => WorktrunkError::HookCommandFailed {
+                                    hook_type,
+                                    command_name,
+                                    error,
+                                    exit_code: Some(exit_code.unwrap_or(1)),
+                                },
+                                other => other,
+                            });
                         }
                     }
                 }
@@ -182,20 +231,184 @@ impl<'a> HookPipeline<'a> {
 
         // For Warn strategy with PostMerge: if any command failed, propagate the exit code
         // This matches git's behavior: post-hooks can't stop the operation but affect exit status
-        if let Some((error, command_name, exit_code)) = first_failure {
-            return Err(WorktrunkError::HookCommandFailed {
-                hook_type,
-                command_name,
-                error,
-                exit_code: Some(exit_code),
-            }
-            .into());
+        if let Some(failure) = first_failure {
+            return Err(failure.into());
         }
 
         Ok(command_count)
     }
 
-    /// Spawn hook commands in the background (used for post-start hooks).
+    /// Run a command with streamed output, retrying after failures as configured.
+    fn run_streamed(&self, prepared: &PreparedCommand) -> anyhow::Result<anyhow::Result<()>> {
+        let attempts = prepared.options.retries.saturating_add(1);
+        let mut attempt = 1;
+        loop {
+            let result = execute_command_in_worktree(
+                self.ctx.worktree_path,
+                &prepared.expanded,
+                Some(&prepared.context_json),
+                prepared.options.timeout,
+            );
+            match result {
+                Err(err) if attempt < attempts && !is_interrupted(&err) => {
+                    attempt += 1;
+                    let name = prepared.name.as_deref().unwrap_or("command");
+                    let detail = failure_detail(&err);
+                    crate::output::print(warning_message(cformat!(
+                        "<bold>{name}</> failed: {detail}; retrying (attempt {attempt} of {attempts})"
+                    )))?;
+                }
+                result => return Ok(result),
+            }
+        }
+    }
+
+    /// Split commands into execution batches.
+    ///
+    /// Each run of adjacent `parallel` commands forms one batch; every other command is
+    /// a batch of its own, so sequential commands act as barriers.
+    fn batches(commands: Vec<PreparedCommand>) -> Vec<Vec<PreparedCommand>> {
+        let mut batches: Vec<Vec<PreparedCommand>> = Vec::new();
+        for cmd in commands {
+            match batches.last_mut() {
+                Some(batch) if cmd.options.parallel && batch[0].options.parallel => batch.push(cmd),
+                _ => batches.push(vec![cmd]),
+            }
+        }
+        batches
+    }
+
+    /// Run a batch of commands concurrently, printing each command's captured output
+    /// under its name once all of them have finished.
+    ///
+    /// Output goes through a thread-local context, so worker threads only capture;
+    /// all printing happens here on the calling thread.
+    fn run_parallel_batch(
+        &self,
+        batch: &[PreparedCommand],
+        label_prefix: &str,
+    ) -> anyhow::Result<Vec<Option<anyhow::Result<()>>>> {
+        for prepared in batch {
+            let label =
+                crate::commands::format_command_label(label_prefix, prepared.name.as_deref());
+            crate::output::print(progress_message(format!("{label}:")))?;
+            crate::output::gutter(format_bash_with_gutter(&prepared.expanded, ""))?;
+        }
+        crate::output::flush()?;
+
+        let worktree_path = self.ctx.worktree_path;
+        let outputs: Vec<(Vec<u8>, anyhow::Result<()>)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|prepared| scope.spawn(move || run_captured(prepared, worktree_path)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("hook worker thread panicked"))
+                .collect()
+        });
+
+        let mut results = Vec::with_capacity(outputs.len());
+        for (prepared, (output, result)) in batch.iter().zip(outputs) {
+            print_captured_output(prepared, &output)?;
+            results.push(Some(result));
+        }
+
+        Ok(results)
+    }
+
+    /// Run commands as a dependency graph: each command starts as soon as every
+    /// command it `needs` has succeeded, so independent commands run concurrently.
+    ///
+    /// Output is captured and printed per command as each finishes. Commands whose
+    /// dependencies failed never start and are returned as `None`. With `FailFast`,
+    /// no new command starts after a failure; commands already running finish first.
+    ///
+    /// `needs` naming a command that isn't in `commands` (filtered out by name) is ignored.
+    fn run_dependency_graph(
+        &self,
+        commands: &[PreparedCommand],
+        label_prefix: &str,
+        failure_strategy: &HookFailureStrategy,
+    ) -> anyhow::Result<Vec<Option<anyhow::Result<()>>>> {
+        let deps: Vec<Vec<usize>> = commands
+            .iter()
+            .map(|cmd| {
+                cmd.options
+                    .needs
+                    .iter()
+                    .filter_map(|need| {
+                        commands
+                            .iter()
+                            .position(|other| other.name.as_deref() == Some(need.as_str()))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut results: Vec<Option<anyhow::Result<()>>> = commands.iter().map(|_| None).collect();
+        let mut started = vec![false; commands.len()];
+        let worktree_path = self.ctx.worktree_path;
+
+        std::thread::scope(|scope| -> anyhow::Result<()> {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut running = 0;
+            let mut stopped = false;
+
+            loop {
+                if !stopped {
+                    for (index, prepared) in commands.iter().enumerate() {
+                        let ready = deps[index]
+                            .iter()
+                            .all(|&dep| matches!(results[dep], Some(Ok(()))));
+                        if started[index] || !ready {
+                            continue;
+                        }
+                        started[index] = true;
+                        running += 1;
+
+                        let label = crate::commands::format_command_label(
+                            label_prefix,
+                            prepared.name.as_deref(),
+                        );
+                        crate::output::print(progress_message(format!("{label}:")))?;
+                        crate::output::gutter(format_bash_with_gutter(&prepared.expanded, ""))?;
+
+                        let tx = tx.clone();
+                        scope.spawn(move || {
+                            let outcome = run_captured(prepared, worktree_path);
+                            // Receiver outlives all workers
+                            let _ = tx.send((index, outcome));
+                        });
+                    }
+                    crate::output::flush()?;
+                }
+
+                if running == 0 {
+                    break;
+                }
+
+                let (index, (output, result)) = rx
+                    .recv()
+                    .expect("hook workers hold a sender until they finish");
+                running -= 1;
+                print_captured_output(&commands[index], &output)?;
+                if result.is_err() && matches!(failure_strategy, HookFailureStrategy::FailFast) {
+                    stopped = true;
+                }
+                results[index] = Some(result);
+            }
+
+            Ok(())
+        })?;
+
+        Ok(results)
+    }
+
+    /// Spawn hook commands in the background (used for post-start and post-remove hooks).
+    ///
+    /// With `after`, every command also waits for that detached operation of the same
+    /// branch (spawned with [`spawn_detached_after`]) to succeed.
     pub fn spawn_background(
         &self,
         command_config: &CommandConfig,
@@ -203,6 +416,7 @@ impl<'a> HookPipeline<'a> {
         source: HookSource,
         extra_vars: &[(&str, &str)],
         name_filter: Option<&str>,
+        after: Option<&str>,
     ) -> anyhow::Result<()> {
         let commands =
             self.prepare_commands(command_config, hook_type, &source, extra_vars, name_filter)?;
@@ -213,25 +427,68 @@ impl<'a> HookPipeline<'a> {
         // Derive operation name from hook type (e.g., "post-start")
         let operation_prefix = hook_type.to_string();
         let label_prefix = source.format_label(hook_type);
+        // Include source in operation name to prevent log file collisions between
+        // user and project hooks with the same name
+        let operation_for =
+            |name: &str| format!("{}-{}-{}", source.label_prefix(), operation_prefix, name);
+
+        // With `needs`, every command joins a dependency graph coordinated through
+        // marker files, so clear markers from the previous run first
+        let has_dependencies =
+            after.is_some() || commands.iter().any(|cmd| !cmd.options.needs.is_empty());
+        if has_dependencies {
+            let operations: Vec<String> = commands
+                .iter()
+                .map(|cmd| operation_for(cmd.name.as_deref().unwrap_or("cmd")))
+                .collect();
+            clear_done_markers(self.ctx.repo, self.ctx.branch, &operations)?;
+        }
 
-        for prepared in commands {
+        for prepared in &commands {
             let label =
                 crate::commands::format_command_label(&label_prefix, prepared.name.as_deref());
             crate::output::print(progress_message(format!("{label}:")))?;
             crate::output::gutter(format_bash_with_gutter(&prepared.expanded, ""))?;
 
             let name = prepared.name.as_deref().unwrap_or("cmd");
-            // Include source in operation name to prevent log file collisions between
-            // user and project hooks with the same name
-            let operation = format!("{}-{}-{}", source.label_prefix(), operation_prefix, name);
-            if let Err(err) = spawn_detached(
-                self.ctx.repo,
-                self.ctx.worktree_path,
-                &prepared.expanded,
-                self.ctx.branch,
-                &operation,
-                Some(&prepared.context_json),
-            ) {
+            let operation = operation_for(name);
+            let spawned = if has_dependencies {
+                // Only wait for commands that are actually spawned (a name filter may
+                // exclude some), otherwise the marker would never appear
+                let needs: Vec<String> = prepared
+                    .options
+                    .needs
+                    .iter()
+                    .filter(|need| {
+                        commands
+                            .iter()
+                            .any(|cmd| cmd.name.as_deref() == Some(need.as_str()))
+                    })
+                    .map(|need| operation_for(need))
+                    .chain(after.map(str::to_string))
+                    .collect();
+                spawn_detached_after(
+                    self.ctx.repo,
+                    self.ctx.worktree_path,
+                    &prepared.expanded,
+                    self.ctx.branch,
+                    &operation,
+                    &needs,
+                    Some(&prepared.context_json),
+                    self.ctx.config.logs.as_ref(),
+                )
+            } else {
+                spawn_detached(
+                    self.ctx.repo,
+                    self.ctx.worktree_path,
+                    &prepared.expanded,
+                    self.ctx.branch,
+                    &operation,
+                    Some(&prepared.context_json),
+                    self.ctx.config.logs.as_ref(),
+                )
+            };
+            if let Err(err) = spawned {
                 let err_msg = err.to_string();
                 let message = match &prepared.name {
                     Some(name) => format!("Failed to spawn \"{name}\": {err_msg}"),
@@ -271,3 +528,100 @@ impl<'a> HookPipeline<'a> {
         Ok(())
     }
 }
+
+/// Print a command's captured output under its name (nothing if it printed nothing)
+fn print_captured_output(prepared: &PreparedCommand, output: &[u8]) -> anyhow::Result<()> {
+    if output.is_empty() {
+        return Ok(());
+    }
+    let name = prepared.name.as_deref().unwrap_or("command");
+    crate::output::print(info_message(cformat!("Output from <bold>{name}</>:")))?;
+    crate::output::flush()?;
+    // Child output goes to stderr, matching streamed execution
+    // (reset first to prevent color bleeding, as execute_command_in_worktree does)
+    let mut stderr = std::io::stderr().lock();
+    write!(stderr, "{}", anstyle::Reset)?;
+    stderr.write_all(output)?;
+    stderr.flush()?;
+    Ok(())
+}
+
+/// Run a command with captured output, retrying after failures as configured.
+///
+/// Output from every attempt is kept, with a marker line before each retry.
+/// Called from worker threads, so it must not print.
+fn run_captured(
+    prepared: &PreparedCommand,
+    worktree_path: &std::path::Path,
+) -> (Vec<u8>, anyhow::Result<()>) {
+    let attempts = prepared.options.retries.saturating_add(1);
+    let mut output = Vec::new();
+    let mut attempt = 1;
+    loop {
+        let (attempt_output, result) = execute_captured(
+            &prepared.expanded,
+            worktree_path,
+            Some(&prepared.context_json),
+            prepared.options.timeout,
+        );
+        output.extend_from_slice(&attempt_output);
+        match result {
+            Err(err) if attempt < attempts && !is_interrupted(&err) => {
+                attempt += 1;
+                let detail = failure_detail(&err);
+                output.extend_from_slice(
+                    format!("[{detail}; retrying (attempt {attempt} of {attempts})]\n").as_bytes(),
+                );
+            }
+            result => return (output, result),
+        }
+    }
+}
+
+/// Short, unstyled description of why a command failed
+fn failure_detail(err: &anyhow::Error) -> String {
+    if let Some(timed_out) = err.downcast_ref::<CommandTimedOut>() {
+        return timed_out.to_string();
+    }
+    match err.downcast_ref::<WorktrunkError>() {
+        Some(WorktrunkError::ChildProcessExited { message, .. }) => message.clone(),
+        _ => err.to_string(),
+    }
+}
+
+/// Whether a command was stopped by Ctrl-C (not worth retrying)
+fn is_interrupted(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<WorktrunkError>(),
+        Some(WorktrunkError::ChildProcessExited { code: 130, .. })
+    )
+}
+
+/// Convert a command error into the hook-level error reported for it
+fn hook_failure(
+    err: anyhow::Error,
+    hook_type: HookType,
+    command_name: Option<String>,
+) -> WorktrunkError {
+    if let Some(timed_out) = err.downcast_ref::<CommandTimedOut>() {
+        return WorktrunkError::HookCommandTimedOut {
+            hook_type,
+            command_name,
+            elapsed: timed_out.elapsed,
+        };
+    }
+
+    // Extract raw message and exit code from error
+    let (error, exit_code) = match err.downcast_ref::<WorktrunkError>() {
+        Some(WorktrunkError::ChildProcessExited { message, code }) => {
+            (message.clone(), Some(*code))
+        }
+        _ => (err.to_string(), None),
+    };
+    WorktrunkError::HookCommandFailed {
+        hook_type,
+        command_name,
+        error,
+        exit_code,
+    }
+}
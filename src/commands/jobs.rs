@@ -0,0 +1,608 @@
+//! Status of detached background commands and `wt hook stop`
+//!
+//! Each detached command records its state next to its log, in
+//! `.git/wt-logs/{branch}-{operation}.job`:
+//!
+//! ```text
+//! pid=12345
+//! pgid=12340
+//! lstart=Wed Jan  1 00:00:00 2025
+//! started=1735776000
+//! exit=0
+//! ```
+//!
+//! The `exit` line is appended when the command finishes. A job without one whose
+//! process is gone was killed (by `wt hook stop`, a reboot, ...). PIDs get reused,
+//! so a process only counts as the job's when its process group and start time
+//! (as `ps -o lstart` prints it) match too.
+//!
+//! `wt remove` stops a worktree's jobs before removing the directory, and with
+//! `--stop-processes` any other process working inside it (see [`stop_worktree_processes`]).
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::success_message;
+
+use super::logs::{command_name, split_stem};
+use super::process::{sanitize_for_filename, spawned_groups};
+
+/// State of a detached command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Running,
+    Finished,
+    Failed(i32),
+    /// Process is gone without recording an exit status
+    Stopped,
+}
+
+/// A detached command, read from its `.job` file
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// Branch as written in the file name (sanitized)
+    pub branch: String,
+    /// Operation name, e.g. `user-post-start-npm`
+    pub operation: String,
+    pub pid: u32,
+    /// Process group the command runs in
+    pub pgid: Option<u32>,
+    /// Process start time as `ps -o lstart` prints it
+    pub lstart: Option<String>,
+    /// Start time (Unix timestamp)
+    pub started: i64,
+    pub exit: Option<i32>,
+}
+
+impl Job {
+    /// Status, given a snapshot from [`list_processes`]
+    pub fn status(&self, processes: &[ProcessInfo]) -> JobStatus {
+        match self.exit {
+            Some(0) => JobStatus::Finished,
+            Some(code) => JobStatus::Failed(code),
+            None if self.is_running(processes) => JobStatus::Running,
+            None => JobStatus::Stopped,
+        }
+    }
+
+    /// Whether the job's own process is still alive (not just a process that got its PID)
+    fn is_running(&self, processes: &[ProcessInfo]) -> bool {
+        let (Some(pgid), Some(lstart)) = (self.pgid, self.lstart.as_deref()) else {
+            return false;
+        };
+        processes
+            .iter()
+            .any(|p| p.pid == self.pid && p.pgid == pgid && p.lstart == lstart && !p.is_zombie())
+    }
+
+    /// Hook command name (`npm`), or the operation itself when it isn't a hook command
+    pub fn command(&self) -> &str {
+        command_name(&self.operation)
+    }
+}
+
+/// Number of jobs in each state, for `wt list`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct JobCounts {
+    pub running: usize,
+    pub failed: usize,
+    pub finished: usize,
+    pub stopped: usize,
+}
+
+impl JobCounts {
+    pub fn from_jobs<'a>(
+        jobs: impl IntoIterator<Item = &'a Job>,
+        processes: &[ProcessInfo],
+    ) -> Self {
+        let mut counts = Self::default();
+        for job in jobs {
+            match job.status(processes) {
+                JobStatus::Running => counts.running += 1,
+                JobStatus::Finished => counts.finished += 1,
+                JobStatus::Failed(_) => counts.failed += 1,
+                JobStatus::Stopped => counts.stopped += 1,
+            }
+        }
+        counts
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Read all `.job` files in the log directory
+pub fn find_jobs(log_dir: &Path) -> anyhow::Result<Vec<Job>> {
+    if !log_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut jobs = Vec::new();
+    for entry in fs::read_dir(log_dir)? {
+        let path = entry?.path();
+        let Some(stem) = path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .and_then(|f| f.strip_suffix(".job"))
+        else {
+            continue;
+        };
+        // A job that hasn't written its PID yet (or a truncated file) isn't tracked
+        let Some(job) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| parse_job(&content))
+        else {
+            continue;
+        };
+        let (branch, operation) = split_stem(stem);
+        jobs.push(Job {
+            branch: branch.to_string(),
+            operation: operation.to_string(),
+            ..job
+        });
+    }
+    jobs.sort_by(|a, b| (&a.branch, &a.operation).cmp(&(&b.branch, &b.operation)));
+    Ok(jobs)
+}
+
+/// Jobs of one branch
+pub fn jobs_for_branch(repo: &Repository, branch: &str) -> anyhow::Result<Vec<Job>> {
+    let log_dir = repo.git_common_dir()?.join("wt-logs");
+    let branch = sanitize_for_filename(branch);
+    Ok(find_jobs(&log_dir)?
+        .into_iter()
+        .filter(|job| job.branch == branch)
+        .collect())
+}
+
+/// Parse a job file (branch and operation come from its name and are left empty)
+fn parse_job(content: &str) -> Option<Job> {
+    let mut pid = None;
+    let mut pgid = None;
+    let mut lstart = None;
+    let mut started = 0;
+    let mut exit = None;
+    for line in content.lines() {
+        match line.split_once('=') {
+            Some(("pid", value)) => pid = value.trim().parse().ok(),
+            Some(("pgid", value)) => pgid = value.trim().parse().ok(),
+            Some(("lstart", value)) => lstart = Some(normalize_lstart(value)),
+            Some(("started", value)) => started = value.trim().parse().unwrap_or(0),
+            Some(("exit", value)) => exit = value.trim().parse().ok(),
+            _ => {}
+        }
+    }
+    Some(Job {
+        branch: String::new(),
+        operation: String::new(),
+        pid: pid?,
+        pgid,
+        lstart,
+        started,
+        exit,
+    })
+}
+
+/// `ps` pads single-digit days; compare start times with single spaces
+fn normalize_lstart(lstart: &str) -> String {
+    lstart.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Handle `wt hook stop`
+///
+/// Stops every running job of the branch (default: current) whose command or
+/// operation is `name`.
+pub fn handle_hook_stop(name: &str, branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = match branch {
+        Some(branch) => branch.to_string(),
+        None => repo
+            .current_branch()?
+            .ok_or_else(|| anyhow::anyhow!("Not on a branch; pass --branch"))?,
+    };
+
+    let processes = list_processes();
+    let running: Vec<Job> = jobs_for_branch(&repo, &branch)?
+        .into_iter()
+        .filter(|job| name == job.command() || name == job.operation)
+        .filter(|job| job.status(&processes) == JobStatus::Running)
+        .collect();
+
+    if running.is_empty() {
+        anyhow::bail!("No running background command {name} on {branch}");
+    }
+
+    for job in &running {
+        stop_job(job, &processes)?;
+        crate::output::print(success_message(cformat!(
+            "Stopped <bold>{}</> on <bold>{branch}</>",
+            job.operation
+        )))?;
+    }
+    Ok(())
+}
+
+/// Send SIGTERM to a running job and everything it started
+///
+/// Detached commands get their own process group; signal the whole group unless
+/// it's ours (which would mean the job wasn't detached as expected). Nothing is
+/// signalled unless the job's process is still the one that was recorded.
+fn stop_job(job: &Job, processes: &[ProcessInfo]) -> anyhow::Result<()> {
+    if !job.is_running(processes) {
+        anyhow::bail!("Background command {} is no longer running", job.operation);
+    }
+    let target = match (job.pgid, own_group(processes)) {
+        (Some(group), own) if Some(group) != own => format!("-{group}"),
+        _ => job.pid.to_string(),
+    };
+    let status = Command::new("kill")
+        .args(["-TERM", "--", &target])
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to run kill: {e}"))?;
+    if !status.success() {
+        anyhow::bail!("Failed to stop process {}", job.pid);
+    }
+    Ok(())
+}
+
+fn own_group(processes: &[ProcessInfo]) -> Option<u32> {
+    let own_pid = std::process::id();
+    processes.iter().find(|p| p.pid == own_pid).map(|p| p.pgid)
+}
+
+/// How long processes get to exit after SIGTERM before `wt remove` kills them
+pub const STOP_GRACE_PERIOD: Duration = Duration::from_secs(2);
+
+/// Which processes `wt remove` stops before removing a worktree
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StopScope {
+    /// Nothing (`--no-stop-jobs`)
+    None,
+    /// Background commands wt started for the branch
+    #[default]
+    Jobs,
+    /// Those, and any other process working inside the worktree (`--stop-processes`)
+    All,
+}
+
+/// Processes stopped before removing a worktree
+#[derive(Debug, Default)]
+pub struct StoppedProcesses {
+    /// Background commands by name, then other processes by executable name
+    pub stopped: Vec<String>,
+    /// Those still running after the grace period, which got SIGKILL
+    pub killed: Vec<String>,
+    /// Other processes working inside the worktree, left alone (`name (pid)`)
+    pub untouched: Vec<String>,
+}
+
+/// A process (or a detached command's whole process group) to stop
+struct StopTarget {
+    name: String,
+    pid: u32,
+    group: Option<u32>,
+}
+
+impl StopTarget {
+    fn signal(&self, signal: &str) {
+        let target = match self.group {
+            Some(group) => format!("-{group}"),
+            None => self.pid.to_string(),
+        };
+        let _ = Command::new("kill")
+            .args([&format!("-{signal}"), "--", &target])
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+
+    fn alive(&self, processes: &[ProcessInfo]) -> bool {
+        processes.iter().any(|p| {
+            !p.is_zombie()
+                && match self.group {
+                    Some(group) => p.pgid == group,
+                    None => p.pid == self.pid,
+                }
+        })
+    }
+}
+
+/// Stop processes belonging to a worktree that's about to be removed
+///
+/// Background commands spawned for `branch` are stopped unless `scope` is
+/// [`StopScope::None`]. Other processes whose working directory is inside
+/// `worktree_path` are only stopped with [`StopScope::All`]; otherwise they're
+/// reported as untouched. Everything stopped gets SIGTERM; what's still running
+/// after a short grace period gets SIGKILL. wt itself, the shell it was started
+/// from, other shells (session leaders) and wt's background operations for other
+/// branches (such as removals started from this worktree) are never touched.
+///
+/// Best effort: processes that can't be inspected are skipped.
+pub fn stop_worktree_processes(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch: Option<&str>,
+    scope: StopScope,
+) -> StoppedProcesses {
+    let processes = list_processes();
+    let own_pid = std::process::id();
+    let own_group = own_group(&processes);
+    let ancestors = ancestors(own_pid, &processes);
+
+    let branch = branch.map(sanitize_for_filename);
+    let jobs = repo
+        .git_common_dir()
+        .ok()
+        .and_then(|dir| find_jobs(&dir.join("wt-logs")).ok())
+        .unwrap_or_default();
+
+    let mut jobs_to_stop = Vec::new();
+    let mut other_job_groups: HashSet<u32> = spawned_groups().into_iter().collect();
+    for job in jobs {
+        if job.status(&processes) != JobStatus::Running {
+            continue;
+        }
+        let group = job.pgid.filter(|group| Some(*group) != own_group);
+        if branch.as_deref() != Some(job.branch.as_str()) || job.operation == "remove" {
+            other_job_groups.extend(group);
+            continue;
+        }
+        jobs_to_stop.push(StopTarget {
+            name: job.command().to_string(),
+            pid: job.pid,
+            group,
+        });
+    }
+
+    let root = fs::canonicalize(worktree_path).unwrap_or_else(|_| worktree_path.to_path_buf());
+    let job_groups: HashSet<u32> = jobs_to_stop.iter().filter_map(|t| t.group).collect();
+    let mut result = StoppedProcesses::default();
+    let mut targets = match scope {
+        StopScope::None => Vec::new(),
+        StopScope::Jobs | StopScope::All => jobs_to_stop,
+    };
+    let cwds = process_cwds();
+    for process in &processes {
+        let skip = process.pid == own_pid
+            || ancestors.contains(&process.pid)
+            || process.is_session_leader()
+            || process.is_zombie()
+            || job_groups.contains(&process.pgid)
+            || other_job_groups.contains(&process.pgid);
+        if skip
+            || !cwds
+                .get(&process.pid)
+                .is_some_and(|cwd| cwd.starts_with(&root))
+        {
+            continue;
+        }
+        if scope == StopScope::All {
+            targets.push(StopTarget {
+                name: process.name.clone(),
+                pid: process.pid,
+                group: None,
+            });
+        } else {
+            result
+                .untouched
+                .push(format!("{} ({})", process.name, process.pid));
+        }
+    }
+
+    if targets.is_empty() {
+        return result;
+    }
+
+    for target in &targets {
+        target.signal("TERM");
+    }
+    let deadline = Instant::now() + STOP_GRACE_PERIOD;
+    let mut remaining: Vec<&StopTarget> = targets.iter().collect();
+    loop {
+        let processes = list_processes();
+        remaining.retain(|target| target.alive(&processes));
+        if remaining.is_empty() || Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    for target in &remaining {
+        target.signal("KILL");
+    }
+
+    result.stopped = targets.iter().map(|t| t.name.clone()).collect();
+    result.killed = remaining.iter().map(|t| t.name.clone()).collect();
+    result
+}
+
+/// A row of `ps`
+pub struct ProcessInfo {
+    pid: u32,
+    ppid: u32,
+    pgid: u32,
+    stat: String,
+    /// Start time, see [`normalize_lstart`]
+    lstart: String,
+    /// Executable name
+    name: String,
+}
+
+impl ProcessInfo {
+    fn is_zombie(&self) -> bool {
+        self.stat.starts_with('Z')
+    }
+
+    fn is_session_leader(&self) -> bool {
+        self.stat.contains('s')
+    }
+}
+
+/// All processes on the system
+///
+/// Take one snapshot and check every job against it rather than running `ps` per job.
+pub fn list_processes() -> Vec<ProcessInfo> {
+    // `C` locale, so start times compare equal to the ones job files record
+    let Ok(output) = Command::new("ps")
+        .args(["-A", "-o", "pid=,ppid=,pgid=,stat=,lstart=,comm="])
+        .env("LC_ALL", "C")
+        .output()
+    else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(parse_ps_line)
+        .collect()
+}
+
+fn parse_ps_line(line: &str) -> Option<ProcessInfo> {
+    let mut fields = line.split_whitespace();
+    let pid = fields.next()?.parse().ok()?;
+    let ppid = fields.next()?.parse().ok()?;
+    let pgid = fields.next()?.parse().ok()?;
+    let stat = fields.next()?.to_string();
+    // Always five fields, e.g. `Wed Jan  1 00:00:00 2025`
+    let lstart = fields.by_ref().take(5).collect::<Vec<_>>().join(" ");
+    // macOS prints the full path, which may contain spaces
+    let command = fields.collect::<Vec<_>>().join(" ");
+    let name = command.rsplit('/').next().unwrap_or(&command).to_string();
+    Some(ProcessInfo {
+        pid,
+        ppid,
+        pgid,
+        stat,
+        lstart,
+        name,
+    })
+}
+
+/// Parent, grandparent, ... of a process
+fn ancestors(pid: u32, processes: &[ProcessInfo]) -> HashSet<u32> {
+    let mut ancestors = HashSet::new();
+    let mut current = pid;
+    while let Some(parent) = processes
+        .iter()
+        .find(|p| p.pid == current)
+        .map(|p| p.ppid)
+        .filter(|&ppid| ppid > 1 && ancestors.insert(ppid))
+    {
+        current = parent;
+    }
+    ancestors
+}
+
+/// Working directory of every process we're allowed to inspect
+#[cfg(target_os = "linux")]
+fn process_cwds() -> std::collections::HashMap<u32, PathBuf> {
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return Default::default();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse().ok()?;
+            let cwd = fs::read_link(entry.path().join("cwd")).ok()?;
+            Some((pid, cwd))
+        })
+        .collect()
+}
+
+/// Working directory of every process we're allowed to inspect
+#[cfg(not(target_os = "linux"))]
+fn process_cwds() -> std::collections::HashMap<u32, PathBuf> {
+    // `-F pn` prints `p<pid>` then `n<path>` for each process's cwd
+    let Ok(output) = Command::new("lsof")
+        .args(["-d", "cwd", "-F", "pn"])
+        .output()
+    else {
+        return Default::default();
+    };
+    let mut cwds = std::collections::HashMap::new();
+    let mut pid = None;
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(value) = line.strip_prefix('p') {
+            pid = value.parse().ok();
+        } else if let (Some(path), Some(pid)) = (line.strip_prefix('n'), pid) {
+            cwds.insert(pid, PathBuf::from(path));
+        }
+    }
+    cwds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_job() {
+        let job =
+            parse_job("pid=42\npgid=40\nlstart=Wed Jan  1 00:00:00 2025\nstarted=1735776000\n")
+                .unwrap();
+        assert_eq!(
+            (job.pid, job.pgid, job.started, job.exit),
+            (42, Some(40), 1735776000, None)
+        );
+        assert_eq!(job.lstart.as_deref(), Some("Wed Jan 1 00:00:00 2025"));
+
+        let job = parse_job("pid=42\nstarted=1735776000\nexit=3\n").unwrap();
+        assert_eq!(
+            (job.pid, job.pgid, job.lstart, job.exit),
+            (42, None, None, Some(3))
+        );
+
+        assert!(parse_job("").is_none());
+    }
+
+    #[test]
+    fn test_job_status() {
+        let job = |exit| Job {
+            branch: "feature".into(),
+            operation: "user-post-start-npm".into(),
+            pid: 4321,
+            pgid: Some(4320),
+            lstart: Some("Wed Jan 1 00:00:00 2025".into()),
+            started: 0,
+            exit,
+        };
+        let running = |line| vec![parse_ps_line(line).unwrap()];
+        let same = running(" 4321  4320  4320 S    Wed Jan  1 00:00:00 2025 sh");
+        assert_eq!(job(Some(0)).status(&same), JobStatus::Finished);
+        assert_eq!(job(Some(2)).status(&same), JobStatus::Failed(2));
+        assert_eq!(job(None).status(&same), JobStatus::Running);
+        assert_eq!(job(None).status(&[]), JobStatus::Stopped);
+        assert_eq!(job(None).command(), "npm");
+
+        // The PID now belongs to another process
+        let reused = running(" 4321  4320  4320 S    Thu Jan  2 00:00:00 2025 sh");
+        assert_eq!(job(None).status(&reused), JobStatus::Stopped);
+        let regrouped = running(" 4321  4320  4321 S    Wed Jan  1 00:00:00 2025 sh");
+        assert_eq!(job(None).status(&regrouped), JobStatus::Stopped);
+
+        // Job files written without the identity fields can't be verified
+        let legacy = Job {
+            pgid: None,
+            lstart: None,
+            ..job(None)
+        };
+        assert_eq!(legacy.status(&same), JobStatus::Stopped);
+    }
+
+    #[test]
+    fn test_parse_ps_line() {
+        let process = parse_ps_line(
+            "  4321     1  4321 Ss   Wed Jan  1 00:00:00 2025 /usr/local/bin/my server",
+        )
+        .unwrap();
+        assert_eq!((process.pid, process.ppid, process.pgid), (4321, 1, 4321));
+        assert_eq!(process.lstart, "Wed Jan 1 00:00:00 2025");
+        assert!(process.is_session_leader());
+        assert!(!process.is_zombie());
+        assert_eq!(process.name, "my server");
+        assert!(parse_ps_line("garbage").is_none());
+    }
+}
@@ -27,7 +27,7 @@ use worktrunk::git::Repository;
 /// - `https://<host>/<owner>/<repo>.git` → `owner`
 /// - `git@<host>:<owner>/<repo>.git` → `owner`
 /// - `ssh://git@<host>/<owner>/<repo>.git` → `owner`
-fn parse_remote_owner(url: &str) -> Option<&str> {
+pub(crate) fn parse_remote_owner(url: &str) -> Option<&str> {
     let url = url.trim();
 
     let owner = if let Some(rest) = url.strip_prefix("https://") {
@@ -220,6 +220,40 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_github_pr_checks() {
+        // Check runs and commit statuses share the rollup
+        let pr: GitHubPrInfo = serde_json::from_str(
+            r#"{
+                "statusCheckRollup": [
+                    {"name": "build", "status": "COMPLETED", "conclusion": "SUCCESS"},
+                    {"name": "test", "status": "IN_PROGRESS", "conclusion": ""},
+                    {"name": "lint", "status": "COMPLETED", "conclusion": "FAILURE"},
+                    {"context": "ci/deploy", "state": "PENDING"},
+                    {"name": "docs", "status": "COMPLETED", "conclusion": "SKIPPED"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let checks: Vec<(String, CiStatus)> = pr
+            .checks()
+            .into_iter()
+            .map(|check| (check.name, check.status))
+            .collect();
+        assert_eq!(
+            checks,
+            [
+                ("build".to_string(), CiStatus::Passed),
+                ("test".to_string(), CiStatus::Running),
+                ("lint".to_string(), CiStatus::Failed),
+                ("ci/deploy".to_string(), CiStatus::Running),
+                ("docs".to_string(), CiStatus::NoCI),
+            ]
+        );
+        assert_eq!(pr.ci_status(), CiStatus::Running);
+    }
 }
 
 /// Maximum number of PRs/MRs to fetch when filtering by source repository.
@@ -294,7 +328,7 @@ fn get_gitlab_project_id(repo_root: &str) -> Option<u64> {
 }
 
 /// Configure command to disable color output
-fn disable_color_output(cmd: &mut Command) {
+pub(crate) fn disable_color_output(cmd: &mut Command) {
     cmd.env_remove("CLICOLOR_FORCE");
     cmd.env_remove("GH_FORCE_TTY");
     cmd.env("NO_COLOR", "1");
@@ -382,6 +416,16 @@ pub struct PrStatus {
     /// URL to the PR/MR (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// Individual checks behind `ci_status` (GitHub PRs only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<CiCheck>,
+}
+
+/// One check of a PR's status rollup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CiCheck {
+    pub name: String,
+    pub status: CiStatus,
 }
 
 /// Cached CI status stored in git config
@@ -573,6 +617,18 @@ impl CiStatus {
             Self::NoCI => AnsiColor::BrightBlack,
         }
     }
+
+    /// Human-readable name of this status (e.g., "passed", "no CI")
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Running => "running",
+            Self::Failed => "failed",
+            Self::Conflicts => "conflicts",
+            Self::NoCI => "no CI",
+            Self::Error => "error",
+        }
+    }
 }
 
 impl PrStatus {
@@ -614,6 +670,7 @@ impl PrStatus {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            checks: Vec::new(),
         }
     }
 
@@ -848,6 +905,7 @@ impl PrStatus {
             source: CiSource::PullRequest,
             is_stale,
             url: pr_info.url.clone(),
+            checks: pr_info.checks(),
         })
     }
 
@@ -960,6 +1018,7 @@ impl PrStatus {
             // TODO: Fetch GitLab MR URL from glab output to enable clickable links
             // Currently only GitHub PRs have clickable underlined indicators
             url: None,
+            checks: Vec::new(),
         })
     }
 
@@ -1020,6 +1079,7 @@ impl PrStatus {
             source: CiSource::Branch,
             is_stale,
             url: None, // Workflow runs don't have a PR URL
+            checks: Vec::new(),
         })
     }
 
@@ -1069,6 +1129,7 @@ impl PrStatus {
             is_stale,
             // TODO: Fetch GitLab pipeline URL to enable clickable links
             url: None,
+            checks: Vec::new(),
         })
     }
 }
@@ -1101,10 +1162,32 @@ struct HeadRepositoryOwner {
     login: String,
 }
 
+/// An entry of `statusCheckRollup`: a check run (`name`, `status`, `conclusion`)
+/// or a commit status (`context`, `state`)
 #[derive(Debug, Deserialize)]
 struct GitHubCheck {
+    name: Option<String>,
     status: Option<String>,
     conclusion: Option<String>,
+    context: Option<String>,
+    state: Option<String>,
+}
+
+impl GitHubCheck {
+    fn ci_status(&self) -> CiStatus {
+        match (
+            self.status.as_deref(),
+            self.conclusion.as_deref(),
+            self.state.as_deref(),
+        ) {
+            (Some("IN_PROGRESS" | "QUEUED" | "PENDING" | "EXPECTED"), _, _)
+            | (_, _, Some("PENDING" | "EXPECTED")) => CiStatus::Running,
+            (_, Some("FAILURE" | "ERROR" | "CANCELLED" | "TIMED_OUT" | "ACTION_REQUIRED"), _)
+            | (_, _, Some("FAILURE" | "ERROR")) => CiStatus::Failed,
+            (_, Some("SUCCESS"), _) | (_, _, Some("SUCCESS")) => CiStatus::Passed,
+            _ => CiStatus::NoCI,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -1116,6 +1199,21 @@ struct GitHubWorkflowRun {
 }
 
 impl GitHubPrInfo {
+    fn checks(&self) -> Vec<CiCheck> {
+        self.status_check_rollup
+            .iter()
+            .flatten()
+            .map(|check| CiCheck {
+                name: check
+                    .name
+                    .clone()
+                    .or_else(|| check.context.clone())
+                    .unwrap_or_default(),
+                status: check.ci_status(),
+            })
+            .collect()
+    }
+
     fn ci_status(&self) -> CiStatus {
         let Some(checks) = &self.status_check_rollup else {
             return CiStatus::NoCI;
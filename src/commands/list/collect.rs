@@ -29,6 +29,7 @@ use super::model::{
     AheadBehind, BranchDiffTotals, CommitDetails, DisplayFields, GitOperationState, ItemKind,
     ListItem, UpstreamStatus, WorktreeData,
 };
+use crate::commands::jobs::JobCounts;
 
 use super::model::WorkingTreeStatus;
 
@@ -125,6 +126,10 @@ pub(super) enum TaskResult {
         item_idx: usize,
         pr_status: Option<PrStatus>,
     },
+    /// Detached background commands (post-start) and their status
+    BackgroundJobs { item_idx: usize, jobs: JobCounts },
+    /// Whether the worktree has a sparse checkout
+    SparseCheckout { item_idx: usize, sparse: bool },
 }
 
 impl TaskResult {
@@ -143,7 +148,9 @@ impl TaskResult {
             | TaskResult::GitOperation { item_idx, .. }
             | TaskResult::UserMarker { item_idx, .. }
             | TaskResult::Upstream { item_idx, .. }
-            | TaskResult::CiStatus { item_idx, .. } => *item_idx,
+            | TaskResult::CiStatus { item_idx, .. }
+            | TaskResult::BackgroundJobs { item_idx, .. }
+            | TaskResult::SparseCheckout { item_idx, .. } => *item_idx,
         }
     }
 }
@@ -390,6 +397,14 @@ fn drain_results(
                 // Wrap in Some() to indicate "loaded" (Some(None) = no CI, Some(Some(status)) = has CI)
                 items[item_idx].pr_status = Some(pr_status);
             }
+            TaskResult::BackgroundJobs { item_idx, jobs } => {
+                items[item_idx].background_jobs = Some(jobs);
+            }
+            TaskResult::SparseCheckout { item_idx, sparse } => {
+                if let ItemKind::Worktree(data) = &mut items[item_idx].kind {
+                    data.sparse = sparse;
+                }
+            }
         }
 
         // Invoke callback (progressive mode re-renders rows, buffered mode does nothing)
@@ -586,6 +601,7 @@ pub fn collect(
                 is_ancestor: None,
                 upstream: None,
                 pr_status: None,
+                background_jobs: None,
                 status_symbols: None,
                 display: DisplayFields::default(),
                 kind: ItemKind::Worktree(Box::new(worktree_data)),
@@ -988,6 +1004,7 @@ pub fn build_worktree_item(
         is_ancestor: None,
         upstream: None,
         pr_status: None,
+        background_jobs: None,
         status_symbols: None,
         display: DisplayFields::default(),
         kind: ItemKind::Worktree(Box::new(WorktreeData::from_worktree(
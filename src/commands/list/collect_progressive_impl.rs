@@ -23,7 +23,7 @@
 use crate::output;
 use crossbeam_channel::Sender;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use worktrunk::git::{LineDiff, Repository, Worktree};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::warning_message;
@@ -33,6 +33,7 @@ use super::collect::{ExpectedResults, TaskKind, TaskResult, detect_git_operation
 use super::model::{
     AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus, WorkingTreeStatus,
 };
+use crate::commands::jobs::{JobCounts, ProcessInfo, jobs_for_branch, list_processes};
 
 // ============================================================================
 // Options and Context
@@ -526,6 +527,45 @@ impl Task for CiStatusTask {
     }
 }
 
+/// Task 12 (worktree only): Sparse checkout detection
+pub struct SparseCheckoutTask;
+
+impl Task for SparseCheckoutTask {
+    const KIND: TaskKind = TaskKind::SparseCheckout;
+
+    fn compute(ctx: TaskContext) -> TaskResult {
+        let repo = Repository::at(&ctx.repo_path);
+        TaskResult::SparseCheckout {
+            item_idx: ctx.item_idx,
+            sparse: repo.is_sparse_checkout(),
+        }
+    }
+}
+
+/// Task 11 (worktree only): Status of detached background commands
+pub struct BackgroundJobsTask;
+
+impl Task for BackgroundJobsTask {
+    const KIND: TaskKind = TaskKind::BackgroundJobs;
+
+    fn compute(ctx: TaskContext) -> TaskResult {
+        // One `ps` for the whole listing, shared by every worktree's task
+        static PROCESSES: OnceLock<Vec<ProcessInfo>> = OnceLock::new();
+
+        let repo = Repository::at(&ctx.repo_path);
+        let jobs = ctx
+            .branch
+            .as_deref()
+            .and_then(|branch| jobs_for_branch(&repo, branch).ok())
+            .map(|jobs| JobCounts::from_jobs(&jobs, PROCESSES.get_or_init(list_processes)))
+            .unwrap_or_default();
+        TaskResult::BackgroundJobs {
+            item_idx: ctx.item_idx,
+            jobs,
+        }
+    }
+}
+
 // ============================================================================
 // Collection Entry Points
 // ============================================================================
@@ -565,6 +605,7 @@ pub fn collect_worktree_progressive(
         spawner.spawn::<IsAncestorTask>(s, &ctx);
         spawner.spawn::<WorkingTreeDiffTask>(s, &ctx);
         spawner.spawn::<GitOperationTask>(s, &ctx);
+        spawner.spawn::<SparseCheckoutTask>(s, &ctx);
         spawner.spawn::<UserMarkerTask>(s, &ctx);
         spawner.spawn::<UpstreamTask>(s, &ctx);
 
@@ -581,6 +622,9 @@ pub fn collect_worktree_progressive(
         if !skip.contains(&TaskKind::WouldMergeAdd) {
             spawner.spawn::<WouldMergeAddTask>(s, &ctx);
         }
+        if !skip.contains(&TaskKind::BackgroundJobs) {
+            spawner.spawn::<BackgroundJobsTask>(s, &ctx);
+        }
     });
 }
 
@@ -13,6 +13,7 @@ pub enum ColumnKind {
     Upstream,
     Time,
     CiStatus,
+    BackgroundJobs,
     Commit,
     Message,
 }
@@ -97,13 +98,26 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
         Some(TaskKind::CiStatus),
         8,
     ),
-    ColumnSpec::new(ColumnKind::Commit, super::layout::HEADER_COMMIT, 9, None, 9),
-    ColumnSpec::new(ColumnKind::Time, super::layout::HEADER_AGE, 10, None, 10),
+    ColumnSpec::new(
+        ColumnKind::BackgroundJobs,
+        super::layout::HEADER_JOBS,
+        8,
+        Some(TaskKind::BackgroundJobs),
+        9,
+    ),
+    ColumnSpec::new(
+        ColumnKind::Commit,
+        super::layout::HEADER_COMMIT,
+        9,
+        None,
+        10,
+    ),
+    ColumnSpec::new(ColumnKind::Time, super::layout::HEADER_AGE, 10, None, 11),
     ColumnSpec::new(
         ColumnKind::Message,
         super::layout::HEADER_MESSAGE,
         11,
         None,
-        11,
+        12,
     ),
 ];
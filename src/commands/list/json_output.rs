@@ -22,6 +22,7 @@ use worktrunk::git::LineDiff;
 
 use super::ci_status::PrStatus;
 use super::model::{DivergenceContext, ItemKind, ListItem, UpstreamStatus};
+use crate::commands::jobs::JobCounts;
 
 /// JSON output for a single list item
 #[derive(Debug, Clone, Serialize)]
@@ -84,6 +85,10 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pr: Option<JsonPr>,
 
+    /// Counts of detached background commands by status (absent when none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_jobs: Option<JobCounts>,
+
     /// Pre-formatted statusline for statusline tools (tmux, starship)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statusline: Option<String>,
@@ -185,7 +190,7 @@ pub struct JsonRemote {
 /// Worktree-specific state
 #[derive(Debug, Clone, Serialize)]
 pub struct JsonWorktree {
-    /// Worktree state: "no_worktree", "path_mismatch", "prunable", "locked" (absent when normal)
+    /// Worktree state: "no_worktree", "path_mismatch", "prunable", "locked", "sparse" (absent when normal)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<&'static str>,
 
@@ -198,6 +203,10 @@ pub struct JsonWorktree {
 
     /// Bare repository
     pub bare: bool,
+
+    /// Sparse checkout (only some directories checked out)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub sparse: bool,
 }
 
 /// CI status from PR or branch workflow
@@ -311,6 +320,7 @@ impl JsonItem {
                 reason,
                 detached: data.detached,
                 bare: data.bare,
+                sparse: data.sparse,
             }
         });
 
@@ -324,6 +334,8 @@ impl JsonItem {
             .and_then(|opt| opt.as_ref())
             .map(pr_status_to_json);
 
+        let background_jobs = item.background_jobs.filter(|jobs| !jobs.is_empty());
+
         // Statusline and symbols (raw, without ANSI codes)
         let statusline = item.display.statusline.clone();
         let symbols = item
@@ -348,6 +360,7 @@ impl JsonItem {
             is_current,
             is_previous,
             pr,
+            background_jobs,
             statusline,
             symbols,
         }
@@ -383,6 +396,7 @@ fn worktree_state_to_json(
             WorktreeState::PathMismatch => return (Some("path_mismatch"), None),
             WorktreeState::Prunable => return (Some("prunable"), data.prunable.clone()),
             WorktreeState::Locked => return (Some("locked"), data.locked.clone()),
+            WorktreeState::Sparse => return (Some("sparse"), None),
         }
     }
 
@@ -448,7 +462,7 @@ fn format_raw_symbols(symbols: &super::model::StatusSymbols) -> String {
         result.push_str(upstream_div);
     }
 
-    // Worktree state (operations ✘⤴⤵ take priority over location /⚑⊟⊞)
+    // Worktree state (operations ✘⤴⤵ take priority over location /⚑⊟⊞⊡)
     let op_state = symbols.operation_state.to_string();
     if !op_state.is_empty() {
         result.push_str(&op_state);
@@ -182,6 +182,7 @@ pub const HEADER_PATH: &str = "Path";
 pub const HEADER_UPSTREAM: &str = "Remote⇅";
 pub const HEADER_AGE: &str = "Age";
 pub const HEADER_CI: &str = "CI";
+pub const HEADER_JOBS: &str = "Jobs";
 pub const HEADER_COMMIT: &str = "Commit";
 pub const HEADER_MESSAGE: &str = "Message";
 
@@ -246,6 +247,7 @@ pub struct ColumnWidths {
     pub status: usize, // Includes both git status symbols and user-defined status
     pub time: usize,
     pub ci_status: usize,
+    pub background_jobs: usize,
     pub message: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
@@ -262,6 +264,7 @@ pub struct ColumnDataFlags {
     pub branch_diff: bool,
     pub upstream: bool,
     pub ci_status: bool,
+    pub background_jobs: bool,
 }
 
 /// Layout metadata including position mask for Status column
@@ -319,6 +322,7 @@ impl ColumnKind {
             ColumnKind::Upstream => flags.upstream,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
+            ColumnKind::BackgroundJobs => flags.background_jobs,
             ColumnKind::Commit => true,
             ColumnKind::Message => true,
         }
@@ -337,6 +341,7 @@ impl ColumnKind {
             ColumnKind::Path => ColumnIdeal::text(max_path_width),
             ColumnKind::Time => ColumnIdeal::text(widths.time),
             ColumnKind::CiStatus => ColumnIdeal::text(widths.ci_status),
+            ColumnKind::BackgroundJobs => ColumnIdeal::text(widths.background_jobs),
             ColumnKind::Commit => ColumnIdeal::text(commit_width),
             ColumnKind::Message => None,
             ColumnKind::WorkingDiff => {
@@ -450,6 +455,7 @@ fn build_estimated_widths(max_branch: usize, skip_tasks: &HashSet<TaskKind>) ->
     let upstream_fixed = fit_header(HEADER_UPSTREAM, 7); // "↑99 ↓99"
     let age_estimate = 4; // "11mo" (short format)
     let ci_estimate = fit_header(HEADER_CI, 1); // Single indicator symbol
+    let jobs_estimate = fit_header(HEADER_JOBS, 8); // "●2 ✗1 ✓3"
 
     // Assume columns will have data (better to show and hide than to not show)
     // Columns whose required task is skipped won't have data
@@ -460,6 +466,7 @@ fn build_estimated_widths(max_branch: usize, skip_tasks: &HashSet<TaskKind>) ->
         branch_diff: !skip_tasks.contains(&TaskKind::BranchDiff),
         upstream: true,
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
+        background_jobs: !skip_tasks.contains(&TaskKind::BackgroundJobs),
     };
 
     let widths = ColumnWidths {
@@ -467,6 +474,7 @@ fn build_estimated_widths(max_branch: usize, skip_tasks: &HashSet<TaskKind>) ->
         status: status_fixed,
         time: age_estimate,
         ci_status: ci_estimate,
+        background_jobs: jobs_estimate,
         message: 50, // Will be flexible during allocation
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
@@ -843,6 +851,7 @@ mod tests {
             is_ancestor: None,
             upstream: Some(UpstreamStatus::from_parts(Some("origin".to_string()), 4, 2)),
             pr_status: None,
+            background_jobs: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
@@ -859,6 +868,7 @@ mod tests {
                 is_previous: false,
                 path_mismatch: false,
                 working_diff_display: None,
+                sparse: false,
             })),
         };
 
@@ -936,6 +946,7 @@ mod tests {
             is_ancestor: None,
             upstream: Some(UpstreamStatus::default()),
             pr_status: None,
+            background_jobs: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
@@ -952,6 +963,7 @@ mod tests {
                 is_previous: false,
                 path_mismatch: false,
                 working_diff_display: None,
+                sparse: false,
             })),
         };
 
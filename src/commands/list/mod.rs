@@ -138,6 +138,19 @@ use worktrunk::git::Repository;
 
 // Re-export for statusline and other consumers
 pub use collect::{CollectOptions, build_worktree_item, populate_items};
+pub use json_output::JsonItem;
+
+/// Whether any detached background command has recorded its status
+fn has_background_jobs(repo: &Repository) -> bool {
+    repo.git_common_dir()
+        .ok()
+        .and_then(|dir| std::fs::read_dir(dir.join("wt-logs")).ok())
+        .is_some_and(|entries| {
+            entries
+                .flatten()
+                .any(|entry| entry.path().extension().is_some_and(|ext| ext == "job"))
+        })
+}
 
 pub fn handle_list(
     format: crate::OutputFormat,
@@ -153,7 +166,7 @@ pub fn handle_list(
 
     // Build skip set based on flags
     // Without --full: skip expensive operations (BranchDiff, CiStatus, WouldMergeAdd)
-    let skip_tasks: std::collections::HashSet<TaskKind> = if show_full {
+    let mut skip_tasks: std::collections::HashSet<TaskKind> = if show_full {
         std::collections::HashSet::new() // Compute everything
     } else {
         [
@@ -164,6 +177,10 @@ pub fn handle_list(
         .into_iter()
         .collect()
     };
+    // The Jobs column only appears once a background command has been tracked
+    if !has_background_jobs(&repo) {
+        skip_tasks.insert(TaskKind::BackgroundJobs);
+    }
 
     // Progressive rendering only for table format with Progressive mode
     let show_progress = match format {
@@ -3,6 +3,7 @@ use worktrunk::git::{IntegrationReason, LineDiff};
 
 use super::ci_status::PrStatus;
 use super::columns::ColumnKind;
+use crate::commands::jobs::JobCounts;
 
 /// Display fields shared between WorktreeInfo and BranchInfo
 /// These contain formatted strings with ANSI colors for json-pretty output
@@ -95,6 +96,9 @@ pub struct WorktreeData {
     /// Only true when: has branch name, not main worktree, and path differs from template.
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub path_mismatch: bool,
+    /// Whether the worktree has a sparse checkout (only some directories checked out)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub sparse: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_diff_display: Option<String>,
 }
@@ -227,6 +231,9 @@ pub struct ListItem {
 
     /// CI/PR status: None = not loaded, Some(None) = no CI, Some(Some(status)) = has CI
     pub pr_status: Option<Option<PrStatus>>,
+    /// Background command counts: None = not loaded (or not a worktree)
+    #[serde(skip)]
+    pub background_jobs: Option<JobCounts>,
     /// Git status symbols - None until all dependencies are ready.
     /// Note: This field is not serialized directly. JSON output converts to JsonItem first.
     #[serde(skip)]
@@ -260,6 +267,7 @@ impl ListItem {
             is_ancestor: None,
             upstream: None,
             pr_status: None,
+            background_jobs: None,
             status_symbols: None,
             display: DisplayFields::default(),
             kind: ItemKind::Branch,
@@ -387,6 +395,15 @@ impl ListItem {
         }
     }
 
+    /// Uncommitted line changes as `+N -M`, or None for branches and clean worktrees
+    pub fn working_diff_plain(&self) -> Option<String> {
+        let diff = self.worktree_data()?.working_tree_diff.as_ref()?;
+        if diff.is_empty() {
+            return None;
+        }
+        ColumnKind::WorkingDiff.format_diff_plain(diff.added, diff.deleted)
+    }
+
     /// Format this item as a single-line statusline string.
     ///
     /// Format: `branch  status  @working  commits  ^branch_diff  upstream  ci`
@@ -407,12 +424,7 @@ impl ListItem {
 
         // 3. Working diff (worktrees only)
         // Prefix with @ ("at" current state) to distinguish from branch diff (^)
-        if let Some(data) = self.worktree_data()
-            && let Some(ref diff) = data.working_tree_diff
-            && !diff.is_empty()
-            && let Some(formatted) =
-                ColumnKind::WorkingDiff.format_diff_plain(diff.added, diff.deleted)
-        {
+        if let Some(formatted) = self.working_diff_plain() {
             parts.push(format!("@{formatted}"));
         }
 
@@ -499,13 +511,15 @@ impl ListItem {
             ItemKind::Worktree(data) => {
                 // Full status computation for worktrees
 
-                // Worktree location state - priority: path_mismatch > prunable > locked
+                // Worktree location state - priority: path_mismatch > prunable > locked > sparse
                 let worktree_state = if data.path_mismatch {
                     WorktreeState::PathMismatch
                 } else if data.prunable.is_some() {
                     WorktreeState::Prunable
                 } else if data.locked.is_some() {
                     WorktreeState::Locked
+                } else if data.sparse {
+                    WorktreeState::Sparse
                 } else {
                     WorktreeState::None
                 };
@@ -755,7 +769,7 @@ impl Divergence {
 /// - For worktrees: whether the path matches the template, or has issues
 /// - For branches (without worktree): shows / to distinguish from worktrees
 ///
-/// Priority order for worktrees: PathMismatch > Prunable > Locked
+/// Priority order for worktrees: PathMismatch > Prunable > Locked > Sparse
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
 pub enum WorktreeState {
     #[strum(serialize = "")]
@@ -768,6 +782,8 @@ pub enum WorktreeState {
     Prunable,
     /// Locked (protected from removal)
     Locked,
+    /// Sparse checkout (only some directories checked out)
+    Sparse,
     /// Branch indicator (for branches without worktrees)
     Branch,
 }
@@ -779,6 +795,7 @@ impl std::fmt::Display for WorktreeState {
             Self::PathMismatch => write!(f, "⚑"),
             Self::Prunable => write!(f, "⊟"),
             Self::Locked => write!(f, "⊞"),
+            Self::Sparse => write!(f, "⊡"),
             Self::Branch => write!(f, "/"),
         }
     }
@@ -1038,7 +1055,7 @@ impl PositionMask {
             1, // STAGED: + (1 char)
             1, // MODIFIED: ! (1 char)
             1, // UNTRACKED: ? (1 char)
-            1, // WORKTREE_STATE: ✘⤴⤵/⚑⊟⊞ (1 char, priority: conflicts > rebase > merge > path_mismatch > prunable > locked > branch)
+            1, // WORKTREE_STATE: ✘⤴⤵/⚑⊟⊞⊡ (1 char, priority: conflicts > rebase > merge > path_mismatch > prunable > locked > sparse > branch)
             1, // MAIN_STATE: ^✗_⊂↕↑↓ (1 char, priority: is_main > would_conflict > same_commit > integrated > diverged > ahead > behind)
             1, // UPSTREAM_DIVERGENCE: |⇡⇣⇅ (1 char)
             2, // USER_MARKER: single emoji or two chars (allocate 2)
@@ -1056,7 +1073,7 @@ impl PositionMask {
 /// Symbols are categorized to enable vertical alignment in table output.
 /// Display order (left to right):
 /// - Working tree: +, !, ? (staged, modified, untracked - NOT mutually exclusive)
-/// - Worktree state: ✘, ⤴, ⤵, /, ⚑, ⊟, ⊞ (operations + location)
+/// - Worktree state: ✘, ⤴, ⤵, /, ⚑, ⊟, ⊞, ⊡ (operations + location)
 /// - Main state: ^, ✗, _, ⊂, ↕, ↑, ↓ (relationship to main branch - single-stroke vertical arrows)
 /// - Upstream divergence: |, ⇅, ⇡, ⇣ (relationship to remote - vertical arrows)
 /// - User marker: custom labels, emoji
@@ -1221,8 +1238,10 @@ impl StatusSymbols {
             // Fall back to location state
             match self.worktree_state {
                 WorktreeState::None => (String::new(), false),
-                // Branch indicator (/) is informational (dimmed)
-                WorktreeState::Branch => (cformat!("<dim>{}</>", self.worktree_state), true),
+                // Branch (/) and sparse (⊡) indicators are informational (dimmed)
+                WorktreeState::Branch | WorktreeState::Sparse => {
+                    (cformat!("<dim>{}</>", self.worktree_state), true)
+                }
                 // Path mismatch (⚑) is a stronger warning (red)
                 WorktreeState::PathMismatch => (cformat!("<red>{}</>", self.worktree_state), true),
                 // Other worktree attrs (⊟⊞) are warnings (yellow)
@@ -12,6 +12,7 @@ use super::layout::{
 use super::model::{
     AheadBehind, CommitDetails, ListItem, PositionMask, UpstreamStatus, WorktreeData,
 };
+use crate::commands::jobs::JobCounts;
 use worktrunk::git::LineDiff;
 
 /// Compute style for branch/path based on worktree state.
@@ -666,6 +667,20 @@ impl ColumnLayout {
                     }
                 }
             }
+            ColumnKind::BackgroundJobs => {
+                // Only worktrees run background commands
+                if ctx.worktree_data.is_none() {
+                    return StyledLine::new();
+                }
+                match ctx.item.background_jobs {
+                    None => {
+                        let mut cell = StyledLine::new();
+                        cell.push_styled("⋯", Style::new().dimmed());
+                        cell
+                    }
+                    Some(jobs) => render_jobs(&jobs),
+                }
+            }
             ColumnKind::Commit => {
                 let mut cell = StyledLine::new();
                 cell.push_styled(ctx.short_head().to_string(), Style::new().dimmed());
@@ -689,6 +704,36 @@ impl ColumnLayout {
     }
 }
 
+/// Job counts as symbols: `●` running, `✗` failed, `■` stopped, `✓` finished
+///
+/// Counts follow the symbol when there's more than one (`●2 ✓1`).
+fn render_jobs(jobs: &JobCounts) -> StyledLine {
+    use anstyle::{AnsiColor, Color};
+
+    let color = |c: AnsiColor| Style::new().fg_color(Some(Color::Ansi(c)));
+    let mut cell = StyledLine::new();
+    for (symbol, count, style) in [
+        ("●", jobs.running, color(AnsiColor::Green)),
+        ("✗", jobs.failed, color(AnsiColor::Red)),
+        ("■", jobs.stopped, color(AnsiColor::Yellow)),
+        ("✓", jobs.finished, Style::new().dimmed()),
+    ] {
+        if count == 0 {
+            continue;
+        }
+        if cell.width() > 0 {
+            cell.push_raw(" ");
+        }
+        let text = if count == 1 {
+            symbol.to_string()
+        } else {
+            format!("{symbol}{count}")
+        };
+        cell.push_styled(text, style);
+    }
+    cell
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,387 @@
+//! `wt hook logs` - browse and follow logs of background operations
+//!
+//! Background operations (post-start commands, background removals) write to
+//! `.git/wt-logs/{branch}-{operation}.log`, with previous runs kept as
+//! `{branch}-{operation}.log.{run}` (see [`super::process::spawn_detached`]).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+use clap::ValueEnum;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{format_with_gutter, info_message};
+
+use super::jobs::{Job, JobStatus, ProcessInfo, find_jobs, list_processes};
+use super::process::{log_path_for_run, sanitize_for_filename};
+use crate::display::format_relative_time_short;
+
+/// How often `--follow` checks the log for new output
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Logs of one background operation on one branch
+struct LogEntry {
+    /// Branch as written in the file name (sanitized)
+    branch: String,
+    /// Operation name, e.g. `user-post-start-npm` or `remove`
+    operation: String,
+    /// Hook command name (`npm`), or the operation itself when it isn't a hook command
+    command: String,
+    /// Log files, latest run first
+    runs: Vec<PathBuf>,
+}
+
+impl LogEntry {
+    fn latest(&self) -> &Path {
+        &self.runs[0]
+    }
+
+    fn matches(&self, name: Option<&str>, branch: Option<&str>) -> bool {
+        name.is_none_or(|name| name == self.command || name == self.operation)
+            && branch.is_none_or(|branch| sanitize_for_filename(branch) == self.branch)
+    }
+}
+
+/// Handle `wt hook logs`
+pub fn handle_hook_logs(
+    name: Option<&str>,
+    branch: Option<&str>,
+    follow: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let log_dir = repo.git_common_dir()?.join("wt-logs");
+
+    let entries: Vec<LogEntry> = find_logs(&log_dir)?
+        .into_iter()
+        .filter(|entry| entry.matches(name, branch))
+        .collect();
+
+    if follow {
+        let entry = select_for_follow(&repo, entries, name, branch)?;
+        return follow_log(entry.latest());
+    }
+
+    if entries.is_empty() {
+        crate::output::print(info_message(match name {
+            Some(name) => cformat!("No logs for <bold>{name}</>"),
+            None => "No background logs".to_string(),
+        }))?;
+        return Ok(());
+    }
+
+    let jobs = find_jobs(&log_dir)?;
+    let processes = list_processes();
+    let mut by_branch: BTreeMap<&str, Vec<&LogEntry>> = BTreeMap::new();
+    for entry in &entries {
+        by_branch.entry(&entry.branch).or_default().push(entry);
+    }
+
+    for (branch, entries) in by_branch {
+        crate::output::print(info_message(cformat!("<bold>{branch}</>")))?;
+        let lines: Vec<String> = entries
+            .iter()
+            .map(|entry| {
+                let job = jobs
+                    .iter()
+                    .find(|job| job.branch == entry.branch && job.operation == entry.operation);
+                format_entry(entry, job, &processes)
+            })
+            .collect();
+        crate::output::gutter(format_with_gutter(&lines.join("\n"), "", None))?;
+    }
+
+    Ok(())
+}
+
+/// One line per operation: name, status, size, age, previous runs and path of the latest log
+fn format_entry(entry: &LogEntry, job: Option<&Job>, processes: &[ProcessInfo]) -> String {
+    let metadata = fs::metadata(entry.latest()).ok();
+    let size = metadata
+        .as_ref()
+        .map(|m| format_size(m.len()))
+        .unwrap_or_default();
+    let age = metadata
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| format_relative_time_short(d.as_secs() as i64))
+        .unwrap_or_default();
+    let previous = match entry.runs.len() - 1 {
+        0 => String::new(),
+        1 => " (+1 previous run)".to_string(),
+        n => format!(" (+{n} previous runs)"),
+    };
+
+    let status = job
+        .map(|job| format_status(job, processes))
+        .unwrap_or_default();
+
+    cformat!(
+        "<bold>{}</>{status} {size} {age}{previous} <dim>{}</>",
+        entry.operation,
+        format_path_for_display(entry.latest())
+    )
+}
+
+/// Job status, with a leading space
+fn format_status(job: &Job, processes: &[ProcessInfo]) -> String {
+    match job.status(processes) {
+        JobStatus::Running => cformat!(
+            " <green>running (started {})</>",
+            format_relative_time_short(job.started)
+        ),
+        JobStatus::Finished => cformat!(" <dim>finished</>"),
+        JobStatus::Failed(code) => cformat!(" <red>failed (exit {code})</>"),
+        JobStatus::Stopped => cformat!(" <yellow>stopped</>"),
+    }
+}
+
+/// Human-readable file size (e.g., `512B`, `1.5K`, `12.0M`)
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["K", "M", "G"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    for unit in &UNITS[..UNITS.len() - 1] {
+        if size < 1024.0 {
+            return format!("{size:.1}{unit}");
+        }
+        size /= 1024.0;
+    }
+    format!("{size:.1}{}", UNITS[UNITS.len() - 1])
+}
+
+/// Pick the single log `--follow` should show
+///
+/// Without `--branch`, matches on the current branch win over other branches.
+fn select_for_follow(
+    repo: &Repository,
+    entries: Vec<LogEntry>,
+    name: Option<&str>,
+    branch: Option<&str>,
+) -> anyhow::Result<LogEntry> {
+    let mut entries = entries;
+    if entries.len() > 1
+        && branch.is_none()
+        && let Some(current) = repo.current_branch()?
+    {
+        let current = sanitize_for_filename(&current);
+        if entries.iter().any(|entry| entry.branch == current) {
+            entries.retain(|entry| entry.branch == current);
+        }
+    }
+
+    match entries.len() {
+        0 => match name {
+            Some(name) => anyhow::bail!("No logs for {name}"),
+            None => anyhow::bail!("No background logs"),
+        },
+        1 => Ok(entries.remove(0)),
+        _ => {
+            let candidates: Vec<String> = entries
+                .iter()
+                .map(|entry| format!("{} ({})", entry.operation, entry.branch))
+                .collect();
+            Err(anyhow::anyhow!(candidates.join("\n"))
+                .context("Multiple logs match; pick one with a name or --branch"))
+        }
+    }
+}
+
+/// Print a log and keep printing what's appended until interrupted
+///
+/// A new run of the operation replaces the file; following continues with it.
+fn follow_log(path: &Path) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout();
+    let mut file = fs::File::open(path)?;
+    let mut position = 0;
+    let mut buffer = Vec::new();
+
+    loop {
+        buffer.clear();
+        file.read_to_end(&mut buffer)?;
+        if !buffer.is_empty() {
+            position += buffer.len() as u64;
+            stdout.write_all(&buffer)?;
+            stdout.flush()?;
+        }
+
+        std::thread::sleep(FOLLOW_POLL_INTERVAL);
+
+        // Rotation moves the file we have open aside; start over on the new run
+        let replaced =
+            fs::metadata(path).is_ok_and(|m| m.len() < position || !same_file(&file, &m));
+        if replaced && let Ok(new_file) = fs::File::open(path) {
+            file = new_file;
+            position = 0;
+        } else {
+            file.seek(SeekFrom::Start(position))?;
+        }
+    }
+}
+
+#[cfg(unix)]
+fn same_file(file: &fs::File, metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    file.metadata()
+        .is_ok_and(|open| open.ino() == metadata.ino() && open.dev() == metadata.dev())
+}
+
+#[cfg(not(unix))]
+fn same_file(_file: &fs::File, _metadata: &fs::Metadata) -> bool {
+    true
+}
+
+/// Collect log files, grouping previous runs with their operation
+fn find_logs(log_dir: &Path) -> anyhow::Result<Vec<LogEntry>> {
+    if !log_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    // Latest runs identify the operations; previous runs are looked up by stem
+    let mut stems = Vec::new();
+    for entry in fs::read_dir(log_dir)? {
+        let file_name = entry?.file_name();
+        // Previous runs end in `.log.{run}`, so only latest runs match
+        if let Some(stem) = file_name.to_str().and_then(|f| f.strip_suffix(".log")) {
+            stems.push(stem.to_string());
+        }
+    }
+    stems.sort();
+
+    Ok(stems
+        .into_iter()
+        .map(|stem| {
+            let runs = (0..)
+                .map(|run| log_path_for_run(log_dir, &stem, run))
+                .take_while(|path| path.exists())
+                .collect();
+            let (branch, operation) = split_stem(&stem);
+            let command = command_name(operation).to_string();
+            LogEntry {
+                branch: branch.to_string(),
+                operation: operation.to_string(),
+                command,
+                runs,
+            }
+        })
+        .collect())
+}
+
+/// Latest post-start logs of a branch as (operation, path) pairs, for `wt select`
+pub(crate) fn post_start_logs(
+    log_dir: &Path,
+    branch: &str,
+) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    let is_post_start = |operation: &str| {
+        ["user", "project"]
+            .iter()
+            .any(|source| operation.starts_with(&format!("{source}-{}-", HookType::PostStart)))
+    };
+    Ok(find_logs(log_dir)?
+        .into_iter()
+        .filter(|entry| entry.matches(None, Some(branch)) && is_post_start(&entry.operation))
+        .map(|mut entry| (entry.operation, entry.runs.swap_remove(0)))
+        .collect())
+}
+
+/// Split a log stem into branch and operation
+///
+/// Operations are `{source}-{hook-type}-{name}` for hook commands and `remove`
+/// for background removals. Unrecognized stems are returned whole as the operation.
+pub(crate) fn split_stem(stem: &str) -> (&str, &str) {
+    for hook_type in HookType::value_variants() {
+        for source in ["user", "project"] {
+            let marker = format!("-{source}-{hook_type}-");
+            if let Some(index) = stem.find(&marker) {
+                return (&stem[..index], &stem[index + 1..]);
+            }
+        }
+    }
+    if let Some(branch) = stem.strip_suffix("-remove") {
+        return (branch, "remove");
+    }
+    ("", stem)
+}
+
+/// Hook command name from an operation (`user-post-start-npm` → `npm`)
+pub(crate) fn command_name(operation: &str) -> &str {
+    for hook_type in HookType::value_variants() {
+        for source in ["user", "project"] {
+            if let Some(name) = operation.strip_prefix(&format!("{source}-{hook_type}-")) {
+                return name;
+            }
+        }
+    }
+    operation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_stem() {
+        assert_eq!(
+            split_stem("feature-auth-user-post-start-npm"),
+            ("feature-auth", "user-post-start-npm")
+        );
+        assert_eq!(
+            split_stem("main-project-post-start-dev-server"),
+            ("main", "project-post-start-dev-server")
+        );
+        assert_eq!(split_stem("bugfix-remove"), ("bugfix", "remove"));
+        assert_eq!(split_stem("unrelated"), ("", "unrelated"));
+    }
+
+    #[test]
+    fn test_command_name() {
+        assert_eq!(command_name("user-post-start-npm"), "npm");
+        assert_eq!(command_name("project-post-start-dev-server"), "dev-server");
+        assert_eq!(command_name("remove"), "remove");
+    }
+
+    #[test]
+    fn test_find_logs_dotted_names() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "release.18-user-post-start-build.2.log",
+            "release.18-user-post-start-build.2.log.1",
+            "release.18-remove.log",
+        ] {
+            fs::write(dir.path().join(name), "output").unwrap();
+        }
+
+        let logs = find_logs(dir.path()).unwrap();
+        let found: Vec<_> = logs
+            .iter()
+            .map(|entry| {
+                (
+                    entry.branch.as_str(),
+                    entry.operation.as_str(),
+                    entry.runs.len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("release.18", "remove", 1),
+                ("release.18", "user-post-start-build.2", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512B");
+        assert_eq!(format_size(1536), "1.5K");
+        assert_eq!(format_size(10 * 1024 * 1024), "10.0M");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0G");
+    }
+}
@@ -1,5 +1,5 @@
 use worktrunk::HookType;
-use worktrunk::config::{Command, ProjectConfig};
+use worktrunk::config::{Command, ProjectConfig, sanitize_branch_name};
 use worktrunk::git::Repository;
 use worktrunk::styling::info_message;
 
@@ -8,6 +8,7 @@ use super::command_executor::CommandContext;
 use super::commit::CommitOptions;
 use super::context::CommandEnv;
 use super::hooks::{HookFailureStrategy, HookPipeline, HookSource};
+use super::jobs::StopScope;
 use super::project_config::collect_commands_for_hooks;
 use super::repository_ext::RepositoryCliExt;
 use super::worktree::{MergeOperations, RemoveResult, handle_push};
@@ -59,9 +60,11 @@ impl<'a> MergeCommandCollector<'a> {
 
         if !self.no_verify {
             hooks.push(HookType::PreMerge);
+            hooks.push(HookType::PrePush);
             hooks.push(HookType::PostMerge);
             if self.will_remove {
                 hooks.push(HookType::PreRemove);
+                hooks.push(HookType::PostRemove);
             }
         }
 
@@ -85,6 +88,11 @@ pub fn handle_merge(
     let repo = &env.repo;
     let config = &env.config;
     let current_branch = env.branch.clone();
+    let review = config
+        .commit
+        .as_ref()
+        .and_then(|c| c.review)
+        .unwrap_or(false);
 
     // Validate --no-commit: requires clean working tree
     if !commit && repo.is_dirty()? {
@@ -142,6 +150,7 @@ pub fn handle_merge(
             options.stage_mode = stage_mode;
             options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
             options.show_no_squash_note = true;
+            options.review = review;
 
             options.commit()?;
             true // Committed directly
@@ -157,7 +166,8 @@ pub fn handle_merge(
                 Some(&target_branch),
                 force,
                 !verify, // skip_pre_commit when !verify
-                stage_mode
+                stage_mode,
+                review,
             )?,
             super::standalone::SquashResult::Squashed
         )
@@ -193,6 +203,9 @@ pub fn handle_merge(
             squashed,
             rebased,
         }),
+        verify,
+        force,
+        config,
     )?;
 
     // Destination: prefer the target branch's worktree; fall back to main when absent
@@ -219,7 +232,14 @@ pub fn handle_merge(
         };
         // Run hooks during merge removal (pass through verify flag)
         // Approval was handled at the gate (MergeCommandCollector)
-        crate::output::handle_remove_output(&remove_result, Some(&current_branch), true, verify)?;
+        crate::output::handle_remove_output(
+            &remove_result,
+            Some(&current_branch),
+            true,
+            verify,
+            StopScope::Jobs,
+            config,
+        )?;
     } else {
         // Print comprehensive summary (worktree preserved)
         // Priority: main worktree > on target > --no-remove flag
@@ -473,3 +493,81 @@ pub fn execute_pre_remove_commands(
 
     Ok(())
 }
+
+/// Execute post-remove commands in the main worktree (best-effort)
+///
+/// Runs user hooks first, then project hooks. `{{ branch }}`, `{{ worktree }}` and
+/// `{{ worktree_name }}` describe the removed worktree; `{{ branch }}` is empty for
+/// detached HEAD. Failures are logged but don't abort.
+///
+/// Without `after`, the worktree is already gone and the commands run sequentially.
+/// With `after` (the operation of a background removal), they're spawned in the
+/// background under `ctx.branch` and wait for that removal to succeed.
+/// Approval is handled at the gate (command entry point).
+pub fn execute_post_remove_commands(
+    ctx: &CommandContext,
+    removed_worktree: &std::path::Path,
+    branch: Option<&str>,
+    after: Option<&str>,
+) -> anyhow::Result<()> {
+    let pipeline = HookPipeline::new(*ctx);
+    let worktree = removed_worktree.to_string_lossy();
+    let worktree_name = removed_worktree
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let branch = sanitize_branch_name(branch.unwrap_or(""));
+    let extra_vars = [
+        ("branch", branch.as_str()),
+        ("worktree", &*worktree),
+        ("worktree_name", &*worktree_name),
+    ];
+
+    let project_config = ctx.repo.load_project_config()?;
+    let hooks = [
+        (ctx.config.post_remove.as_ref(), HookSource::User),
+        (
+            project_config.as_ref().and_then(|c| c.post_remove.as_ref()),
+            HookSource::Project,
+        ),
+    ];
+
+    // User hooks first (no approval required), then project hooks (approved at the gate)
+    for (commands, source) in hooks {
+        let Some(commands) = commands else {
+            continue;
+        };
+        match after {
+            Some(operation) => pipeline.spawn_background(
+                commands,
+                HookType::PostRemove,
+                source,
+                &extra_vars,
+                None,
+                Some(operation),
+            )?,
+            None => {
+                pipeline.run_sequential(
+                    commands,
+                    HookType::PostRemove,
+                    source,
+                    &extra_vars,
+                    HookFailureStrategy::Warn,
+                    None,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether any post-remove commands are configured (user or project)
+pub fn has_post_remove_commands(ctx: &CommandContext) -> bool {
+    ctx.config.post_remove.is_some()
+        || ctx
+            .repo
+            .load_project_config()
+            .ok()
+            .flatten()
+            .is_some_and(|c| c.post_remove.is_some())
+}
@@ -4,15 +4,20 @@ pub mod commit;
 pub mod config;
 pub mod configure_shell;
 pub mod context;
+pub mod describe;
 mod hooks;
 pub mod init;
+pub mod jobs;
 pub mod list;
+pub mod logs;
 pub mod merge;
+pub mod pr_ref;
 pub mod process;
 pub mod project_config;
 pub mod repository_ext;
 #[cfg(unix)]
 pub mod select;
+pub mod sparse;
 pub mod standalone;
 pub mod statusline;
 pub mod worktree;
@@ -26,7 +31,9 @@ pub use configure_shell::{
     ConfigAction, handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
 pub use init::handle_init;
+pub use jobs::handle_hook_stop;
 pub use list::handle_list;
+pub use logs::handle_hook_logs;
 pub use merge::{execute_pre_remove_commands, handle_merge};
 #[cfg(unix)]
 pub use select::handle_select;
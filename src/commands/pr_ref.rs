@@ -0,0 +1,271 @@
+//! `pr:<number>` and `mr:<number>` arguments for `wt switch`.
+//!
+//! Resolves a GitHub PR (through `gh`) or GitLab MR (through `glab`) to its head
+//! branch, fetches it, and creates a local branch tracking it. `wt switch` then
+//! creates the worktree for that branch as for any other existing branch.
+//!
+//! The head is fetched from the remote whose URL belongs to the head repository's
+//! owner, so PRs from a fork that's already a remote track the fork's branch. Other
+//! fork PRs are fetched from the PR's head ref on the base repository
+//! (`refs/pull/<n>/head`, `refs/merge-requests/<n>/head`), which `git pull` also
+//! follows.
+
+use std::process::Command;
+
+use anyhow::{Context, bail};
+use color_print::cformat;
+use serde::Deserialize;
+use worktrunk::git::Repository;
+use worktrunk::styling::progress_message;
+
+use super::list::ci_status::{disable_color_output, parse_remote_owner};
+
+/// A `pr:<number>` or `mr:<number>` argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReviewRef {
+    /// GitHub pull request
+    Pr(u64),
+    /// GitLab merge request
+    Mr(u64),
+}
+
+impl ReviewRef {
+    fn parse(name: &str) -> Option<Self> {
+        if let Some(number) = name.strip_prefix("pr:") {
+            number.parse().ok().map(Self::Pr)
+        } else if let Some(number) = name.strip_prefix("mr:") {
+            number.parse().ok().map(Self::Mr)
+        } else {
+            None
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Self::Pr(number) => format!("PR #{number}"),
+            Self::Mr(number) => format!("MR !{number}"),
+        }
+    }
+}
+
+/// Where a PR/MR's head lives, and the local branch to create for it
+struct ReviewHead {
+    /// Local branch name: the head branch, prefixed by the fork owner for forks
+    local_branch: String,
+    /// Remote to fetch from
+    remote: String,
+    /// Ref to fetch on that remote
+    remote_ref: String,
+}
+
+/// Whether `name` is a `pr:<number>` or `mr:<number>` argument
+pub fn is_review_ref(name: &str) -> bool {
+    ReviewRef::parse(name).is_some()
+}
+
+/// Resolve `pr:<number>` / `mr:<number>` to a local branch tracking its head
+///
+/// Returns `name` unchanged for any other argument. Fetches the head and creates
+/// the local branch if it doesn't exist yet.
+pub fn resolve_review_ref(repo: &Repository, name: &str) -> anyhow::Result<String> {
+    let Some(review_ref) = ReviewRef::parse(name) else {
+        return Ok(name.to_string());
+    };
+
+    let head = match review_ref {
+        ReviewRef::Pr(number) => github_head(repo, number),
+        ReviewRef::Mr(number) => gitlab_head(repo, number),
+    }
+    .map_err(|e| anyhow::anyhow!("Failed to resolve {}: {e:#}", review_ref.label()))?;
+
+    if repo.local_branch_exists(&head.local_branch)? {
+        return Ok(head.local_branch);
+    }
+
+    crate::output::print(progress_message(cformat!(
+        "Fetching {} (<bold>{}</>) from <bold>{}</>...",
+        review_ref.label(),
+        head.local_branch,
+        head.remote
+    )))?;
+
+    match head.remote_ref.strip_prefix("refs/heads/") {
+        // A branch: fetch into the remote-tracking ref, and track that
+        Some(branch) => {
+            let tracking = format!("{}/{branch}", head.remote);
+            repo.run_command(&[
+                "fetch",
+                &head.remote,
+                &format!("+{}:refs/remotes/{tracking}", head.remote_ref),
+            ])?;
+            repo.run_command(&["branch", "--track", &head.local_branch, &tracking])?;
+        }
+        // A PR head ref: create the branch from it, and pull from it
+        None => {
+            repo.run_command(&["fetch", &head.remote, &head.remote_ref])?;
+            repo.run_command(&["branch", &head.local_branch, "FETCH_HEAD"])?;
+            let config_key = |key: &str| format!("branch.{}.{key}", head.local_branch);
+            repo.run_command(&["config", &config_key("remote"), &head.remote])?;
+            repo.run_command(&["config", &config_key("merge"), &head.remote_ref])?;
+        }
+    }
+
+    Ok(head.local_branch)
+}
+
+/// Remote whose URL belongs to `owner` (case-insensitive, as on GitHub)
+fn remote_for_owner(repo: &Repository, owner: &str) -> Option<String> {
+    let output = repo
+        .run_command(&["config", "--get-regexp", r"remote\..+\.url"])
+        .unwrap_or_default();
+    output.lines().find_map(|line| {
+        // "remote.<name>.url <value>"; ".url " handles remote names with dots
+        let (name, url) = line.strip_prefix("remote.")?.split_once(".url ")?;
+        parse_remote_owner(url)
+            .is_some_and(|url_owner| url_owner.eq_ignore_ascii_case(owner))
+            .then(|| name.to_string())
+    })
+}
+
+/// Run a `gh`/`glab` command in the repository, returning stdout
+fn run_tool(repo: &Repository, tool: &str, args: &[&str]) -> anyhow::Result<Vec<u8>> {
+    let mut cmd = Command::new(tool);
+    cmd.args(args);
+    cmd.current_dir(repo.worktree_base()?);
+    disable_color_output(&mut cmd);
+    cmd.env("PAGER", "cat");
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {tool}; is it installed?"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("{tool} {} failed: {}", args[..2].join(" "), stderr.trim());
+    }
+    Ok(output.stdout)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitHubPr {
+    head_ref_name: String,
+    head_repository_owner: GitHubOwner,
+    is_cross_repository: bool,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubOwner {
+    login: String,
+}
+
+fn github_head(repo: &Repository, number: u64) -> anyhow::Result<ReviewHead> {
+    let number = number.to_string();
+    let stdout = run_tool(
+        repo,
+        "gh",
+        &[
+            "pr",
+            "view",
+            &number,
+            "--json",
+            "headRefName,headRepositoryOwner,isCrossRepository,url",
+        ],
+    )?;
+    let pr: GitHubPr =
+        serde_json::from_slice(&stdout).context("Failed to parse gh pr view JSON")?;
+    Ok(github_review_head(repo, &number, pr))
+}
+
+fn github_review_head(repo: &Repository, number: &str, pr: GitHubPr) -> ReviewHead {
+    let owner = pr.head_repository_owner.login;
+    // https://github.com/<owner>/<repo>/pull/<n>
+    let base_owner = pr
+        .url
+        .strip_prefix("https://")
+        .and_then(|rest| rest.split('/').nth(1));
+    let base_remote = base_owner
+        .and_then(|base_owner| remote_for_owner(repo, base_owner))
+        .unwrap_or_else(|| "origin".to_string());
+
+    if !pr.is_cross_repository {
+        return ReviewHead {
+            local_branch: pr.head_ref_name.clone(),
+            remote: base_remote,
+            remote_ref: format!("refs/heads/{}", pr.head_ref_name),
+        };
+    }
+
+    let local_branch = format!("{owner}/{}", pr.head_ref_name);
+    match remote_for_owner(repo, &owner) {
+        Some(fork_remote) => ReviewHead {
+            local_branch,
+            remote: fork_remote,
+            remote_ref: format!("refs/heads/{}", pr.head_ref_name),
+        },
+        None => ReviewHead {
+            local_branch,
+            remote: base_remote,
+            remote_ref: format!("refs/pull/{number}/head"),
+        },
+    }
+}
+
+#[derive(Deserialize)]
+struct GitLabMr {
+    source_branch: String,
+    source_project_id: u64,
+    target_project_id: u64,
+    author: GitLabAuthor,
+}
+
+#[derive(Deserialize)]
+struct GitLabAuthor {
+    username: String,
+}
+
+fn gitlab_head(repo: &Repository, number: u64) -> anyhow::Result<ReviewHead> {
+    let number = number.to_string();
+    let stdout = run_tool(repo, "glab", &["mr", "view", &number, "--output", "json"])?;
+    let mr: GitLabMr =
+        serde_json::from_slice(&stdout).context("Failed to parse glab mr view JSON")?;
+    Ok(gitlab_review_head(repo, &number, mr))
+}
+
+fn gitlab_review_head(repo: &Repository, number: &str, mr: GitLabMr) -> ReviewHead {
+    // glab resolves the project from the primary remote
+    let remote = repo
+        .primary_remote()
+        .unwrap_or_else(|_| "origin".to_string());
+
+    if mr.source_project_id == mr.target_project_id {
+        ReviewHead {
+            local_branch: mr.source_branch.clone(),
+            remote,
+            remote_ref: format!("refs/heads/{}", mr.source_branch),
+        }
+    } else {
+        ReviewHead {
+            local_branch: format!("{}/{}", mr.author.username, mr.source_branch),
+            remote,
+            remote_ref: format!("refs/merge-requests/{number}/head"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_review_ref() {
+        assert_eq!(ReviewRef::parse("pr:1234"), Some(ReviewRef::Pr(1234)));
+        assert_eq!(ReviewRef::parse("mr:7"), Some(ReviewRef::Mr(7)));
+        assert_eq!(ReviewRef::parse("pr:"), None);
+        assert_eq!(ReviewRef::parse("pr:abc"), None);
+        assert_eq!(ReviewRef::parse("feature"), None);
+        assert_eq!(ReviewRef::parse("pr-1234"), None);
+        assert_eq!(ReviewRef::Pr(1).label(), "PR #1");
+        assert_eq!(ReviewRef::Mr(2).label(), "MR !2");
+    }
+}
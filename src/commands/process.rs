@@ -1,16 +1,19 @@
 use anyhow::Context;
+use color_print::cformat;
 use std::fs;
 use std::path::Path;
 #[cfg(unix)]
 use std::process::Command;
 use std::process::Stdio;
+use worktrunk::config::LogsConfig;
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
+use worktrunk::styling::warning_message;
 
 /// Sanitize a string for use as a filename on all platforms.
 /// Replaces characters that are illegal in Windows filenames or are path separators.
 /// Also handles Windows reserved device names (CON, PRN, AUX, NUL, COM1-9, LPT1-9).
-fn sanitize_for_filename(s: &str) -> String {
+pub(crate) fn sanitize_for_filename(s: &str) -> String {
     // Replace illegal characters
     let sanitized: String = s
         .chars()
@@ -45,6 +48,214 @@ fn posix_command_separator(command: &str) -> &'static str {
     }
 }
 
+/// Create the log directory in the common git directory (shared across all worktrees)
+pub(crate) fn create_log_dir(repo: &Repository) -> anyhow::Result<std::path::PathBuf> {
+    let log_dir = repo.git_common_dir()?.join("wt-logs");
+    fs::create_dir_all(&log_dir).with_context(|| {
+        format!(
+            "Failed to create log directory {}",
+            format_path_for_display(&log_dir)
+        )
+    })?;
+    Ok(log_dir)
+}
+
+/// File stem shared by a detached operation's log and marker files: `{branch}-{name}`
+fn log_stem(branch: &str, name: &str) -> String {
+    format!(
+        "{}-{}",
+        sanitize_for_filename(branch),
+        sanitize_for_filename(name)
+    )
+}
+
+/// Path of an operation's log for a given run: `{stem}.log` for the latest,
+/// `{stem}.log.{run}` for previous runs (1 = most recent)
+///
+/// The run number goes after `.log` so that a hook name or branch ending in
+/// `.{digits}` can't pass for a previous run.
+pub(crate) fn log_path_for_run(log_dir: &Path, stem: &str, run: usize) -> std::path::PathBuf {
+    if run == 0 {
+        log_dir.join(format!("{stem}.log"))
+    } else {
+        log_dir.join(format!("{stem}.log.{run}"))
+    }
+}
+
+/// Shift an operation's logs to make room for a new run
+///
+/// Each kept run moves one slot back (`{stem}.log` → `{stem}.log.1` → `{stem}.log.2`).
+/// Runs beyond `keep` are deleted, as are the oldest previous runs once their
+/// combined size exceeds `max-size`.
+fn rotate_logs(log_dir: &Path, stem: &str, retention: &LogsConfig) -> anyhow::Result<()> {
+    let keep = retention.keep();
+    // Validated when the config loads
+    let max_size = retention.max_size().unwrap_or(u64::MAX);
+    let remove = |path: &Path| {
+        fs::remove_file(path)
+            .with_context(|| format!("Failed to remove {}", format_path_for_display(path)))
+    };
+
+    // Drop the run that would fall off the end, plus any left over from a larger `keep`
+    let mut run = keep - 1;
+    while log_path_for_run(log_dir, stem, run).exists() {
+        remove(&log_path_for_run(log_dir, stem, run))?;
+        run += 1;
+    }
+
+    for run in (0..keep - 1).rev() {
+        let from = log_path_for_run(log_dir, stem, run);
+        if from.exists() {
+            let to = log_path_for_run(log_dir, stem, run + 1);
+            fs::rename(&from, &to)
+                .with_context(|| format!("Failed to rotate {}", format_path_for_display(&from)))?;
+        }
+    }
+
+    // Newest previous runs take priority under the size cap
+    let mut total = 0;
+    for run in 1..keep {
+        let path = log_path_for_run(log_dir, stem, run);
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        total += metadata.len();
+        if total > max_size {
+            remove(&path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// How long a command with `needs` waits for a dependency to start running
+pub const DEPENDENCY_START_TIMEOUT_SECS: u32 = 30;
+
+/// Spawn a detached process that waits for other detached operations first
+///
+/// Used for post-start commands with `needs`. Every operation in the dependency
+/// graph writes its exit code to a `{branch}-{name}.done` marker next to its log;
+/// this command polls for the markers of `needs` (operation names) and only runs
+/// once all of them report success. If one failed, the command is skipped and
+/// reports failure itself, so the skip propagates down the graph.
+///
+/// Each operation also records its shell's PID in a `.pid` marker when it starts.
+/// A dependency that dies without writing its `.done` marker (killed, or stopped
+/// with `wt hook stop`) counts as failed once that PID is gone; one that never
+/// starts within [`DEPENDENCY_START_TIMEOUT_SECS`] counts as failed too, so a
+/// waiting command never polls forever.
+///
+/// Stale markers for `all_operations` should be cleared with [`clear_done_markers`]
+/// before spawning any command of the graph.
+///
+/// Requires a POSIX shell; PowerShell falls back to spawning without ordering,
+/// with a warning.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_detached_after(
+    repo: &Repository,
+    worktree_path: &Path,
+    command: &str,
+    branch: &str,
+    name: &str,
+    needs: &[String],
+    context_json: Option<&str>,
+    retention: Option<&LogsConfig>,
+) -> anyhow::Result<std::path::PathBuf> {
+    use worktrunk::shell_exec::ShellConfig;
+
+    if !ShellConfig::get().is_posix() {
+        crate::output::print(warning_message(cformat!(
+            "<bold>{name}</> needs {}, but ordering requires a POSIX shell; starting it now",
+            needs.join(", ")
+        )))?;
+        return spawn_detached(
+            repo,
+            worktree_path,
+            command,
+            branch,
+            name,
+            context_json,
+            retention,
+        );
+    }
+
+    let log_dir = create_log_dir(repo)?;
+    let marker = |op: &str, extension: &str| {
+        let path = log_dir.join(format!("{}.{extension}", log_stem(branch, op)));
+        shell_escape::escape(path.to_string_lossy().into_owned().into()).into_owned()
+    };
+    let done = marker(name, "done");
+    // Write the marker via rename so a waiting command never reads a partial file
+    let write_done = |status: &str| format!("echo {status} > {done}.tmp && mv {done}.tmp {done}");
+
+    let skip = |reason: String| {
+        format!(
+            "echo {}; {}; exit 1",
+            shell_escape::escape(format!("Skipped: {reason}").into()),
+            write_done("1")
+        )
+    };
+    // Poll every 0.2s
+    let start_polls = DEPENDENCY_START_TIMEOUT_SECS * 5;
+    let wait: String = needs
+        .iter()
+        .map(|need| {
+            let dep = marker(need, "done");
+            let dep_pid = marker(need, "pid");
+            format!(
+                "polls=0\n\
+                 while [ ! -f {dep} ]; do\n\
+                 if [ -f {dep_pid} ]; then\n\
+                 pid=$(cat {dep_pid})\n\
+                 if [ -n \"$pid\" ] && ! kill -0 \"$pid\" 2>/dev/null && [ ! -f {dep} ]; then {}; fi\n\
+                 elif [ $polls -ge {start_polls} ]; then {}; fi\n\
+                 polls=$((polls + 1)); sleep 0.2\n\
+                 done\n\
+                 if [ \"$(cat {dep})\" != 0 ]; then {}; fi\n",
+                skip(format!("{need} stopped before finishing")),
+                skip(format!("{need} did not start")),
+                skip(format!("{need} failed")),
+            )
+        })
+        .collect();
+
+    let wrapped = format!(
+        "echo $$ > {}\n{wait}{{ {command}\n}}\nstatus=$?\n{}\nexit $status\n",
+        marker(name, "pid"),
+        write_done("$status")
+    );
+
+    spawn_detached(
+        repo,
+        worktree_path,
+        &wrapped,
+        branch,
+        name,
+        context_json,
+        retention,
+    )
+}
+
+/// Remove `.done` and `.pid` markers left by a previous run of these operations
+pub fn clear_done_markers(
+    repo: &Repository,
+    branch: &str,
+    operations: &[String],
+) -> anyhow::Result<()> {
+    let log_dir = create_log_dir(repo)?;
+    for op in operations {
+        for extension in ["done", "pid"] {
+            let path = log_dir.join(format!("{}.{extension}", log_stem(branch, op)));
+            if path.exists() {
+                fs::remove_file(&path).with_context(|| {
+                    format!("Failed to remove {}", format_path_for_display(&path))
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Spawn a detached background process with output redirected to a log file
 ///
 /// The process will be fully detached from the parent:
@@ -60,6 +271,7 @@ fn posix_command_separator(command: &str) -> &'static str {
 /// * `branch` - Branch name for log organization
 /// * `name` - Operation identifier (e.g., "post-start-npm", "remove")
 /// * `context_json` - Optional JSON context to pipe to command's stdin
+/// * `retention` - The user's `[logs]` config, for how many previous runs to keep
 ///
 /// # Returns
 /// Path to the log file where output is being written
@@ -70,24 +282,15 @@ pub fn spawn_detached(
     branch: &str,
     name: &str,
     context_json: Option<&str>,
+    retention: Option<&LogsConfig>,
 ) -> anyhow::Result<std::path::PathBuf> {
-    // Get the git common directory (shared across all worktrees)
-    let git_common_dir = repo.git_common_dir()?;
+    let log_dir = create_log_dir(repo)?;
 
-    // Create log directory in the common git directory
-    let log_dir = git_common_dir.join("wt-logs");
-    fs::create_dir_all(&log_dir).with_context(|| {
-        format!(
-            "Failed to create log directory {}",
-            format_path_for_display(&log_dir)
-        )
-    })?;
-
-    // Generate log filename (no timestamp - overwrites on each run)
+    // Generate log filename; previous runs are kept as {branch}-{name}.log.1, .log.2, ...
     // Format: {branch}-{name}.log (e.g., "feature-post-start-npm.log", "bugfix-remove.log")
-    let safe_branch = sanitize_for_filename(branch);
-    let safe_name = sanitize_for_filename(name);
-    let log_path = log_dir.join(format!("{}-{}.log", safe_branch, safe_name));
+    let stem = log_stem(branch, name);
+    rotate_logs(&log_dir, &stem, &retention.cloned().unwrap_or_default())?;
+    let log_path = log_dir.join(format!("{stem}.log"));
 
     // Create log file
     let log_file = fs::File::create(&log_path).with_context(|| {
@@ -99,7 +302,11 @@ pub fn spawn_detached(
 
     #[cfg(unix)]
     {
-        spawn_detached_unix(worktree_path, command, log_file, context_json)?;
+        // Record PID, start time and exit status for `wt list` and `wt hook stop`
+        let job_path = log_dir.join(format!("{stem}.job"));
+        let _ = fs::remove_file(&job_path);
+        let command = track_job(command, &job_path);
+        spawn_detached_unix(worktree_path, &command, log_file, context_json)?;
     }
 
     #[cfg(windows)]
@@ -110,6 +317,23 @@ pub fn spawn_detached(
     Ok(log_path)
 }
 
+/// Wrap a command so its shell records PID, process group, start time and exit status in `job_path`
+///
+/// The command runs in a subshell so an `exit` inside it still reaches the status line.
+/// See [`super::jobs`] for the file format.
+#[cfg(unix)]
+fn track_job(command: &str, job_path: &Path) -> String {
+    let job = shell_escape::escape(job_path.to_string_lossy().into_owned().into()).into_owned();
+    format!(
+        "printf 'pid=%s\\npgid=%s\\nlstart=%s\\nstarted=%s\\n' \"$$\" \
+         \"$(ps -o pgid= -p $$)\" \"$(LC_ALL=C ps -o lstart= -p $$)\" \"$(date +%s)\" > {job}\n\
+         ( {command}\n)\n\
+         status=$?\n\
+         echo \"exit=$status\" >> {job}\n\
+         exit $status\n"
+    )
+}
+
 #[cfg(unix)]
 fn spawn_detached_unix(
     worktree_path: &Path,
@@ -124,6 +348,7 @@ fn spawn_detached_unix(
     // - We wait for the outer shell to exit (happens immediately after backgrounding)
     // - This prevents zombie process accumulation under high concurrency
     // - Output redirected to log file for debugging
+    // - Own process group, so `wt hook stop` can signal the command and its children
 
     // Build the command, optionally piping JSON context to stdin
     let full_command = match context_json {
@@ -141,7 +366,10 @@ fn spawn_detached_unix(
         None => command.to_string(),
     };
 
+    use std::os::unix::process::CommandExt;
+
     let mut child = Command::new("sh")
+        .process_group(0)
         .arg("-c")
         .arg(format!(
             "nohup sh -c {} &",
@@ -158,6 +386,12 @@ fn spawn_detached_unix(
         .spawn()
         .context("Failed to spawn detached process")?;
 
+    // The outer shell leads the new process group
+    SPAWNED_GROUPS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(child.id());
+
     // Wait for the outer shell to exit (immediate, doesn't block on background command)
     child
         .wait()
@@ -166,6 +400,20 @@ fn spawn_detached_unix(
     Ok(())
 }
 
+/// Process groups of the background operations spawned by this invocation
+static SPAWNED_GROUPS: std::sync::Mutex<Vec<u32>> = std::sync::Mutex::new(Vec::new());
+
+/// Process groups of the background operations spawned by this invocation
+///
+/// `wt remove` leaves these alone when stopping a worktree's processes: removals of
+/// other worktrees started from the current one run with it as working directory.
+pub(crate) fn spawned_groups() -> Vec<u32> {
+    SPAWNED_GROUPS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
 #[cfg(windows)]
 fn spawn_detached_windows(
     worktree_path: &Path,
@@ -285,4 +533,62 @@ mod tests {
         assert_eq!(sanitize_for_filename("CONSOLE"), "CONSOLE");
         assert_eq!(sanitize_for_filename("COM10"), "COM10");
     }
+
+    #[test]
+    fn test_rotate_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_dir = dir.path();
+        let retention = LogsConfig {
+            keep: Some(3),
+            max_size: None,
+        };
+        let read = |run| fs::read_to_string(log_path_for_run(log_dir, "main-remove", run)).ok();
+
+        for content in ["first", "second", "third", "fourth"] {
+            rotate_logs(log_dir, "main-remove", &retention).unwrap();
+            fs::write(log_path_for_run(log_dir, "main-remove", 0), content).unwrap();
+        }
+
+        assert_eq!(read(0).as_deref(), Some("fourth"));
+        assert_eq!(read(1).as_deref(), Some("third"));
+        assert_eq!(read(2).as_deref(), Some("second"));
+        assert_eq!(read(3), None);
+    }
+
+    #[test]
+    fn test_rotate_logs_size_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_dir = dir.path();
+        let retention = LogsConfig {
+            keep: Some(5),
+            max_size: Some("10B".to_string()),
+        };
+
+        for content in ["aaaaaa", "bbbbbb", "cccccc"] {
+            rotate_logs(log_dir, "main-remove", &retention).unwrap();
+            fs::write(log_path_for_run(log_dir, "main-remove", 0), content).unwrap();
+        }
+
+        // Only the newest previous run fits under the cap
+        assert!(log_path_for_run(log_dir, "main-remove", 0).exists());
+        assert!(log_path_for_run(log_dir, "main-remove", 1).exists());
+        assert!(!log_path_for_run(log_dir, "main-remove", 2).exists());
+    }
+
+    #[test]
+    fn test_rotate_logs_keep_one_overwrites() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_dir = dir.path();
+        let retention = LogsConfig {
+            keep: Some(1),
+            max_size: None,
+        };
+
+        fs::write(log_path_for_run(log_dir, "main-remove", 0), "old").unwrap();
+        fs::write(log_path_for_run(log_dir, "main-remove", 1), "stale").unwrap();
+        rotate_logs(log_dir, "main-remove", &retention).unwrap();
+
+        assert!(!log_path_for_run(log_dir, "main-remove", 0).exists());
+        assert!(!log_path_for_run(log_dir, "main-remove", 1).exists());
+    }
 }
@@ -8,15 +8,7 @@ pub fn collect_commands_for_hooks(
 ) -> Vec<Command> {
     let mut commands = Vec::new();
     for hook in hooks {
-        let cfg = match hook {
-            HookType::PostCreate => &project_config.post_create,
-            HookType::PostStart => &project_config.post_start,
-            HookType::PreCommit => &project_config.pre_commit,
-            HookType::PreMerge => &project_config.pre_merge,
-            HookType::PostMerge => &project_config.post_merge,
-            HookType::PreRemove => &project_config.pre_remove,
-        };
-        if let Some(config) = cfg {
+        if let Some(config) = project_config.hook(*hook) {
             commands.extend(config.commands_with_phase(*hook));
         }
     }
@@ -0,0 +1,483 @@
+//! Actions on the items picked in `wt select`
+//!
+//! Enter switches to the item under the cursor. Tab marks several items, and the
+//! action keys then act on all of them: remove them, open them in `$EDITOR`, or copy
+//! their paths. Merge, like switch, takes a single item. Create makes a new worktree
+//! named after the query.
+
+use anyhow::Context;
+use color_print::cformat;
+use skim::prelude::*;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use worktrunk::HookType;
+use worktrunk::config::{SelectKeys, WorktrunkConfig};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{format_with_gutter, info_message, warning_message};
+
+use crate::commands::command_approval::approve_hooks;
+use crate::commands::command_executor::CommandContext;
+use crate::commands::jobs::StopScope;
+use crate::commands::merge::handle_merge;
+use crate::commands::worktree::{
+    SwitchEnds, SwitchResult, compute_worktree_path, execute_switch_commands, handle_remove,
+    handle_remove_by_path, handle_remove_current, handle_switch,
+};
+use crate::output::{handle_remove_output, handle_switch_output, print_error};
+
+/// What to do with the selected items, picked by the key that closed skim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SelectAction {
+    Switch,
+    Remove,
+    Merge,
+    Edit,
+    CopyPath,
+    Create,
+}
+
+/// Actions bound to a key; each key accepts the selection with the action's name
+const KEYED_ACTIONS: [SelectAction; 5] = [
+    SelectAction::Create,
+    SelectAction::Remove,
+    SelectAction::Merge,
+    SelectAction::Edit,
+    SelectAction::CopyPath,
+];
+
+impl SelectAction {
+    /// Skim key bindings for the action keys
+    pub(super) fn bindings(keys: &SelectKeys) -> impl Iterator<Item = String> + '_ {
+        KEYED_ACTIONS
+            .iter()
+            .map(|action| format!("{}:accept({})", action.key(keys), action.name()))
+    }
+
+    /// Legend for the preview window
+    pub(super) fn legend(keys: &SelectKeys) -> String {
+        KEYED_ACTIONS
+            .iter()
+            .map(|action| format!("{}: {}", action.key(keys), action.name()))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    /// The action for the event that closed skim (plain Enter switches)
+    pub(super) fn from_event(event: &Event) -> Option<Self> {
+        match event {
+            Event::EvActAccept(None) => Some(Self::Switch),
+            Event::EvActAccept(Some(name)) => KEYED_ACTIONS
+                .iter()
+                .find(|action| action.name() == name)
+                .copied(),
+            _ => None,
+        }
+    }
+
+    /// The key bound to this action: configured in `[select.keys]`, or the default
+    fn key(self, keys: &SelectKeys) -> &str {
+        let (configured, default) = match self {
+            Self::Switch => return "enter",
+            Self::Create => (&keys.create, "alt-c"),
+            Self::Remove => (&keys.remove, "alt-r"),
+            Self::Merge => (&keys.merge, "alt-m"),
+            Self::Edit => (&keys.edit, "alt-e"),
+            Self::CopyPath => (&keys.copy, "alt-y"),
+        };
+        configured.as_deref().unwrap_or(default)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Switch => "switch",
+            Self::Remove => "remove",
+            Self::Merge => "merge",
+            Self::Edit => "edit",
+            Self::CopyPath => "copy",
+            Self::Create => "create",
+        }
+    }
+}
+
+/// A worktree or branch picked in skim
+pub(super) struct Picked {
+    /// Branch name, or "(detached)"
+    pub name: String,
+    pub branch: Option<String>,
+    /// None for branches without a worktree
+    pub path: Option<PathBuf>,
+}
+
+/// What an action did to one picked item, for the summary
+enum Outcome {
+    Done(String),
+    Skipped(&'static str),
+    Failed,
+}
+
+/// Run `action` on the picked items and print a per-item summary
+///
+/// Create ignores the picked items and uses `query` as the new branch name.
+pub(super) fn run_action(
+    action: SelectAction,
+    picked: &[Picked],
+    query: &str,
+    is_directive_mode: bool,
+) -> anyhow::Result<()> {
+    let outcomes = match action {
+        SelectAction::Create => return switch(query, true, is_directive_mode),
+        SelectAction::Switch | SelectAction::Merge if picked.len() > 1 => {
+            crate::output::print(warning_message(format!(
+                "{} takes a single worktree; {} selected",
+                action.name(),
+                picked.len()
+            )))?;
+            return Ok(());
+        }
+        SelectAction::Switch => return switch(&picked[0].name, false, is_directive_mode),
+        SelectAction::Merge => vec![merge(&picked[0])?],
+        SelectAction::Remove => remove(picked)?,
+        SelectAction::Edit => edit(picked)?,
+        SelectAction::CopyPath => copy_paths(picked)?,
+    };
+
+    let lines: Vec<String> = picked
+        .iter()
+        .zip(&outcomes)
+        .map(|(item, outcome)| match outcome {
+            Outcome::Done(what) => cformat!("<bold>{}</> <green>{what}</>", item.name),
+            Outcome::Skipped(reason) => {
+                cformat!("<bold>{}</> <dim>skipped ({reason})</>", item.name)
+            }
+            Outcome::Failed => cformat!("<bold>{}</> <red>failed</>", item.name),
+        })
+        .collect();
+    crate::output::print(info_message(format!(
+        "{} summary ({} selected)",
+        capitalize(action.name()),
+        picked.len()
+    )))?;
+    crate::output::gutter(format_with_gutter(&lines.join("\n"), "", None))?;
+    Ok(())
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Switch to `name`, or create a new branch and worktree for it with `create`
+///
+/// Without `create`, a branch without a worktree still gets one, as in `wt switch`.
+fn switch(name: &str, create: bool, is_directive_mode: bool) -> anyhow::Result<()> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+
+    // "Approve at the Gate": switch hooks, and create hooks for a branch without a worktree
+    let repo = Repository::current();
+    let repo_root = repo.worktree_base()?;
+    let hook_path = if create {
+        compute_worktree_path(&repo, name, &config)?
+    } else {
+        repo.worktree_root().unwrap_or_else(|_| repo_root.clone())
+    };
+    let ctx = CommandContext::new(&repo, &config, name, &hook_path, &repo_root, false);
+    let hook_types = [
+        HookType::PreSwitch,
+        HookType::PreCreate,
+        HookType::PostCreate,
+        HookType::PostStart,
+        HookType::PostSwitch,
+    ];
+    let approved = approve_hooks(&ctx, &hook_types)?;
+    if create && !approved {
+        crate::output::print(info_message(
+            "Commands declined, continuing worktree creation",
+        ))?;
+    }
+
+    // handle_switch can handle both branch names and worktree paths
+    let (result, resolved_branch) =
+        handle_switch(name, create, None, false, false, !approved, &config)?;
+
+    // Show success message; emit cd directive if in directive mode
+    handle_switch_output(&result, &resolved_branch, false, is_directive_mode)?;
+
+    // Like `wt switch`, post-start runs whenever a worktree was created, also
+    // for an existing branch that had none
+    if approved && let SwitchResult::Created { path, .. } = &result {
+        let ctx = CommandContext::new(&repo, &config, &resolved_branch, path, &repo_root, false);
+        ctx.spawn_post_start_commands(true)?;
+    }
+
+    if approved && !matches!(result, SwitchResult::AlreadyAt(_)) {
+        let ends = SwitchEnds::from_current(&repo, &resolved_branch, result.path());
+        execute_switch_commands(HookType::PostSwitch, &config, &ends, false)?;
+    }
+    Ok(())
+}
+
+/// Remove the picked worktrees (and their branches) through the `wt remove` path
+///
+/// Each item goes through the same safety checks as `wt remove <branch>`; a failure
+/// is reported and the remaining items are still removed. The current worktree goes
+/// last so the others aren't removed from a deleted directory.
+fn remove(picked: &[Picked]) -> anyhow::Result<Vec<Outcome>> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let repo = Repository::current();
+    let current_worktree = repo.worktree_root().ok();
+
+    // "Approve at the Gate": pre-remove and post-remove hooks, once for the batch
+    let repo_root = repo.worktree_base()?;
+    let current_path = current_worktree
+        .clone()
+        .unwrap_or_else(|| repo_root.clone());
+    let current_branch = repo.current_branch()?.unwrap_or_default();
+    let ctx = CommandContext::new(
+        &repo,
+        &config,
+        &current_branch,
+        &current_path,
+        &repo_root,
+        false,
+    );
+    let verify = approve_hooks(&ctx, &[HookType::PreRemove, HookType::PostRemove])?;
+    if !verify {
+        crate::output::print(info_message("Commands declined, continuing removal"))?;
+    }
+
+    let is_current = |item: &Picked| item.path.is_some() && item.path == current_worktree;
+    let mut outcomes: Vec<Option<Outcome>> = picked.iter().map(|_| None).collect();
+    let order = (0..picked.len())
+        .filter(|&i| !is_current(&picked[i]))
+        .chain((0..picked.len()).filter(|&i| is_current(&picked[i])));
+    for i in order {
+        let item = &picked[i];
+        let outcome = match remove_one(item, is_current(item), verify, &config) {
+            Ok(()) => Outcome::Done("removed".to_string()),
+            Err(e) => {
+                print_error(&e)?;
+                Outcome::Failed
+            }
+        };
+        outcomes[i] = Some(outcome);
+    }
+    Ok(outcomes.into_iter().flatten().collect())
+}
+
+fn remove_one(
+    item: &Picked,
+    is_current: bool,
+    verify: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let background = true;
+    let result = if is_current {
+        handle_remove_current(false, false, background)?
+    } else if let Some(branch) = &item.branch {
+        handle_remove(branch, false, false, background)?
+    } else {
+        // Detached worktree: remove by path (no branch to delete)
+        let path = item
+            .path
+            .as_deref()
+            .context("Detached item without a worktree")?;
+        handle_remove_by_path(path, None, false, background)?
+    };
+    handle_remove_output(
+        &result,
+        item.branch.as_deref(),
+        background,
+        verify,
+        StopScope::Jobs,
+        config,
+    )
+}
+
+/// Merge the picked worktree into the default branch, as `wt merge` run inside it
+fn merge(item: &Picked) -> anyhow::Result<Outcome> {
+    let Some(path) = &item.path else {
+        return Ok(Outcome::Skipped("no worktree"));
+    };
+
+    // `wt merge` acts on the worktree the process runs in
+    std::env::set_current_dir(path)
+        .with_context(|| format!("Failed to enter {}", format_path_for_display(path)))?;
+    if Repository::current().worktree_root()? != *path {
+        anyhow::bail!(
+            "Can't merge {} from here; run wt merge in its worktree",
+            item.name
+        );
+    }
+
+    // Same defaults as `wt merge` without flags
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let merge_config = config.merge.as_ref();
+    let stage = config.commit.and_then(|c| c.stage).unwrap_or_default();
+    match handle_merge(
+        None,
+        merge_config.and_then(|m| m.squash).unwrap_or(true),
+        merge_config.and_then(|m| m.commit).unwrap_or(true),
+        merge_config.and_then(|m| m.remove).unwrap_or(true),
+        merge_config.and_then(|m| m.verify).unwrap_or(true),
+        false,
+        stage,
+    ) {
+        Ok(()) => Ok(Outcome::Done("merged".to_string())),
+        Err(e) => {
+            print_error(&e)?;
+            Ok(Outcome::Failed)
+        }
+    }
+}
+
+/// Open the picked worktrees in `$VISUAL` / `$EDITOR` (one editor invocation)
+fn edit(picked: &[Picked]) -> anyhow::Result<Vec<Outcome>> {
+    let paths: Vec<&Path> = picked
+        .iter()
+        .filter_map(|item| item.path.as_deref())
+        .collect();
+    if paths.is_empty() {
+        return Ok(picked
+            .iter()
+            .map(|_| Outcome::Skipped("no worktree"))
+            .collect());
+    }
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    // Through the shell so editors with arguments (`code --wait`) work; stdout goes to
+    // stderr since stdout carries directives in directive mode
+    crate::output::flush()?;
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$@\""))
+        .arg("sh")
+        .args(&paths)
+        .stdout(std::io::stderr())
+        .status()
+        .with_context(|| format!("Failed to run editor {editor}"))?;
+
+    let opened = status.success();
+    if !opened {
+        crate::output::print(warning_message(cformat!(
+            "Editor <bold>{editor}</> exited with {status}"
+        )))?;
+    }
+    Ok(picked
+        .iter()
+        .map(|item| match (&item.path, opened) {
+            (None, _) => Outcome::Skipped("no worktree"),
+            (Some(_), true) => Outcome::Done("opened".to_string()),
+            (Some(_), false) => Outcome::Failed,
+        })
+        .collect())
+}
+
+/// Clipboard commands, tried in order
+const CLIPBOARD_COMMANDS: [&[&str]; 4] = [
+    &["pbcopy"],
+    &["wl-copy"],
+    &["xclip", "-selection", "clipboard"],
+    &["xsel", "--clipboard", "--input"],
+];
+
+/// Copy the paths of the picked worktrees to the clipboard, one per line
+fn copy_paths(picked: &[Picked]) -> anyhow::Result<Vec<Outcome>> {
+    let paths: Vec<String> = picked
+        .iter()
+        .filter_map(|item| item.path.as_ref())
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    if paths.is_empty() {
+        return Ok(picked
+            .iter()
+            .map(|_| Outcome::Skipped("no worktree"))
+            .collect());
+    }
+
+    let copied = match copy_to_clipboard(&paths.join("\n")) {
+        Ok(()) => true,
+        Err(e) => {
+            print_error(&e)?;
+            false
+        }
+    };
+    Ok(picked
+        .iter()
+        .map(|item| match (&item.path, copied) {
+            (None, _) => Outcome::Skipped("no worktree"),
+            (Some(path), true) => Outcome::Done(format_path_for_display(path)),
+            (Some(_), false) => Outcome::Failed,
+        })
+        .collect())
+}
+
+fn copy_to_clipboard(text: &str) -> anyhow::Result<()> {
+    let Some(command) = CLIPBOARD_COMMANDS
+        .iter()
+        .find(|command| which::which(command[0]).is_ok())
+    else {
+        anyhow::bail!("No clipboard command found (pbcopy, wl-copy, xclip or xsel)");
+    };
+
+    let mut child = Command::new(command[0])
+        .args(&command[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {}", command[0]))?;
+    child
+        .stdin
+        .take()
+        .context("Clipboard command has no stdin")?
+        .write_all(text.as_bytes())?;
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("{} exited with {status}", command[0]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_action_from_event() {
+        assert_eq!(
+            SelectAction::from_event(&Event::EvActAccept(None)),
+            Some(SelectAction::Switch)
+        );
+        let keys = SelectKeys::default();
+        for action in KEYED_ACTIONS {
+            let binding = format!("{}:accept({})", action.key(&keys), action.name());
+            assert!(SelectAction::bindings(&keys).any(|b| b == binding));
+            assert_eq!(
+                SelectAction::from_event(&Event::EvActAccept(Some(action.name().to_string()))),
+                Some(action)
+            );
+        }
+        assert_eq!(
+            SelectAction::from_event(&Event::EvActAccept(Some("unknown".to_string()))),
+            None
+        );
+        assert_eq!(SelectAction::from_event(&Event::EvActAbort), None);
+    }
+
+    #[test]
+    fn test_select_action_configured_keys() {
+        let mut keys = SelectKeys::default();
+        keys.remove = Some("ctrl-x".to_string());
+        assert_eq!(SelectAction::Remove.key(&keys), "ctrl-x");
+        assert_eq!(SelectAction::Merge.key(&keys), "alt-m");
+        assert!(SelectAction::bindings(&keys).any(|b| b == "ctrl-x:accept(remove)"));
+        assert!(SelectAction::legend(&keys).contains("ctrl-x: remove"));
+    }
+}
@@ -1,4 +1,3 @@
-use anyhow::Context;
 use skim::prelude::*;
 use std::borrow::Cow;
 use std::fs;
@@ -6,13 +5,16 @@ use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
-use worktrunk::config::WorktrunkConfig;
+use worktrunk::config::{SelectConfig, SelectKeys, SelectLayout, SelectPreview, WorktrunkConfig};
 use worktrunk::git::Repository;
 
+use super::list::ci_status::PrStatus;
 use super::list::collect;
 use super::list::model::ListItem;
-use super::worktree::handle_switch;
-use crate::output::handle_switch_output;
+
+mod actions;
+
+use actions::{Picked, SelectAction};
 
 /// Cached pager command, detected once at startup.
 ///
@@ -78,9 +80,6 @@ fn pager_needs_paging_disabled(pager_cmd: &str) -> bool {
 /// If the pager takes longer than this, kill it and fall back to raw diff.
 const PAGER_TIMEOUT: Duration = Duration::from_millis(2000);
 
-/// Skim uses this percentage of terminal height.
-const SKIM_HEIGHT_PERCENT: usize = 90;
-
 /// Maximum number of list items visible in down layout before scrolling.
 const MAX_VISIBLE_ITEMS: usize = 12;
 
@@ -181,25 +180,47 @@ fn shell_escape(s: &str) -> String {
     }
 }
 
+/// Last `max_bytes` of a file, starting at a line boundary
+fn read_tail(path: &std::path::Path, max_bytes: u64) -> std::io::Result<String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = fs::File::open(path)?;
+    let start = file.metadata()?.len().saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    // Drop the partial first line when starting mid-file
+    if start > 0 {
+        let line_start = bytes.iter().position(|&b| b == b'\n').map_or(0, |i| i + 1);
+        bytes.drain(..line_start);
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
 /// Preview modes for the interactive selector
 ///
 /// Each mode shows a different aspect of the worktree:
 /// 1. WorkingTree: Uncommitted changes (git diff HEAD --stat)
 /// 2. Log: Commit history since diverging from main (git log with merge-base)
 /// 3. BranchDiff: Line diffs in commits ahead of main (git diff --stat main…)
+/// 4. Ci: Per-check CI status and PR link (fetched when the tab is first shown)
+/// 5. Logs: Tail of the branch's post-start logs in `.git/wt-logs/`
 ///
 /// Loosely aligned with `wt list` columns, though not a perfect match:
 /// - Tab 1 corresponds to "HEAD±" column
 /// - Tab 2 shows commits (related to "main↕" counts)
 /// - Tab 3 corresponds to "main…± (--full)" column
+/// - Tab 4 expands the "CI" column
 ///
-/// TODO: Consider adding tab 4 "remote±" showing diff vs upstream tracking branch
+/// TODO: Consider adding a tab "remote±" showing diff vs upstream tracking branch
 /// (unpushed commits). Would align with "Remote⇅" column in `wt list`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PreviewMode {
     WorkingTree = 1,
     Log = 2,
     BranchDiff = 3,
+    Ci = 4,
+    Logs = 5,
 }
 
 /// Typical terminal character aspect ratio (width/height).
@@ -255,15 +276,11 @@ impl PreviewLayout {
     /// For Right layout: always 50%
     /// For Down layout: dynamically sized based on item count - list gets
     /// up to MAX_VISIBLE_ITEMS lines, preview gets the rest (min 5 lines)
-    fn to_preview_window_spec(self, num_items: usize) -> String {
+    /// of the `available` rows skim takes
+    fn to_preview_window_spec(self, num_items: usize, available: usize) -> String {
         match self {
             Self::Right => "right:50%".to_string(),
             Self::Down => {
-                let height = terminal_size::terminal_size()
-                    .map(|(_, terminal_size::Height(h))| h as usize)
-                    .unwrap_or(24);
-
-                let available = height * SKIM_HEIGHT_PERCENT / 100;
                 let list_lines = LIST_CHROME_LINES + num_items.min(MAX_VISIBLE_ITEMS);
                 // Ensure preview doesn't exceed available space while trying to maintain minimum
                 let remaining = available.saturating_sub(list_lines);
@@ -275,19 +292,44 @@ impl PreviewLayout {
     }
 }
 
+impl From<SelectLayout> for PreviewLayout {
+    fn from(layout: SelectLayout) -> Self {
+        match layout {
+            SelectLayout::Auto => Self::auto_detect(),
+            SelectLayout::Right => Self::Right,
+            SelectLayout::Down => Self::Down,
+        }
+    }
+}
+
+impl From<SelectPreview> for PreviewMode {
+    fn from(preview: SelectPreview) -> Self {
+        match preview {
+            SelectPreview::Uncommitted => Self::WorkingTree,
+            SelectPreview::Log => Self::Log,
+            SelectPreview::BranchDiff => Self::BranchDiff,
+            SelectPreview::Ci => Self::Ci,
+            SelectPreview::Logs => Self::Logs,
+        }
+    }
+}
+
 impl PreviewMode {
     fn from_u8(n: u8) -> Self {
         match n {
             2 => Self::Log,
             3 => Self::BranchDiff,
+            4 => Self::Ci,
+            5 => Self::Logs,
             _ => Self::WorkingTree,
         }
     }
 }
 
-/// Preview state persistence (mode only, layout auto-detected)
+/// Preview state persistence (mode only, layout fixed at startup)
 ///
-/// State file format: Single digit representing preview mode (1=WorkingTree, 2=Log, 3=BranchDiff)
+/// State file format: Single digit representing preview mode
+/// (1=WorkingTree, 2=Log, 3=BranchDiff, 4=Ci, 5=Logs)
 struct PreviewStateData;
 
 impl PreviewStateData {
@@ -319,12 +361,12 @@ struct PreviewState {
 }
 
 impl PreviewState {
-    fn new() -> Self {
+    fn new(config: &SelectConfig) -> Self {
         let path = PreviewStateData::state_path();
-        PreviewStateData::write_mode(PreviewMode::WorkingTree);
+        PreviewStateData::write_mode(config.preview.unwrap_or_default().into());
         Self {
             path,
-            initial_layout: PreviewLayout::auto_detect(),
+            initial_layout: config.layout.unwrap_or_default().into(),
         }
     }
 }
@@ -335,6 +377,26 @@ impl Drop for PreviewState {
     }
 }
 
+/// Skim colors, before `select.colors` (see `handle_select` for the reasoning)
+const DEFAULT_COLORS: &str =
+    "fg:-1,bg:-1,header:-1,matched:108,current:237,current_bg:251,current_match:108";
+
+/// Default keys for the preview controls, unless set in `[select.keys]`
+const TOGGLE_PREVIEW_KEY: &str = "alt-p";
+const SCROLL_UP_KEY: &str = "ctrl-u";
+const SCROLL_DOWN_KEY: &str = "ctrl-d";
+
+/// Key legend shown under the preview tabs
+fn controls_legend(keys: &SelectKeys) -> String {
+    format!(
+        "Enter: switch | Esc: cancel | {}/{}: scroll | {}: toggle\nTab: mark | {}",
+        keys.scroll_up.as_deref().unwrap_or(SCROLL_UP_KEY),
+        keys.scroll_down.as_deref().unwrap_or(SCROLL_DOWN_KEY),
+        keys.toggle_preview.as_deref().unwrap_or(TOGGLE_PREVIEW_KEY),
+        SelectAction::legend(keys)
+    )
+}
+
 /// Header item for column names (non-selectable)
 struct HeaderSkimItem {
     display_text: String,
@@ -361,6 +423,12 @@ struct WorktreeSkimItem {
     display_text_with_ansi: String,
     branch_name: String,
     item: Arc<ListItem>,
+    /// Position in the list; skim keys marked items by it (the default is 0 for all)
+    index: usize,
+    /// CI status, fetched when the CI tab first shows this item
+    pr_status: OnceLock<Option<PrStatus>>,
+    /// Key legend shown under the preview tabs (shared by all items)
+    controls: Arc<str>,
 }
 
 impl SkimItem for WorktreeSkimItem {
@@ -376,11 +444,19 @@ impl SkimItem for WorktreeSkimItem {
         Cow::Borrowed(&self.branch_name)
     }
 
+    fn get_index(&self) -> usize {
+        self.index
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
     fn preview(&self, context: PreviewContext<'_>) -> ItemPreview {
         let mode = PreviewStateData::read_mode();
 
         // Build preview: tabs header + content
-        let mut result = Self::render_preview_tabs(mode);
+        let mut result = self.render_preview_tabs(mode);
         result.push_str(&self.preview_for_mode(mode, context.width, context.height));
 
         ItemPreview::AnsiText(result)
@@ -388,12 +464,20 @@ impl SkimItem for WorktreeSkimItem {
 }
 
 impl WorktreeSkimItem {
+    fn picked(&self) -> Picked {
+        Picked {
+            name: self.branch_name.clone(),
+            branch: self.item.branch.clone(),
+            path: self.item.worktree_path().cloned(),
+        }
+    }
+
     /// Render the tab header for the preview window
     ///
     /// Shows all preview modes as tabs, with the current mode bolded
     /// and unselected modes dimmed. Controls shown below in normal text
     /// for visual distinction from inactive tabs.
-    fn render_preview_tabs(mode: PreviewMode) -> String {
+    fn render_preview_tabs(&self, mode: PreviewMode) -> String {
         use anstyle::Style;
 
         /// Format a tab label with bold (active) or dimmed (inactive) styling
@@ -409,6 +493,8 @@ impl WorktreeSkimItem {
         let tab1 = format_tab("1: HEAD±", mode == PreviewMode::WorkingTree);
         let tab2 = format_tab("2: log", mode == PreviewMode::Log);
         let tab3 = format_tab("3: main…±", mode == PreviewMode::BranchDiff);
+        let tab4 = format_tab("4: CI", mode == PreviewMode::Ci);
+        let tab5 = format_tab("5: logs", mode == PreviewMode::Logs);
 
         // Controls use dim yellow to distinguish from dimmed (white) tabs
         // while remaining subdued
@@ -416,12 +502,16 @@ impl WorktreeSkimItem {
             .dimmed()
             .fg_color(Some(anstyle::Color::Ansi(anstyle::AnsiColor::Yellow)));
         let controls = format!(
-            "{}Enter: switch | Esc: cancel | ctrl-u/d: scroll | alt-p: toggle{}",
+            "{}{}{}",
             controls_style.render(),
+            self.controls,
             controls_style.render_reset()
         );
 
-        format!("{} | {} | {}\n{}\n\n", tab1, tab2, tab3, controls)
+        format!(
+            "{} | {} | {} | {} | {}\n{}\n\n",
+            tab1, tab2, tab3, tab4, tab5, controls
+        )
     }
 
     /// Render preview for the given mode with specified dimensions
@@ -430,6 +520,8 @@ impl WorktreeSkimItem {
             PreviewMode::WorkingTree => self.render_working_tree_preview(width),
             PreviewMode::Log => self.render_log_preview(width, height),
             PreviewMode::BranchDiff => self.render_branch_diff_preview(width),
+            PreviewMode::Ci => self.render_ci_preview(),
+            PreviewMode::Logs => self.render_logs_preview(height),
         }
     }
 
@@ -515,6 +607,113 @@ impl WorktreeSkimItem {
         )
     }
 
+    /// Render Tab 4: CI status of each check, with the PR link
+    ///
+    /// Select skips CI collection, so the status is fetched (through the same cache
+    /// as `wt list`) the first time this tab shows the item.
+    fn render_ci_preview(&self) -> String {
+        use anstyle::{Color, Style};
+        use worktrunk::styling::INFO_EMOJI;
+
+        let branch = self.item.branch_name();
+        let Some(local_branch) = self.item.branch.as_deref() else {
+            return format!("{INFO_EMOJI} {branch} is not on a branch, so it has no CI\n");
+        };
+        let status = self.pr_status.get_or_init(|| {
+            let repo = Repository::current();
+            let repo_path = repo.worktree_root().ok()?;
+            let has_upstream = repo.upstream_branch(local_branch).ok().flatten().is_some();
+            PrStatus::detect(local_branch, self.item.head(), &repo_path, has_upstream)
+        });
+        let Some(status) = status else {
+            return format!("{INFO_EMOJI} {branch} has no CI\n");
+        };
+
+        let dim = Style::new().dimmed();
+        let source = match status.source {
+            super::list::ci_status::CiSource::PullRequest => "pull request",
+            super::list::ci_status::CiSource::Branch => "branch workflow",
+        };
+        let mut output = format!(
+            "{} CI {} {dim}({source}){dim:#}\n",
+            status.format_indicator(),
+            status.ci_status.label()
+        );
+        if status.is_stale {
+            output.push_str(&format!(
+                "{dim}Local HEAD differs from the commit CI ran on{dim:#}\n"
+            ));
+        }
+        if let Some(url) = &status.url {
+            output.push_str(url);
+            output.push('\n');
+        }
+
+        if !status.checks.is_empty() {
+            output.push('\n');
+            let name_width = status
+                .checks
+                .iter()
+                .map(|check| check.name.chars().count())
+                .max()
+                .unwrap_or(0);
+            for check in &status.checks {
+                let style = Style::new().fg_color(Some(Color::Ansi(check.status.color())));
+                output.push_str(&format!(
+                    "{style}●{style:#} {:<name_width$}  {dim}{}{dim:#}\n",
+                    check.name,
+                    check.status.label()
+                ));
+            }
+        }
+
+        output
+    }
+
+    /// Render Tab 5: Tail of each post-start log of the branch
+    fn render_logs_preview(&self, height: usize) -> String {
+        use worktrunk::path::format_path_for_display;
+        use worktrunk::styling::INFO_EMOJI;
+        // Tab header takes 4 lines (tabs + two control lines + blank)
+        const HEADER_LINES: usize = 4;
+        // Show at least this many lines of each log, even when they don't all fit
+        const MIN_TAIL_LINES: usize = 3;
+        // Logs aren't capped; only read their end
+        const TAIL_BYTES: u64 = 64 * 1024;
+
+        let branch = self.item.branch_name();
+        let logs = Repository::current()
+            .git_common_dir()
+            .ok()
+            .and_then(|dir| super::logs::post_start_logs(&dir.join("wt-logs"), branch).ok())
+            .unwrap_or_default();
+        if logs.is_empty() {
+            return format!("{INFO_EMOJI} {branch} has no post-start logs\n");
+        }
+
+        // Split the preview between the logs; each one also takes a title and a blank line
+        let tail_lines = (height.saturating_sub(HEADER_LINES) / logs.len())
+            .saturating_sub(2)
+            .max(MIN_TAIL_LINES);
+        let bold = anstyle::Style::new().bold();
+        let dim = anstyle::Style::new().dimmed();
+        let mut output = String::new();
+        for (operation, path) in logs {
+            output.push_str(&format!(
+                "{bold}{operation}{bold:#} {dim}{}{dim:#}\n",
+                format_path_for_display(&path)
+            ));
+            let content = read_tail(&path, TAIL_BYTES).unwrap_or_default();
+            let lines: Vec<&str> = content.lines().collect();
+            for line in &lines[lines.len().saturating_sub(tail_lines)..] {
+                output.push_str(line);
+                output.push('\n');
+            }
+            output.push('\n');
+        }
+        output
+    }
+
     /// Render Tab 2: Log preview
     fn render_log_preview(&self, width: usize, height: usize) -> String {
         use worktrunk::styling::INFO_EMOJI;
@@ -694,13 +893,15 @@ impl WorktreeSkimItem {
 pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
     let repo = Repository::current();
 
-    // Initialize preview mode state file (auto-cleanup on drop)
-    let state = PreviewState::new();
-
-    // Load config (or use default) for path mismatch detection
+    // Load config (or use default) for path mismatch detection and the [select] section
     let config = WorktrunkConfig::load()
         .inspect_err(|e| log::warn!("Config load failed, using defaults: {}", e))
         .unwrap_or_default();
+    let select_config = config.select.clone().unwrap_or_default();
+    let keys = select_config.keys.clone().unwrap_or_default();
+
+    // Initialize preview mode state file (auto-cleanup on drop)
+    let state = PreviewState::new(&select_config);
 
     // Gather list data using simplified collection (buffered mode)
     // Skip expensive operations not needed for select UI
@@ -708,6 +909,7 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
         collect::TaskKind::BranchDiff,
         collect::TaskKind::CiStatus,
         collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::BackgroundJobs,
     ]
     .into_iter()
     .collect();
@@ -746,10 +948,12 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
     let header_plain_text = header_line.plain_text();
 
     // Convert to skim items using the layout system for rendering
+    let controls: Arc<str> = controls_legend(&keys).into();
     let mut items: Vec<Arc<dyn SkimItem>> = list_data
         .items
         .into_iter()
-        .map(|item| {
+        .enumerate()
+        .map(|(index, item)| {
             let branch_name = item.branch_name().to_string();
 
             // Use layout system to render the line - this handles all column alignment
@@ -762,6 +966,10 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
                 display_text_with_ansi,
                 branch_name,
                 item: Arc::new(item),
+                // The header row goes first
+                index: index + 1,
+                pr_status: OnceLock::new(),
+                controls: Arc::clone(&controls),
             }) as Arc<dyn SkimItem>
         })
         .collect();
@@ -778,22 +986,30 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
     // Get state path for key bindings
     let state_path_str = state.path.display().to_string();
 
-    // Calculate half-page scroll: skim uses 90% of terminal height, half of that = 45%
-    let half_page = terminal_size::terminal_size()
-        .map(|(_, terminal_size::Height(h))| (h as usize * 45 / 100).max(5))
-        .unwrap_or(10);
+    // Rows skim takes (90% of the terminal by default; validated when the config loads)
+    let terminal_rows = terminal_size::terminal_size()
+        .map(|(_, terminal_size::Height(h))| h as usize)
+        .unwrap_or(24);
+    let skim_rows = select_config
+        .height_rows(terminal_rows)
+        .unwrap_or(terminal_rows * 90 / 100);
+
+    // Calculate half-page scroll: half of skim's height
+    let half_page = (skim_rows / 2).max(5);
 
-    // Calculate preview window spec based on auto-detected layout
+    // Calculate preview window spec based on the configured or auto-detected layout
     // items.len() - 1 because we added a header row
     let num_items = items.len().saturating_sub(1);
-    let preview_window_spec = state.initial_layout.to_preview_window_spec(num_items);
+    let preview_window_spec = state
+        .initial_layout
+        .to_preview_window_spec(num_items, skim_rows);
 
     // Configure skim options with Rust-based preview and mode switching keybindings
     let options = SkimOptionsBuilder::default()
-        .height("90%".to_string())
+        .height(select_config.height().to_string())
         .layout("reverse".to_string())
         .header_lines(1) // Make first line (header) non-selectable
-        .multi(false)
+        .multi(true) // Tab marks items for the action keys
         .no_info(true) // Hide info line (matched/total counter)
         .preview(Some("".to_string())) // Enable preview (empty string means use SkimItem::preview())
         .preview_window(preview_window_spec)
@@ -810,31 +1026,56 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
         // - On dark terminals: light gray highlight stands out clearly
         // - On light terminals: light gray is subtle but visible
         // - Dark text (237) ensures readability regardless of terminal theme
-        .color(Some(
-            "fg:-1,bg:-1,header:-1,matched:108,current:237,current_bg:251,current_match:108"
-                .to_string(),
-        ))
-        .bind(vec![
-            // Mode switching (1/2/3 keys change preview content)
-            format!(
-                "1:execute-silent(echo 1 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "2:execute-silent(echo 2 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "3:execute-silent(echo 3 > {0})+refresh-preview",
-                state_path_str
-            ),
-            // Preview toggle (alt-p shows/hides preview)
-            // Note: skim doesn't support change-preview-window like fzf, only toggle
-            "alt-p:toggle-preview".to_string(),
-            // Preview scrolling (half-page based on terminal height)
-            format!("ctrl-u:preview-up({half_page})"),
-            format!("ctrl-d:preview-down({half_page})"),
-        ])
+        //
+        // `select.colors` is appended, so its entries override these
+        .color(Some(match &select_config.colors {
+            Some(colors) => format!("{DEFAULT_COLORS},{colors}"),
+            None => DEFAULT_COLORS.to_string(),
+        }))
+        .bind(
+            vec![
+                // Mode switching (1-5 keys change preview content)
+                format!(
+                    "1:execute-silent(echo 1 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "2:execute-silent(echo 2 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "3:execute-silent(echo 3 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "4:execute-silent(echo 4 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "5:execute-silent(echo 5 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                // Preview toggle (alt-p shows/hides preview)
+                // Note: skim doesn't support change-preview-window like fzf, only toggle
+                format!(
+                    "{}:toggle-preview",
+                    keys.toggle_preview.as_deref().unwrap_or(TOGGLE_PREVIEW_KEY)
+                ),
+                // Preview scrolling (half-page based on skim's height)
+                format!(
+                    "{}:preview-up({half_page})",
+                    keys.scroll_up.as_deref().unwrap_or(SCROLL_UP_KEY)
+                ),
+                format!(
+                    "{}:preview-down({half_page})",
+                    keys.scroll_down.as_deref().unwrap_or(SCROLL_DOWN_KEY)
+                ),
+            ]
+            .into_iter()
+            // Action keys (alt-c/r/m/e/y) accept the selection with an action name
+            .chain(SelectAction::bindings(&keys))
+            .collect(),
+        )
         // Legend/controls moved to preview window tabs (render_preview_tabs)
         .no_clear(true) // Prevent skim from clearing screen, we'll do it manually
         .build()
@@ -854,19 +1095,25 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
     // Handle selection
     if let Some(out) = output
         && !out.is_abort
-        && let Some(selected) = out.selected_items.first()
+        && let Some(action) = SelectAction::from_event(&out.final_event)
     {
-        // Get branch name or worktree path from selected item
-        // (output() returns the worktree path for existing worktrees, branch name otherwise)
-        let identifier = selected.output().to_string();
-
-        // Load config
-        let config = WorktrunkConfig::load().context("Failed to load config")?;
-
-        // Switch to the selected worktree
-        // handle_switch can handle both branch names and worktree paths
-        let (result, resolved_branch) =
-            handle_switch(&identifier, false, None, false, false, &config)?;
+        let picked: Vec<Picked> = out
+            .selected_items
+            .iter()
+            .filter_map(|item| item.as_ref().as_any().downcast_ref::<WorktreeSkimItem>())
+            .map(WorktreeSkimItem::picked)
+            .collect();
+        let query = out.query.trim();
+
+        // Creating takes alt-c: on Enter, a mistyped query with no matches
+        // would otherwise create a branch and run its hooks unasked
+        let nothing_to_act_on = match action {
+            SelectAction::Create => query.is_empty(),
+            _ => picked.is_empty(),
+        };
+        if nothing_to_act_on {
+            return Ok(());
+        }
 
         // Clear the terminal screen after skim exits to prevent artifacts
         // Use stderr for terminal control sequences - in directive mode, stdout goes to a FIFO
@@ -876,8 +1123,7 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
         execute!(stderr(), terminal::Clear(terminal::ClearType::All))?;
         execute!(stderr(), crossterm::cursor::MoveTo(0, 0))?;
 
-        // Show success message; emit cd directive if in directive mode
-        handle_switch_output(&result, &resolved_branch, false, is_directive_mode)?;
+        actions::run_action(action, &picked, query, is_directive_mode)?;
     }
 
     Ok(())
@@ -887,11 +1133,24 @@ pub fn handle_select(is_directive_mode: bool) -> anyhow::Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("server.log");
+        fs::write(&path, "first\nsecond\nthird\n").unwrap();
+        assert_eq!(read_tail(&path, 1024).unwrap(), "first\nsecond\nthird\n");
+        // Starts inside `second`, so that partial line is dropped
+        assert_eq!(read_tail(&path, 9).unwrap(), "third\n");
+        assert!(read_tail(&dir.path().join("missing.log"), 1024).is_err());
+    }
+
     #[test]
     fn test_preview_mode_from_u8() {
         assert_eq!(PreviewMode::from_u8(1), PreviewMode::WorkingTree);
         assert_eq!(PreviewMode::from_u8(2), PreviewMode::Log);
         assert_eq!(PreviewMode::from_u8(3), PreviewMode::BranchDiff);
+        assert_eq!(PreviewMode::from_u8(4), PreviewMode::Ci);
+        assert_eq!(PreviewMode::from_u8(5), PreviewMode::Logs);
         // Invalid values default to WorkingTree
         assert_eq!(PreviewMode::from_u8(0), PreviewMode::WorkingTree);
         assert_eq!(PreviewMode::from_u8(99), PreviewMode::WorkingTree);
@@ -900,11 +1159,20 @@ mod tests {
     #[test]
     fn test_preview_layout_to_preview_window_spec() {
         // Right is always 50%
-        assert_eq!(PreviewLayout::Right.to_preview_window_spec(10), "right:50%");
+        assert_eq!(
+            PreviewLayout::Right.to_preview_window_spec(10, 27),
+            "right:50%"
+        );
 
-        // Down calculates based on item count
-        let spec = PreviewLayout::Down.to_preview_window_spec(5);
-        assert!(spec.starts_with("down:"));
+        // Down calculates based on item count: 27 rows - (4 chrome + 5 items)
+        assert_eq!(PreviewLayout::Down.to_preview_window_spec(5, 27), "down:18");
+        // Many items: the list caps at 12 rows
+        assert_eq!(
+            PreviewLayout::Down.to_preview_window_spec(50, 27),
+            "down:11"
+        );
+        // Tiny terminal: preview keeps its minimum
+        assert_eq!(PreviewLayout::Down.to_preview_window_spec(5, 8), "down:5");
     }
 
     #[test]
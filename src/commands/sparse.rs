@@ -0,0 +1,179 @@
+//! Sparse checkouts: configured patterns for new worktrees, and `wt step sparse`.
+//!
+//! New worktrees are created with `git worktree add --no-checkout`, given the
+//! configured cone patterns, then checked out, so only those directories (plus
+//! top-level files) are ever written. In a partial clone, blobs outside the
+//! patterns aren't fetched either.
+//!
+//! `.config` is always checked out, so the project config (and its hooks) stays
+//! available in sparse worktrees.
+
+use anyhow::bail;
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::Repository;
+use worktrunk::styling::{format_with_gutter, info_message, progress_message, success_message};
+
+use super::repository_ext::RepositoryCliExt;
+use crate::cli::SparseCommand;
+
+/// Configured sparse patterns for a new worktree of `branch`
+///
+/// The user config's `[projects."<id>".sparse]` takes precedence over the
+/// project's `[sparse]`. Returns None for a full checkout.
+pub fn configured_sparse_paths(
+    repo: &Repository,
+    config: &WorktrunkConfig,
+    branch: &str,
+) -> anyhow::Result<Option<Vec<String>>> {
+    let user_sparse = repo
+        .project_identifier()
+        .ok()
+        .and_then(|project| config.projects.get(&project))
+        .and_then(|project| project.sparse.as_ref())
+        .and_then(|sparse| sparse.paths_for(branch));
+
+    let paths = match user_sparse {
+        Some(paths) => paths.to_vec(),
+        None => {
+            let project_config = repo.load_project_config()?;
+            match project_config
+                .as_ref()
+                .and_then(|config| config.sparse.as_ref())
+                .and_then(|sparse| sparse.paths_for(branch))
+            {
+                Some(paths) => paths.to_vec(),
+                None => return Ok(None),
+            }
+        }
+    };
+
+    Ok((!paths.is_empty()).then_some(paths))
+}
+
+/// Apply cone patterns to a worktree created with `--no-checkout`, and check it out
+pub fn checkout_sparse(worktree: &Repository, paths: &[String]) -> anyhow::Result<()> {
+    crate::output::print(progress_message(cformat!(
+        "Checking out {}...",
+        format_paths(paths)
+    )))?;
+    set_sparse_paths(worktree, paths)?;
+    worktree.run_command(&["checkout"])?;
+    Ok(())
+}
+
+/// Directory of the project config, checked out in every sparse worktree
+const PROJECT_CONFIG_DIR: &str = ".config";
+
+fn set_sparse_paths(worktree: &Repository, paths: &[String]) -> anyhow::Result<()> {
+    let mut args = vec!["sparse-checkout", "set", "--cone", "--"];
+    args.extend(paths.iter().map(String::as_str));
+    if !paths.iter().any(|path| path == PROJECT_CONFIG_DIR) {
+        args.push(PROJECT_CONFIG_DIR);
+    }
+    worktree.run_command(&args)?;
+    Ok(())
+}
+
+/// `<bold>a</>, <bold>b</>`
+fn format_paths(paths: &[String]) -> String {
+    paths
+        .iter()
+        .map(|path| cformat!("<bold>{path}</>"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Handle `wt step sparse`
+pub fn handle_step_sparse(action: Option<SparseCommand>) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = repo.require_current_branch("change the sparse checkout")?;
+    let current = repo.sparse_checkout_paths()?;
+
+    let Some(action) = action else {
+        if current.is_empty() {
+            crate::output::print(info_message(cformat!(
+                "<bold>{branch}</> has a full checkout"
+            )))?;
+        } else {
+            crate::output::print(info_message(cformat!(
+                "Sparse checkout of <bold>{branch}</>:"
+            )))?;
+            crate::output::gutter(format_with_gutter(&current.join("\n"), "", None))?;
+        }
+        return Ok(());
+    };
+
+    match action {
+        SparseCommand::Add { paths } => {
+            if current.is_empty() {
+                bail!(
+                    "{branch} has a full checkout; use `wt step sparse set` to check out only some directories"
+                );
+            }
+            let mut args = vec!["sparse-checkout", "add", "--"];
+            args.extend(paths.iter().map(String::as_str));
+            repo.run_command(&args)?;
+            crate::output::print(success_message(cformat!(
+                "Added {} to the sparse checkout",
+                format_paths(&paths)
+            )))?;
+        }
+        SparseCommand::Remove { paths } => {
+            let remaining: Vec<String> = current
+                .iter()
+                .filter(|path| !paths.contains(path))
+                .cloned()
+                .collect();
+            let missing: Vec<&String> = paths.iter().filter(|p| !current.contains(p)).collect();
+            if let Some(path) = missing.first() {
+                bail!("{path} isn't in the sparse checkout of {branch}");
+            }
+            if remaining.iter().all(|path| path == PROJECT_CONFIG_DIR) {
+                bail!(
+                    "Can't remove every directory; use `wt step sparse disable` for a full checkout"
+                );
+            }
+            set_sparse_paths(&repo, &remaining)?;
+            crate::output::print(success_message(cformat!(
+                "Removed {} from the sparse checkout",
+                format_paths(&paths)
+            )))?;
+        }
+        SparseCommand::Set { paths } => {
+            set_sparse_paths(&repo, &paths)?;
+            crate::output::print(success_message(cformat!(
+                "Checked out only {}",
+                format_paths(&paths)
+            )))?;
+        }
+        SparseCommand::Reset => {
+            let config = WorktrunkConfig::load()?;
+            match configured_sparse_paths(&repo, &config, &branch)? {
+                Some(paths) => {
+                    set_sparse_paths(&repo, &paths)?;
+                    crate::output::print(success_message(cformat!(
+                        "Reset the sparse checkout to {}",
+                        format_paths(&paths)
+                    )))?;
+                }
+                None => disable(&repo, current.is_empty())?,
+            }
+        }
+        SparseCommand::Disable => disable(&repo, current.is_empty())?,
+    }
+
+    Ok(())
+}
+
+fn disable(repo: &Repository, already_full: bool) -> anyhow::Result<()> {
+    if already_full {
+        crate::output::print(info_message("Already a full checkout"))?;
+        return Ok(());
+    }
+    repo.run_command(&["sparse-checkout", "disable"])?;
+    crate::output::print(success_message(
+        "Disabled the sparse checkout; all files are checked out",
+    ))?;
+    Ok(())
+}
@@ -11,7 +11,7 @@ use worktrunk::styling::{
 };
 
 use super::command_executor::CommandContext;
-use super::commit::{CommitGenerator, CommitOptions};
+use super::commit::{CommitGenerator, CommitOptions, ReviewedCommit};
 use super::context::CommandEnv;
 use super::hooks::HookPipeline;
 use super::merge::{
@@ -19,6 +19,7 @@ use super::merge::{
 };
 use super::project_config::collect_commands_for_hooks;
 use super::repository_ext::RepositoryCliExt;
+use super::worktree::{PushRange, SwitchEnds};
 
 /// Handle `wt hook` command
 ///
@@ -56,6 +57,18 @@ pub fn run_hook(hook_type: HookType, force: bool, name_filter: Option<&str>) ->
 
     // Execute the hook based on type
     match hook_type {
+        HookType::PreCreate => {
+            let user_config = user_hook!(pre_create);
+            let project_config = project_config.as_ref().and_then(|c| c.pre_create.as_ref());
+            run_hook_with_filter(
+                &ctx,
+                user_config,
+                project_config,
+                hook_type,
+                &[],
+                name_filter,
+            )
+        }
         HookType::PostCreate => {
             let user_config = user_hook!(post_create);
             let project_config = project_config.as_ref().and_then(|c| c.post_create.as_ref());
@@ -80,6 +93,31 @@ pub fn run_hook(hook_type: HookType, force: bool, name_filter: Option<&str>) ->
                 name_filter,
             )
         }
+        HookType::PreSwitch | HookType::PostSwitch => {
+            let (user_config, project_config) = if hook_type == HookType::PreSwitch {
+                (
+                    user_hook!(pre_switch),
+                    project_config.as_ref().and_then(|c| c.pre_switch.as_ref()),
+                )
+            } else {
+                (
+                    user_hook!(post_switch),
+                    project_config.as_ref().and_then(|c| c.post_switch.as_ref()),
+                )
+            };
+            // Run standalone, the switch goes from the current worktree to itself
+            let vars = SwitchEnds::from_current(repo, ctx.branch, ctx.worktree_path).vars();
+            let extra_vars: Vec<(&str, &str)> =
+                vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            run_hook_with_filter(
+                &ctx,
+                user_config,
+                project_config,
+                hook_type,
+                &extra_vars,
+                name_filter,
+            )
+        }
         HookType::PreCommit => {
             let user_config = user_hook!(pre_commit);
             let project_config = project_config.as_ref().and_then(|c| c.pre_commit.as_ref());
@@ -106,11 +144,40 @@ pub fn run_hook(hook_type: HookType, force: bool, name_filter: Option<&str>) ->
             let project_cfg = project_config.unwrap_or_default();
             run_pre_merge_commands(&project_cfg, &ctx, &env.branch, name_filter)
         }
+        HookType::PrePush => {
+            let user_config = user_hook!(pre_push);
+            let project_config = project_config.as_ref().and_then(|c| c.pre_push.as_ref());
+            // Commits a push of the current branch to the default branch would bring in
+            let range = PushRange::new(repo, &repo.default_branch()?)?;
+            let vars = range.vars();
+            let extra_vars: Vec<(&str, &str)> =
+                vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            run_hook_with_filter(
+                &ctx,
+                user_config,
+                project_config,
+                hook_type,
+                &extra_vars,
+                name_filter,
+            )
+        }
         HookType::PostMerge => {
             // Use current branch as target (matches approval prompt for wt hook)
             execute_post_merge_commands(&ctx, &env.branch, name_filter)
         }
         HookType::PreRemove => execute_pre_remove_commands(&ctx, name_filter),
+        HookType::PostRemove => {
+            let user_config = user_hook!(post_remove);
+            let project_config = project_config.as_ref().and_then(|c| c.post_remove.as_ref());
+            run_hook_with_filter(
+                &ctx,
+                user_config,
+                project_config,
+                hook_type,
+                &[],
+                name_filter,
+            )
+        }
     }
 }
 
@@ -199,6 +266,7 @@ pub fn step_commit(
     force: bool,
     no_verify: bool,
     stage_mode: super::commit::StageMode,
+    review: bool,
 ) -> anyhow::Result<()> {
     use super::command_approval::approve_hooks;
 
@@ -225,6 +293,7 @@ pub fn step_commit(
     options.no_verify = no_verify;
     options.stage_mode = stage_mode;
     options.show_no_squash_note = false;
+    options.review = review;
     // Only warn about untracked if we're staging all
     options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
 
@@ -249,11 +318,13 @@ pub enum SquashResult {
 /// # Arguments
 /// * `skip_pre_commit` - If true, skip all pre-commit hooks (both user and project)
 /// * `stage_mode` - What to stage before committing (All or Tracked; None not supported for squash)
+/// * `review` - Let the user review the generated message before committing
 pub fn handle_squash(
     target: Option<&str>,
     force: bool,
     skip_pre_commit: bool,
     stage_mode: super::commit::StageMode,
+    review: bool,
 ) -> anyhow::Result<SquashResult> {
     use super::commit::StageMode;
 
@@ -261,7 +332,11 @@ pub fn handle_squash(
     let repo = &env.repo;
     let current_branch = env.branch.clone();
     let ctx = env.context(force);
-    let generator = CommitGenerator::new(&env.config.commit_generation);
+    let generator = CommitGenerator::new(
+        &env.config.commit_generation,
+        review,
+        ReviewedCommit::Squash,
+    );
 
     // Get target branch (default to default branch if not provided)
     let target_branch = repo.resolve_target_branch(target)?;
@@ -395,31 +470,45 @@ pub fn handle_squash(
     // Get commit subjects for the squash message
     let subjects = repo.commit_subjects(&range)?;
 
-    // Generate squash commit message
-    crate::output::print(progress_message("Generating squash commit message..."))?;
-
-    generator.emit_hint_if_needed()?;
-
     // Get current branch and repo name for template variables
     let repo_root = repo.worktree_root()?;
     let repo_name = repo_root
         .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("repo");
+    let generate = |hint: Option<&str>| {
+        crate::llm::generate_squash_message(
+            &target_branch,
+            &merge_base,
+            &subjects,
+            &current_branch,
+            repo_name,
+            &env.config.commit_generation,
+            hint,
+        )
+    };
 
-    let commit_message = crate::llm::generate_squash_message(
-        &target_branch,
-        &merge_base,
-        &subjects,
-        &current_branch,
-        repo_name,
-        &env.config.commit_generation,
-    )?;
+    // Generate squash commit message, unless resuming an aborted review
+    let commit_message = match generator.saved_message(repo)? {
+        Some(message) => {
+            crate::output::print(info_message(
+                "Using the squash commit message from the aborted review",
+            ))?;
+            message
+        }
+        None => {
+            crate::output::print(progress_message("Generating squash commit message..."))?;
+            generator.emit_hint_if_needed()?;
+            generate(None)?
+        }
+    };
 
     // Display the generated commit message
     let formatted_message = generator.format_message_for_display(&commit_message);
     crate::output::gutter(format_with_gutter(&formatted_message, "", None))?;
 
+    let commit_message = generator.review_message(repo, commit_message, generate)?;
+
     // Reset to merge base (soft reset stages all changes, including any already-staged uncommitted changes)
     repo.run_command(&["reset", "--soft", &merge_base])
         .context("Failed to reset to merge base")?;
@@ -435,6 +524,7 @@ pub fn handle_squash(
     // Commit with the generated message
     repo.run_command(&["commit", "-m", &commit_message])
         .context("Failed to create squash commit")?;
+    generator.clear_saved_message(repo)?;
 
     // Get commit hash for display
     let commit_hash = repo
@@ -548,11 +638,17 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
 
     // Collect all commands from the project config
     let all_hooks = [
+        HookType::PreCreate,
         HookType::PostCreate,
         HookType::PostStart,
+        HookType::PreSwitch,
+        HookType::PostSwitch,
         HookType::PreCommit,
         HookType::PreMerge,
+        HookType::PrePush,
         HookType::PostMerge,
+        HookType::PreRemove,
+        HookType::PostRemove,
     ];
     let commands = collect_commands_for_hooks(&project_config, &all_hooks);
 
@@ -660,12 +756,17 @@ pub fn handle_hook_show(hook_type_filter: Option<&str>, expanded: bool) -> anyho
 
     // Parse hook type filter if provided
     let filter: Option<HookType> = hook_type_filter.map(|s| match s {
+        "pre-create" => HookType::PreCreate,
         "post-create" => HookType::PostCreate,
         "post-start" => HookType::PostStart,
+        "pre-switch" => HookType::PreSwitch,
+        "post-switch" => HookType::PostSwitch,
         "pre-commit" => HookType::PreCommit,
         "pre-merge" => HookType::PreMerge,
+        "pre-push" => HookType::PrePush,
         "post-merge" => HookType::PostMerge,
         "pre-remove" => HookType::PreRemove,
+        "post-remove" => HookType::PostRemove,
         _ => unreachable!("clap validates hook type"),
     });
 
@@ -726,12 +827,17 @@ fn render_user_hooks(
 
     // Collect all user hooks
     let hooks = [
+        (HookType::PreCreate, &config.pre_create),
         (HookType::PostCreate, &config.post_create),
         (HookType::PostStart, &config.post_start),
+        (HookType::PreSwitch, &config.pre_switch),
+        (HookType::PostSwitch, &config.post_switch),
         (HookType::PreCommit, &config.pre_commit),
         (HookType::PreMerge, &config.pre_merge),
+        (HookType::PrePush, &config.pre_push),
         (HookType::PostMerge, &config.post_merge),
         (HookType::PreRemove, &config.pre_remove),
+        (HookType::PostRemove, &config.post_remove),
     ];
 
     let mut has_any = false;
@@ -789,12 +895,17 @@ fn render_project_hooks(
 
     // Collect all project hooks
     let hooks = [
+        (HookType::PreCreate, &config.pre_create),
         (HookType::PostCreate, &config.post_create),
         (HookType::PostStart, &config.post_start),
+        (HookType::PreSwitch, &config.pre_switch),
+        (HookType::PostSwitch, &config.post_switch),
         (HookType::PreCommit, &config.pre_commit),
         (HookType::PreMerge, &config.pre_merge),
+        (HookType::PrePush, &config.pre_push),
         (HookType::PostMerge, &config.post_merge),
         (HookType::PreRemove, &config.pre_remove),
+        (HookType::PostRemove, &config.post_remove),
     ];
 
     let mut has_any = false;
@@ -880,6 +991,13 @@ fn expand_command_template(template: &str, ctx: &CommandContext, hook_type: Hook
 
     // Build extra vars based on hook type (same logic as run_hook approval)
     let default_branch = ctx.repo.default_branch().ok();
+    let switch_vars = matches!(hook_type, HookType::PreSwitch | HookType::PostSwitch)
+        .then(|| SwitchEnds::from_current(ctx.repo, ctx.branch, ctx.worktree_path).vars());
+    let push_vars = default_branch
+        .as_deref()
+        .filter(|_| hook_type == HookType::PrePush)
+        .and_then(|target| PushRange::new(ctx.repo, target).ok())
+        .map(|range| range.vars());
     let extra_vars: Vec<(&str, &str)> = match hook_type {
         HookType::PreCommit => {
             // Pre-commit uses default branch as target (for comparison context)
@@ -893,6 +1011,22 @@ fn expand_command_template(template: &str, ctx: &CommandContext, hook_type: Hook
             // Pre-merge and post-merge use current branch as target
             vec![("target", ctx.branch)]
         }
+        HookType::PrePush => {
+            // Pushing the current branch to the default branch (as `wt hook pre-push` does)
+            push_vars
+                .iter()
+                .flatten()
+                .map(|(k, v)| (*k, v.as_str()))
+                .collect()
+        }
+        HookType::PreSwitch | HookType::PostSwitch => {
+            // Switching from the current worktree to itself (as `wt hook pre-switch` does)
+            switch_vars
+                .iter()
+                .flatten()
+                .map(|(k, v)| (*k, v.as_str()))
+                .collect()
+        }
         _ => Vec::new(),
     };
     let template_ctx = build_hook_context(ctx, &extra_vars);
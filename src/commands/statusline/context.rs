@@ -0,0 +1,292 @@
+//! Agent context readers for `wt list statusline --context <name>`.
+//!
+//! Coding agents and editor terminals pipe a JSON payload to their statusline
+//! command. A reader maps that payload to the two fields the statusline uses:
+//! the working directory and the model name. Claude Code's reader is built in;
+//! `[statusline.context.<name>]` in the user config maps any other agent's
+//! payload, or overrides the built-in one.
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use std::io::{self, Read};
+use std::time::Duration;
+use worktrunk::config::{StatuslineConfig, StatuslineContext};
+
+/// Built-in readers as (name, dir path, model path)
+const BUILTIN_READERS: &[(&str, &str, Option<&str>)] = &[
+    // https://docs.claude.com/en/docs/claude-code/statusline
+    (
+        "claude-code",
+        "$.workspace.current_dir",
+        Some("$.model.display_name"),
+    ),
+];
+
+/// Context parsed from an agent's stdin JSON
+#[derive(Debug, PartialEq)]
+pub(super) struct AgentContext {
+    /// Working directory (`.` when the payload has none)
+    pub current_dir: String,
+    pub model_name: Option<String>,
+}
+
+/// One step of a field path
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Reads an agent's stdin JSON, with the paths of the fields the statusline uses
+#[derive(Debug)]
+pub(super) struct ContextReader {
+    dir: Vec<Segment>,
+    model: Option<Vec<Segment>>,
+}
+
+impl ContextReader {
+    /// Reader named `name`, from the user config or the built-ins
+    pub fn resolve(name: &str, config: Option<&StatuslineConfig>) -> Result<Self> {
+        if let Some(mapping) = config.and_then(|config| config.context.get(name)) {
+            return Self::from_mapping(mapping)
+                .with_context(|| format!("Invalid statusline.context.{name}"));
+        }
+
+        let Some((_, dir, model)) = BUILTIN_READERS
+            .iter()
+            .find(|(builtin, _, _)| *builtin == name)
+        else {
+            let mut names: Vec<&str> = BUILTIN_READERS.iter().map(|(name, _, _)| *name).collect();
+            if let Some(config) = config {
+                names.extend(config.context.keys().map(String::as_str));
+            }
+            bail!(
+                "Unknown statusline context: {name}. Available: {}",
+                names.join(", ")
+            );
+        };
+
+        Ok(Self {
+            dir: parse_path(dir).expect("built-in paths are valid"),
+            model: model.map(|model| parse_path(model).expect("built-in paths are valid")),
+        })
+    }
+
+    fn from_mapping(mapping: &StatuslineContext) -> Result<Self> {
+        let dir = parse_path(&mapping.dir).map_err(|e| anyhow::anyhow!("dir: {e}"))?;
+        let model = mapping
+            .model
+            .as_deref()
+            .map(parse_path)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("model: {e}"))?;
+        Ok(Self { dir, model })
+    }
+
+    /// Parse a payload. Returns None if it's empty or not valid JSON.
+    pub fn parse(&self, input: &str) -> Option<AgentContext> {
+        if input.is_empty() {
+            return None;
+        }
+
+        let json: Value = serde_json::from_str(input).ok()?;
+
+        let current_dir = lookup(&json, &self.dir)
+            .and_then(Value::as_str)
+            .unwrap_or(".")
+            .to_string();
+
+        let model_name = self
+            .model
+            .as_ref()
+            .and_then(|path| lookup(&json, path))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        Some(AgentContext {
+            current_dir,
+            model_name,
+        })
+    }
+
+    /// Try to read and parse the payload from stdin.
+    /// Returns None if stdin is empty or not valid JSON.
+    pub fn read_stdin(&self) -> Option<AgentContext> {
+        // Non-blocking read with timeout
+        // Agents pipe JSON to stdin, but we shouldn't block if nothing is there
+        use std::sync::mpsc;
+        use std::thread;
+
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut input = String::new();
+            let _ = io::stdin().read_to_string(&mut input);
+            let _ = tx.send(input);
+        });
+
+        // Wait up to 10ms for stdin
+        let input = rx.recv_timeout(Duration::from_millis(10)).ok()?;
+
+        self.parse(&input)
+    }
+}
+
+/// Parse a JSONPath-style path: `$.a.b`, `a.b`, `a[0].b`
+fn parse_path(path: &str) -> Result<Vec<Segment>, String> {
+    let rest = path.strip_prefix('$').unwrap_or(path);
+    let rest = rest.strip_prefix('.').unwrap_or(rest);
+    if rest.is_empty() {
+        return Err(format!("empty path `{path}`"));
+    }
+
+    let mut segments = Vec::new();
+    for part in rest.split('.') {
+        let (key, mut indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if key.is_empty() && indices.is_empty() {
+            return Err(format!("empty key in `{path}`"));
+        }
+        if !key.is_empty() {
+            segments.push(Segment::Key(key.to_string()));
+        }
+        while !indices.is_empty() {
+            let index = indices
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .and_then(|(index, rest)| Some((index.parse().ok()?, rest)));
+            let Some((index, rest)) = index else {
+                return Err(format!("invalid index in `{path}`"));
+            };
+            segments.push(Segment::Index(index));
+            indices = rest;
+        }
+    }
+    Ok(segments)
+}
+
+fn lookup<'a>(json: &'a Value, path: &[Segment]) -> Option<&'a Value> {
+    path.iter().try_fold(json, |value, segment| match segment {
+        Segment::Key(key) => value.get(key),
+        Segment::Index(index) => value.get(index),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builtin(name: &str) -> ContextReader {
+        ContextReader::resolve(name, None).unwrap()
+    }
+
+    fn custom(toml: &str) -> Result<ContextReader> {
+        let config: StatuslineConfig = toml::from_str(toml).unwrap();
+        ContextReader::resolve("custom", Some(&config))
+    }
+
+    #[test]
+    fn test_claude_code_fixture() {
+        let input = include_str!("../../../tests/fixtures/statusline-context/claude-code.json");
+        let ctx = builtin("claude-code").parse(input).expect("should parse");
+        assert_eq!(ctx.current_dir, "/home/user/project");
+        assert_eq!(ctx.model_name.as_deref(), Some("Opus"));
+    }
+
+    #[test]
+    fn test_custom_fixture() {
+        let input = include_str!("../../../tests/fixtures/statusline-context/custom.json");
+        let reader = custom(
+            r#"
+[context.custom]
+dir = "$.session.roots[0].path"
+model = "session.agent.model"
+"#,
+        )
+        .unwrap();
+        let ctx = reader.parse(input).expect("should parse");
+        assert_eq!(ctx.current_dir, "/home/user/project");
+        assert_eq!(ctx.model_name.as_deref(), Some("Sonnet"));
+
+        // The built-in reader finds nothing in a different payload
+        let ctx = builtin("claude-code").parse(input).expect("should parse");
+        assert_eq!(ctx.current_dir, ".");
+        assert_eq!(ctx.model_name, None);
+    }
+
+    #[test]
+    fn test_config_overrides_builtin() {
+        let config: StatuslineConfig = toml::from_str(
+            r#"
+[context.claude-code]
+dir = "cwd"
+"#,
+        )
+        .unwrap();
+        let reader = ContextReader::resolve("claude-code", Some(&config)).unwrap();
+        let ctx = reader
+            .parse(r#"{"cwd": "/tmp/test", "model": {"display_name": "Opus"}}"#)
+            .expect("should parse");
+        assert_eq!(ctx.current_dir, "/tmp/test");
+        assert_eq!(ctx.model_name, None);
+    }
+
+    #[test]
+    fn test_claude_code_parse_missing_fields() {
+        // Model is optional
+        let ctx = builtin("claude-code")
+            .parse(r#"{"workspace": {"current_dir": "/tmp/test"}}"#)
+            .expect("should parse");
+        assert_eq!(ctx.current_dir, "/tmp/test");
+        assert_eq!(ctx.model_name, None);
+
+        // Missing workspace defaults to "."
+        let ctx = builtin("claude-code")
+            .parse(r#"{"model": {"display_name": "Sonnet"}}"#)
+            .expect("should parse");
+        assert_eq!(ctx.current_dir, ".");
+        assert_eq!(ctx.model_name.as_deref(), Some("Sonnet"));
+    }
+
+    #[test]
+    fn test_parse_empty_or_invalid() {
+        let reader = builtin("claude-code");
+        assert!(reader.parse("").is_none());
+        assert!(reader.parse("not json").is_none());
+        assert!(reader.parse("{invalid}").is_none());
+    }
+
+    #[test]
+    fn test_unknown_reader() {
+        let err = ContextReader::resolve("nope", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown statusline context: nope. Available: claude-code"
+        );
+    }
+
+    #[test]
+    fn test_parse_path() {
+        use Segment::{Index, Key};
+        assert_eq!(
+            parse_path("$.a.b").unwrap(),
+            vec![Key("a".into()), Key("b".into())]
+        );
+        assert_eq!(parse_path("a").unwrap(), vec![Key("a".into())]);
+        assert_eq!(
+            parse_path("a[0][2].b").unwrap(),
+            vec![Key("a".into()), Index(0), Index(2), Key("b".into())]
+        );
+        assert_eq!(parse_path("$[1]").unwrap(), vec![Index(1)]);
+
+        assert!(parse_path("$").is_err());
+        assert!(parse_path("a..b").is_err());
+        assert!(parse_path("a[x]").is_err());
+        assert!(parse_path("a[0").is_err());
+
+        let err = custom("[context.custom]\ndir = \"a[x]\"").unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "Invalid statusline.context.custom: dir: invalid index in `a[x]`"
+        );
+    }
+}
@@ -0,0 +1,602 @@
+//! Statusline output for shell prompts and editors.
+//!
+//! Outputs a single-line status for the current worktree:
+//! `branch  status  ±working  commits  upstream  [ci]`
+//!
+//! This command reuses the data collection infrastructure from `wt list`,
+//! avoiding duplication of git operations.
+
+mod context;
+
+use crate::output;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{SystemTime, UNIX_EPOCH};
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::Repository;
+
+use super::list::ci_status::CachedCiStatus;
+use super::list::model::ListItem;
+use super::list::{self, CollectOptions, JsonItem, collect::TaskKind};
+use super::worktree::compute_worktree_path;
+use crate::cli::StatuslineFormat;
+use context::ContextReader;
+
+/// Format a directory path in fish-style (abbreviated parent directories).
+///
+/// Examples:
+/// - `/home/user/workspace/project` -> `~/w/project`
+/// - `/home/user` -> `~`
+/// - `/tmp/test` -> `/t/test`
+fn format_directory_fish_style(path: &str) -> String {
+    use std::path::{Component, PathBuf};
+
+    let path = PathBuf::from(path);
+
+    // Replace $HOME prefix with ~
+    let (suffix, tilde_prefix) = env::var_os("HOME")
+        .and_then(|home| {
+            path.strip_prefix(&home)
+                .ok()
+                .map(|s| (s.to_path_buf(), true))
+        })
+        .unwrap_or_else(|| (path.clone(), false));
+
+    // Collect normal components (skip RootDir, CurDir, etc.)
+    let components: Vec<_> = suffix
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(s) => Some(s.to_string_lossy()),
+            _ => None,
+        })
+        .collect();
+
+    // Build result: ~/a/b/last or /a/b/last
+    let abbreviated = components
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            if i == components.len() - 1 {
+                s.to_string() // Keep last component full
+            } else {
+                s.chars().next().map(String::from).unwrap_or_default()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match (tilde_prefix, abbreviated.is_empty()) {
+        (true, true) => "~".to_string(),
+        (true, false) => format!("~/{}", abbreviated.join("/")),
+        (false, _) if path.is_absolute() => format!("/{}", abbreviated.join("/")),
+        (false, _) => abbreviated.join("/"),
+    }
+}
+
+/// Git state of the worktree the statusline describes
+enum GitStatus {
+    /// Full status, from the same collection as `wt list`
+    Item(Box<ListItem>),
+    /// Only the branch (outside a listed worktree, or without a default branch)
+    Branch(String),
+}
+
+impl GitStatus {
+    fn branch(&self) -> &str {
+        match self {
+            Self::Item(item) => item.branch_name(),
+            Self::Branch(branch) => branch,
+        }
+    }
+
+    /// Branch the snapshot is cached under (None for detached HEAD)
+    fn cache_key(&self) -> Option<&str> {
+        match self {
+            Self::Item(item) => item.branch.as_deref(),
+            Self::Branch(branch) => Some(branch),
+        }
+    }
+}
+
+/// Values available to the `[statusline] template`, with ANSI styling
+///
+/// Fields that don't apply (no CI, nothing uncommitted) are empty strings,
+/// so `{% if ci %}` works.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TemplateFields {
+    branch: String,
+    status: String,
+    working: String,
+    ahead: usize,
+    behind: usize,
+    commits: String,
+    upstream: String,
+    ci: String,
+}
+
+/// Everything the statusline shows for a worktree
+///
+/// Each full run caches its snapshot in `.git/wt-cache/statusline/<branch>.json`
+/// (branch escaped the way CI status cache keys are), so `--cached` can print it
+/// without running git status or CI lookups.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedStatusline {
+    /// Unix timestamp when the snapshot was taken
+    pub checked_at: u64,
+    /// Path the `worktree-path` template gives the branch
+    worktree_path: Option<PathBuf>,
+    /// The fixed `branch  status  @working  commits  upstream  ci` line
+    line: String,
+    fields: TemplateFields,
+    /// `--format json` output, the branch's entry in `wt list --format=json`
+    json: serde_json::Value,
+}
+
+impl CachedStatusline {
+    /// Seconds before `--cached` refreshes a snapshot in the background
+    const REFRESH_SECS: u64 = 10;
+
+    fn new(repo: &Repository, config: &WorktrunkConfig, git_status: &GitStatus) -> Self {
+        let branch = git_status.branch().to_string();
+        let worktree_path = compute_worktree_path(repo, &branch, config).ok();
+        let checked_at = now_secs();
+
+        let GitStatus::Item(item) = git_status else {
+            return Self {
+                checked_at,
+                worktree_path,
+                line: branch.clone(),
+                json: serde_json::json!({ "branch": branch }),
+                fields: TemplateFields {
+                    branch,
+                    status: String::new(),
+                    working: String::new(),
+                    ahead: 0,
+                    behind: 0,
+                    commits: String::new(),
+                    upstream: String::new(),
+                    ci: String::new(),
+                },
+            };
+        };
+
+        let counts = item.counts();
+        let fields = TemplateFields {
+            status: item
+                .status_symbols
+                .as_ref()
+                .map(|symbols| symbols.format_compact())
+                .unwrap_or_default(),
+            working: item.working_diff_plain().unwrap_or_default(),
+            ahead: counts.ahead,
+            behind: counts.behind,
+            commits: item.display.commits_display.clone().unwrap_or_default(),
+            upstream: item.display.upstream_display.clone().unwrap_or_default(),
+            ci: item
+                .pr_status
+                .as_ref()
+                .and_then(|pr_status| pr_status.as_ref())
+                .map(|pr_status| pr_status.format_indicator())
+                .unwrap_or_default(),
+            branch: branch.clone(),
+        };
+
+        Self {
+            checked_at,
+            worktree_path,
+            line: item.display.statusline.clone().unwrap_or(branch),
+            fields,
+            json: serde_json::to_value(JsonItem::from_list_item(item)).unwrap_or_default(),
+        }
+    }
+
+    /// Directory holding one snapshot file per branch
+    fn cache_dir(repo: &Repository) -> Option<PathBuf> {
+        let dir = repo.git_common_dir().ok()?;
+        Some(dir.join("wt-cache").join("statusline"))
+    }
+
+    fn cache_file(dir: &Path, branch: &str) -> PathBuf {
+        dir.join(format!("{}.json", CachedCiStatus::escape_branch(branch)))
+    }
+
+    /// Read the cached snapshot for `branch`
+    fn read(repo: &Repository, branch: &str) -> Option<Self> {
+        let path = Self::cache_file(&Self::cache_dir(repo)?, branch);
+        let json = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Write the snapshot
+    ///
+    /// Goes through a temporary file, so prompts rendering meanwhile never read
+    /// half a snapshot.
+    fn write(&self, repo: &Repository, branch: &str) {
+        let Some(dir) = Self::cache_dir(repo) else {
+            return;
+        };
+        let Ok(json) = serde_json::to_string(self) else {
+            log::debug!("Failed to serialize statusline cache for {}", branch);
+            return;
+        };
+        let path = Self::cache_file(&dir, branch);
+        let tmp = path.with_extension(format!("json.{}", std::process::id()));
+        let result = fs::create_dir_all(&dir)
+            .and_then(|()| fs::write(&tmp, json))
+            .and_then(|()| fs::rename(&tmp, &path));
+        if let Err(e) = result {
+            log::debug!("Failed to write statusline cache for {}: {}", branch, e);
+            let _ = fs::remove_file(&tmp);
+        }
+    }
+
+    /// Cache files of all branches as (branch_name, path) pairs
+    fn files(repo: &Repository) -> Vec<(String, PathBuf)> {
+        let Some(entries) = Self::cache_dir(repo).and_then(|dir| fs::read_dir(dir).ok()) else {
+            return Vec::new();
+        };
+        let mut files: Vec<_> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                let escaped = path.file_name()?.to_str()?.strip_suffix(".json")?;
+                Some((CachedCiStatus::unescape_branch(escaped), path))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// Remove snapshots of branches that no longer exist
+    fn prune(repo: &Repository) {
+        let Ok(branches) = repo.list_local_branches() else {
+            return;
+        };
+        let branches: HashSet<String> = branches.into_iter().map(|(name, _)| name).collect();
+        for (branch, path) in Self::files(repo) {
+            if !branches.contains(&branch) {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+
+    /// Read the current branch's snapshot, refreshing it in the background when stale
+    ///
+    /// Returns immediately: a missing snapshot shows nothing until the refresh lands.
+    fn load(repo: &Repository, cwd: &Path) -> Option<Self> {
+        let branch = repo.current_branch().ok()??;
+        let cached = Self::read(repo, &branch);
+
+        let now = now_secs();
+        let is_fresh = cached
+            .as_ref()
+            .is_some_and(|c| now.saturating_sub(c.checked_at) < Self::REFRESH_SECS);
+        if !is_fresh {
+            // Claim the refresh first, so prompts rendered meanwhile don't spawn their own
+            if let Some(cached) = &cached {
+                Self {
+                    checked_at: now,
+                    ..cached.clone()
+                }
+                .write(repo, &branch);
+            }
+            spawn_refresh(cwd);
+        }
+
+        cached
+    }
+
+    /// List all cached snapshots as (branch_name, snapshot) pairs
+    pub(crate) fn list_all(repo: &Repository) -> Vec<(String, Self)> {
+        Self::files(repo)
+            .into_iter()
+            .filter_map(|(branch, path)| {
+                let json = fs::read_to_string(path).ok()?;
+                let cached: Self = serde_json::from_str(&json).ok()?;
+                Some((branch, cached))
+            })
+            .collect()
+    }
+
+    /// Clear all cached snapshots, returns count cleared
+    pub(crate) fn clear_all(repo: &Repository) -> usize {
+        Self::files(repo)
+            .into_iter()
+            .filter(|(_, path)| fs::remove_file(path).is_ok())
+            .count()
+    }
+
+    /// Whether `cwd` is the worktree path of the branch, in which case the
+    /// directory already shows the branch
+    fn dir_shows_branch(&self, cwd: &Path) -> bool {
+        let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.worktree_path
+            .as_deref()
+            .is_some_and(|expected| canonical(expected) == canonical(cwd))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Run `wt list statusline --refresh` in the background, which re-caches the snapshot
+fn spawn_refresh(cwd: &Path) {
+    let Ok(exe) = env::current_exe() else {
+        return;
+    };
+    let mut cmd = std::process::Command::new(exe);
+    cmd.args(["list", "statusline", "--refresh"])
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    // Own process group, so Ctrl-C at the prompt doesn't cut the refresh short
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    if let Err(e) = cmd.spawn() {
+        log::debug!("Failed to spawn statusline refresh: {}", e);
+    }
+}
+
+/// Run the statusline command.
+///
+/// Uses the output system like other commands:
+/// - Interactive mode: output goes to stdout
+/// - Directive mode: output goes to stderr, keeping stdout clean for shell directives
+///
+/// With `context`, reads an agent's stdin JSON through the named reader (see
+/// [`ContextReader`]) and adds the directory and model. With `cached`, prints the
+/// last snapshot instead of collecting status (see [`CachedStatusline`]). Only
+/// `refresh` writes snapshots, so a plain render stays read-only.
+pub fn run(
+    context: Option<&str>,
+    cached: bool,
+    refresh: bool,
+    format: StatuslineFormat,
+) -> Result<()> {
+    // A broken config shouldn't break the prompt; fall back to the fixed layout
+    let config = WorktrunkConfig::load()
+        .inspect_err(|e| log::warn!("Config load failed, using defaults: {}", e))
+        .unwrap_or_default();
+
+    // Get context - either from stdin (agent mode) or current directory
+    let agent = context.is_some();
+    let (cwd, model_name) = if let Some(name) = context {
+        let reader = ContextReader::resolve(name, config.statusline.as_ref())?;
+        let ctx = reader.read_stdin();
+        let current_dir = ctx
+            .as_ref()
+            .map(|c| c.current_dir.clone())
+            .unwrap_or_else(|| env::current_dir().unwrap_or_default().display().to_string());
+        let model = ctx.and_then(|c| c.model_name);
+        (Path::new(&current_dir).to_path_buf(), model)
+    } else {
+        (
+            env::current_dir().context("Failed to get current directory")?,
+            None,
+        )
+    };
+
+    // Git status
+    let repo = Repository::at(&cwd);
+    let snapshot = if cached {
+        CachedStatusline::load(&repo, &cwd)
+    } else if repo.git_dir().is_ok() {
+        get_git_status(&repo, &cwd)?.map(|git_status| {
+            let snapshot = CachedStatusline::new(&repo, &config, &git_status);
+            if refresh && let Some(branch) = git_status.cache_key() {
+                snapshot.write(&repo, branch);
+                CachedStatusline::prune(&repo);
+            }
+            snapshot
+        })
+    } else {
+        None
+    };
+
+    if matches!(format, StatuslineFormat::Json) {
+        if let Some(snapshot) = &snapshot {
+            let json = serde_json::to_string_pretty(&snapshot.json)
+                .context("Failed to serialize to JSON")?;
+            output::data(json)?;
+        }
+        return Ok(());
+    }
+
+    let dir = format_directory_fish_style(&cwd.display().to_string());
+
+    let template = config
+        .statusline
+        .as_ref()
+        .and_then(|statusline| statusline.template.as_deref());
+    let output = if let Some(template) = template {
+        render_template(template, snapshot.as_ref(), &dir, model_name.as_deref())?
+    } else {
+        // Build output string
+        let mut output = String::new();
+
+        // Directory (agent mode only)
+        if agent {
+            output = dir.clone();
+        }
+
+        if let Some(snapshot) = &snapshot {
+            // In agent mode, skip the branch name if the directory is the
+            // worktree path the `worktree-path` template gives the branch
+            let status_to_show = if agent && snapshot.dir_shows_branch(&cwd) {
+                snapshot
+                    .line
+                    .strip_prefix(snapshot.fields.branch.as_str())
+                    .map(|rest| rest.trim_start())
+                    .unwrap_or(&snapshot.line)
+            } else {
+                &snapshot.line
+            };
+
+            if !status_to_show.is_empty() {
+                if !output.is_empty() {
+                    output.push_str("  ");
+                }
+                output.push_str(status_to_show);
+            }
+        }
+
+        // Model name (agent mode only)
+        if let Some(model) = model_name {
+            output.push_str("  | ");
+            output.push_str(&model);
+        }
+
+        output
+    };
+
+    // Output via output system (goes to stderr, like `wt list`)
+    if !output.is_empty() {
+        if agent {
+            use std::io::Write;
+            use worktrunk::styling::fix_dim_after_color_reset;
+            let reset = anstyle::Reset;
+            // Bypass anstream - write directly to stdout to preserve ANSI codes
+            // regardless of TTY detection (agents like Claude Code expect raw ANSI)
+            let output = fix_dim_after_color_reset(&output);
+            writeln!(std::io::stdout(), "{reset} {output}")?;
+            std::io::stdout().flush()?;
+        } else {
+            output::data(output)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Render the `[statusline] template` from the user config
+///
+/// Besides the [`TemplateFields`], templates get `dir` and `model` (empty outside
+/// `--context`). Surrounding whitespace is trimmed.
+fn render_template(
+    template: &str,
+    snapshot: Option<&CachedStatusline>,
+    dir: &str,
+    model: Option<&str>,
+) -> Result<String> {
+    let fields = snapshot.map(|snapshot| minijinja::Value::from_serialize(&snapshot.fields));
+    let env = minijinja::Environment::new();
+    let rendered = env
+        .render_str(
+            template,
+            minijinja::context! {
+                dir,
+                model => model.unwrap_or_default(),
+                ..fields.unwrap_or_default()
+            },
+        )
+        .context("Failed to render statusline template")?;
+    Ok(rendered.trim().to_string())
+}
+
+/// Get git status for the current worktree
+fn get_git_status(repo: &Repository, cwd: &Path) -> Result<Option<GitStatus>> {
+    // Get current worktree info
+    let worktrees = repo.list_worktrees()?;
+    // Deepest match, since a `worktree-path` template can nest worktrees inside the main one
+    let current_worktree = worktrees
+        .worktrees
+        .iter()
+        .filter(|wt| cwd.starts_with(&wt.path))
+        .max_by_key(|wt| wt.path.components().count());
+
+    let Some(wt) = current_worktree else {
+        // Not in a worktree - just show branch name
+        if let Ok(Some(branch)) = repo.current_branch() {
+            return Ok(Some(GitStatus::Branch(branch)));
+        }
+        return Ok(None);
+    };
+
+    // Get default branch for comparisons
+    let default_branch = match repo.default_branch() {
+        Ok(b) => b,
+        Err(_) => {
+            // Can't determine default branch - just show current branch
+            return Ok(Some(GitStatus::Branch(
+                wt.branch.as_deref().unwrap_or("HEAD").to_string(),
+            )));
+        }
+    };
+
+    // Determine if this is the main worktree
+    let main_worktree = worktrees
+        .worktrees
+        .iter()
+        .find(|w| w.branch.as_deref() == Some(default_branch.as_str()))
+        .unwrap_or_else(|| worktrees.main());
+    let is_main = wt.path == main_worktree.path;
+
+    // Build item with identity fields
+    let mut items = vec![list::build_worktree_item(wt, is_main, true, false)];
+
+    // Populate computed fields (parallel git operations) and format status_line
+    list::populate_items(
+        &mut items,
+        &default_branch,
+        CollectOptions {
+            // Statusline: fetch CI, skip merge-tree conflicts and background jobs
+            skip_tasks: [TaskKind::MergeTreeConflicts, TaskKind::BackgroundJobs]
+                .into_iter()
+                .collect(),
+        },
+    )?;
+
+    Ok(Some(GitStatus::Item(Box::new(items.remove(0)))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_directory_fish_style() {
+        // Test absolute paths (Unix-style paths only meaningful on Unix)
+        #[cfg(unix)]
+        {
+            assert_eq!(format_directory_fish_style("/tmp/test"), "/t/test");
+            assert_eq!(format_directory_fish_style("/"), "/");
+            assert_eq!(format_directory_fish_style("/var/log/app"), "/v/l/app");
+        }
+
+        // Test with actual HOME (if set)
+        if let Ok(home) = env::var("HOME") {
+            // Basic home substitution
+            let test_path = format!("{home}/workspace/project");
+            let result = format_directory_fish_style(&test_path);
+            assert!(result.starts_with("~/"), "Expected ~ prefix, got: {result}");
+            assert!(
+                result.ends_with("/project"),
+                "Expected /project suffix, got: {result}"
+            );
+
+            // Exact HOME path should become just ~
+            assert_eq!(format_directory_fish_style(&home), "~");
+
+            // Path that shares HOME as string prefix but not as path component
+            // e.g., /home/user vs /home/usered/nested
+            let path_outside_home = format!("{home}ed/nested");
+            let result = format_directory_fish_style(&path_outside_home);
+            assert!(
+                !result.starts_with("~"),
+                "Path sharing HOME string prefix should not use ~: {result}"
+            );
+        }
+    }
+}
@@ -112,7 +112,7 @@
 
 use color_print::cformat;
 use normalize_path::NormalizePath;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use worktrunk::HookType;
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::{GitError, Repository, ResolvedWorktree};
@@ -121,6 +121,7 @@ use worktrunk::styling::{
     warning_message,
 };
 
+use super::command_approval::approve_hooks;
 use super::command_executor::CommandContext;
 use super::hooks::{HookFailureStrategy, HookPipeline, HookSource};
 use super::repository_ext::RepositoryCliExt;
@@ -290,10 +291,100 @@ pub enum RemoveResult {
     },
 }
 
+/// Resolve the branch to switch to, and the remote branch to create it from
+///
+/// `<remote>/<branch>` checks out `<branch>` tracking that remote's branch. A plain
+/// name that exists only on remotes tracks the one remote that has it; when several
+/// do, git's `checkout.defaultRemote` picks, and otherwise the choice is an error.
+/// Local branches are returned unchanged, with no remote branch.
+///
+/// With `fetch`, the remote branch is fetched first.
+fn resolve_remote_branch(
+    repo: &Repository,
+    name: &str,
+    fetch: bool,
+) -> anyhow::Result<(String, Option<String>)> {
+    if repo.local_branch_exists(name)? {
+        return Ok((name.to_string(), None));
+    }
+
+    let (remote, branch) = match repo.split_remote_ref(name)? {
+        Some((remote, branch)) => {
+            if fetch {
+                fetch_remote_branch(repo, &remote, &branch)?;
+            }
+            if repo.local_branch_exists(&branch)? {
+                // An existing branch is switched to as-is; say if it tracks something else
+                let upstream = repo.upstream_branch(&branch)?;
+                if upstream.as_deref() != Some(name) {
+                    crate::output::print(warning_message(cformat!(
+                        "Branch <bold>{branch}</> already exists locally{}; switching to it",
+                        upstream
+                            .map(|upstream| cformat!(", tracking <bold>{upstream}</>"))
+                            .unwrap_or_default()
+                    )))?;
+                }
+                return Ok((branch, None));
+            }
+            (remote, branch)
+        }
+        None => {
+            if fetch {
+                for remote in repo.remotes()? {
+                    // Most remotes won't have the branch
+                    if let Err(e) = fetch_remote_branch(repo, &remote, name) {
+                        log::debug!("Not fetching {name} from {remote}: {e}");
+                    }
+                }
+            }
+            let remotes = repo.remotes_with_branch(name)?;
+            let remote = match remotes.as_slice() {
+                [] => return Ok((name.to_string(), None)),
+                [remote] => remote.clone(),
+                _ => match repo.get_config("checkout.defaultRemote")? {
+                    Some(default) if remotes.contains(&default) => default,
+                    _ => {
+                        return Err(GitError::AmbiguousRemoteBranch {
+                            branch: name.to_string(),
+                            remotes,
+                        }
+                        .into());
+                    }
+                },
+            };
+            (remote, name.to_string())
+        }
+    };
+
+    if !repo.remote_branch_exists(&remote, &branch)? {
+        return Err(GitError::InvalidReference {
+            reference: name.to_string(),
+        }
+        .into());
+    }
+
+    let remote_branch = format!("{remote}/{branch}");
+    Ok((branch, Some(remote_branch)))
+}
+
+/// Fetch `branch` from `remote` into its remote-tracking ref
+fn fetch_remote_branch(repo: &Repository, remote: &str, branch: &str) -> anyhow::Result<()> {
+    crate::output::print(progress_message(cformat!(
+        "Fetching <bold>{branch}</> from <bold>{remote}</>..."
+    )))?;
+    repo.run_command(&[
+        "fetch",
+        remote,
+        &format!("+refs/heads/{branch}:refs/remotes/{remote}/{branch}"),
+    ])?;
+    Ok(())
+}
+
 pub fn handle_switch(
     branch: &str,
     create: bool,
     base: Option<&str>,
+    fetch: bool,
     force: bool,
     no_verify: bool,
     config: &WorktrunkConfig,
@@ -307,6 +398,13 @@ pub fn handle_switch(
     // Resolve special branch names ("@" for current, "-" for previous)
     let resolved_branch = repo.resolve_worktree_name(branch)?;
 
+    // Resolve remote branches (`origin/feature`) to the local branch that tracks them
+    let (resolved_branch, track_remote) = if create {
+        (resolved_branch, None)
+    } else {
+        resolve_remote_branch(&repo, &resolved_branch, fetch)?
+    };
+
     // Record actual current branch as new "previous" for ping-pong behavior
     let new_previous = actual_current_branch;
 
@@ -346,28 +444,50 @@ pub fn handle_switch(
         ))?;
     }
 
+    // With --create, --fetch updates a remote base (`--base origin/main`)
+    if create
+        && fetch
+        && let Some(base) = &resolved_base
+        && let Some((remote, base_branch)) = repo.split_remote_ref(base)?
+    {
+        fetch_remote_branch(&repo, &remote, &base_branch)?;
+    }
+
     // Compute expected worktree path for this branch
     let expected_path = compute_worktree_path(&repo, &resolved_branch, config)?;
 
-    // Helper to build switch result for an existing worktree
-    let switch_to_existing = |path: PathBuf, branch: String| -> (SwitchResult, String) {
-        let canonical_path = path.canonicalize().unwrap_or(path);
-        let current_dir = std::env::current_dir()
-            .ok()
-            .and_then(|p| p.canonicalize().ok());
-        let already_at_worktree = current_dir
-            .as_ref()
-            .map(|cur| cur == &canonical_path)
-            .unwrap_or(false);
-
-        let result = if already_at_worktree {
-            SwitchResult::AlreadyAt(canonical_path)
-        } else {
-            SwitchResult::Existing(canonical_path)
-        };
-        (result, branch)
+    // Run pre-switch commands in the worktree being left (fail-fast: aborts the switch)
+    let run_pre_switch = |new_worktree: &Path, new_branch: &str| -> anyhow::Result<()> {
+        if no_verify {
+            return Ok(());
+        }
+        // Approval was handled at the gate
+        let ends = SwitchEnds::from_current(&repo, new_branch, new_worktree);
+        execute_switch_commands(HookType::PreSwitch, config, &ends, force)
     };
 
+    // Helper to switch to an existing worktree
+    let switch_to_existing =
+        |path: PathBuf, branch: String| -> anyhow::Result<(SwitchResult, String)> {
+            let canonical_path = path.canonicalize().unwrap_or(path);
+            let current_dir = std::env::current_dir()
+                .ok()
+                .and_then(|p| p.canonicalize().ok());
+            let already_at_worktree = current_dir
+                .as_ref()
+                .map(|cur| cur == &canonical_path)
+                .unwrap_or(false);
+
+            let result = if already_at_worktree {
+                SwitchResult::AlreadyAt(canonical_path)
+            } else {
+                run_pre_switch(&canonical_path, &branch)?;
+                SwitchResult::Existing(canonical_path)
+            };
+            let _ = repo.record_switch_previous(new_previous.as_deref());
+            Ok((result, branch))
+        };
+
     // Path-first lookup: check if a worktree exists at the expected path
     if let Some((existing_path, path_branch)) = repo.worktree_at_path(&expected_path)? {
         // Check if directory actually exists (git might have stale metadata)
@@ -376,16 +496,14 @@ pub fn handle_switch(
             return Err(GitError::WorktreeMissing { branch }.into());
         }
         // Worktree exists at expected path - switch to it regardless of its branch
-        let _ = repo.record_switch_previous(new_previous.as_deref());
         let actual_branch = path_branch.unwrap_or_else(|| resolved_branch.clone());
-        return Ok(switch_to_existing(existing_path, actual_branch));
+        return switch_to_existing(existing_path, actual_branch);
     }
 
     // Fallback: check if branch has a worktree at a different path
     match repo.worktree_for_branch(&resolved_branch)? {
         Some(existing_path) if existing_path.exists() => {
-            let _ = repo.record_switch_previous(new_previous.as_deref());
-            return Ok(switch_to_existing(existing_path, resolved_branch));
+            return switch_to_existing(existing_path, resolved_branch);
         }
         Some(_) => {
             return Err(GitError::WorktreeMissing {
@@ -408,6 +526,8 @@ pub fn handle_switch(
         .into());
     }
 
+    run_pre_switch(&worktree_path, &resolved_branch)?;
+
     // Create the worktree
     // Build git worktree add command
     let mut args = vec!["worktree", "add", worktree_path.to_str().unwrap()];
@@ -429,10 +549,38 @@ pub fn handle_switch(
         if let Some(ref base_branch) = base_for_creation {
             args.push(base_branch);
         }
+    } else if let Some(ref remote_branch) = track_remote {
+        args.extend(["--track", "-b", &resolved_branch, remote_branch]);
     } else {
         args.push(&resolved_branch);
     }
 
+    // Sparse worktrees are checked out after the patterns are applied
+    let sparse_paths = super::sparse::configured_sparse_paths(&repo, config, &resolved_branch)?;
+    if sparse_paths.is_some() {
+        args.push("--no-checkout");
+    }
+
+    // Execute pre-create commands (sequential, blocking)
+    // Non-zero exit vetoes the creation; approval was handled at the gate
+    if !no_verify {
+        execute_pre_create_commands(
+            &repo,
+            config,
+            &resolved_branch,
+            &worktree_path,
+            base_for_creation.as_deref(),
+            force,
+        )?;
+    }
+
+    // `--track -b` and git's DWIM checkout of a remote-only branch create the
+    // branch too, so check before `worktree add` what a rollback must delete
+    let new_branch = match sparse_paths {
+        Some(_) if !repo.local_branch_exists(&resolved_branch)? => Some(resolved_branch.as_str()),
+        _ => None,
+    };
+
     // Create worktree and parse specific error cases
     if let Err(e) = repo.run_command(&args) {
         let msg = e.to_string();
@@ -471,8 +619,15 @@ pub fn handle_switch(
         .into());
     }
 
-    // Check if git's DWIM created a tracking branch from a remote
-    // This happens when we don't use --create and the branch exists on a remote
+    if let Some(paths) = &sparse_paths
+        && let Err(err) = super::sparse::checkout_sparse(&Repository::at(&worktree_path), paths)
+    {
+        // Otherwise the worktree stays behind without files, and switching again fails
+        rollback_worktree(&repo, &worktree_path, new_branch)?;
+        return Err(err);
+    }
+
+    // Report the tracking branch when the branch was created from a remote
     let from_remote = if !create {
         // Query the new worktree for its upstream tracking branch
         let worktree_repo = Repository::at(&worktree_path);
@@ -589,6 +744,270 @@ pub fn handle_remove_by_path(
     })
 }
 
+/// Undo creating a worktree whose checkout failed
+///
+/// Removes the worktree, and the branch when it was created along with it. When
+/// that fails too, warns with the commands to clean up by hand.
+fn rollback_worktree(
+    repo: &Repository,
+    worktree_path: &Path,
+    created_branch: Option<&str>,
+) -> anyhow::Result<()> {
+    let path = worktree_path.to_string_lossy();
+    let mut cleanup = Vec::new();
+    if repo
+        .run_command(&["worktree", "remove", "--force", &path])
+        .is_err()
+    {
+        cleanup.push(format!("git worktree remove --force {path}"));
+    }
+    if let Some(branch) = created_branch
+        && (!cleanup.is_empty() || repo.run_command(&["branch", "-D", branch]).is_err())
+    {
+        cleanup.push(format!("git branch -D {branch}"));
+    }
+    if !cleanup.is_empty() {
+        crate::output::print(warning_message(
+            "Failed to undo creating the worktree; to clean up, run:",
+        ))?;
+        crate::output::gutter(format_with_gutter(&cleanup.join("\n"), "", None))?;
+    }
+    Ok(())
+}
+
+/// Execute pre-create commands sequentially (blocking, fail-fast)
+///
+/// Runs user hooks first, then project hooks, in the current worktree since the new
+/// one doesn't exist yet. `{{ worktree }}` and `{{ worktree_name }}` describe the
+/// worktree about to be created; `{{ base }}` is the base of a new branch.
+/// Non-zero exit aborts the creation.
+fn execute_pre_create_commands(
+    repo: &Repository,
+    config: &WorktrunkConfig,
+    branch: &str,
+    worktree_path: &Path,
+    base: Option<&str>,
+    force: bool,
+) -> anyhow::Result<()> {
+    let repo_root = repo.worktree_base()?;
+    let current = repo.worktree_root().unwrap_or_else(|_| repo_root.clone());
+    let ctx = CommandContext::new(repo, config, branch, &current, &repo_root, force);
+    let pipeline = HookPipeline::new(ctx);
+
+    let worktree = worktree_path.to_string_lossy();
+    let worktree_name = worktree_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let mut extra_vars = vec![("worktree", &*worktree), ("worktree_name", &*worktree_name)];
+    extra_vars.extend(base.map(|base| ("base", base)));
+
+    // Run user hooks first (no approval required)
+    if let Some(user_config) = &config.pre_create {
+        pipeline.run_sequential(
+            user_config,
+            HookType::PreCreate,
+            HookSource::User,
+            &extra_vars,
+            HookFailureStrategy::FailFast,
+            None,
+        )?;
+    }
+
+    // Then run project hooks (approval checked at gate, not here)
+    let project_config = repo.load_project_config()?;
+    if let Some(pre_create_config) = project_config.as_ref().and_then(|c| c.pre_create.as_ref()) {
+        pipeline.run_sequential(
+            pre_create_config,
+            HookType::PreCreate,
+            HookSource::Project,
+            &extra_vars,
+            HookFailureStrategy::FailFast,
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+/// The worktrees on either side of a switch
+///
+/// Passed to pre-switch and post-switch commands as `{{ previous_branch }}`,
+/// `{{ previous_worktree }}`, `{{ new_branch }}` and `{{ new_worktree }}`.
+pub struct SwitchEnds {
+    /// Branch being left (None for detached HEAD)
+    pub previous_branch: Option<String>,
+    /// Worktree being left (None when not run from inside a worktree)
+    pub previous_worktree: Option<PathBuf>,
+    pub new_branch: String,
+    pub new_worktree: PathBuf,
+}
+
+impl SwitchEnds {
+    /// A switch from the worktree `repo` was opened in
+    pub fn from_current(repo: &Repository, new_branch: &str, new_worktree: &Path) -> Self {
+        Self {
+            previous_branch: repo.current_branch().ok().flatten(),
+            previous_worktree: repo.worktree_root().ok(),
+            new_branch: new_branch.to_string(),
+            new_worktree: new_worktree.to_path_buf(),
+        }
+    }
+
+    /// Template variables; empty strings for an unknown previous worktree
+    pub fn vars(&self) -> [(&'static str, String); 4] {
+        [
+            (
+                "previous_branch",
+                self.previous_branch.clone().unwrap_or_default(),
+            ),
+            (
+                "previous_worktree",
+                self.previous_worktree
+                    .as_ref()
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            ),
+            ("new_branch", self.new_branch.clone()),
+            (
+                "new_worktree",
+                self.new_worktree.to_string_lossy().into_owned(),
+            ),
+        ]
+    }
+}
+
+/// Execute pre-switch or post-switch commands sequentially (blocking)
+///
+/// Runs user hooks first, then project hooks. pre-switch commands run in the worktree
+/// being left and abort the switch on failure (skipped when there is no such worktree);
+/// post-switch commands run in the worktree switched to, and failures only warn.
+/// Approval is handled at the gate (command entry point).
+pub fn execute_switch_commands(
+    hook_type: HookType,
+    config: &WorktrunkConfig,
+    ends: &SwitchEnds,
+    force: bool,
+) -> anyhow::Result<()> {
+    let (worktree, branch, failure_strategy) = match hook_type {
+        HookType::PreSwitch => {
+            let Some(worktree) = &ends.previous_worktree else {
+                return Ok(());
+            };
+            let branch = ends.previous_branch.as_deref().unwrap_or("HEAD");
+            (worktree, branch, HookFailureStrategy::FailFast)
+        }
+        _ => (
+            &ends.new_worktree,
+            ends.new_branch.as_str(),
+            HookFailureStrategy::Warn,
+        ),
+    };
+
+    let repo = Repository::at(worktree);
+    let repo_root = repo.worktree_base()?;
+    let ctx = CommandContext::new(&repo, config, branch, worktree, &repo_root, force);
+    let pipeline = HookPipeline::new(ctx);
+    let vars = ends.vars();
+    let extra_vars: Vec<(&str, &str)> = vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+    // Run user hooks first (no approval required)
+    if let Some(user_config) = config.hook(hook_type) {
+        pipeline.run_sequential(
+            user_config,
+            hook_type,
+            HookSource::User,
+            &extra_vars,
+            failure_strategy,
+            None,
+        )?;
+    }
+
+    // Then run project hooks, from the config approved at the gate (the current worktree's)
+    let Some(project_config) = Repository::current().load_project_config()? else {
+        return Ok(());
+    };
+    if let Some(project_hook) = project_config.hook(hook_type) {
+        pipeline.run_sequential(
+            project_hook,
+            hook_type,
+            HookSource::Project,
+            &extra_vars,
+            failure_strategy,
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+/// The commits a push fast-forwards the target branch by
+///
+/// Passed to pre-push commands as `{{ target }}`, `{{ commit_range }}` and
+/// `{{ commit_count }}`.
+pub struct PushRange {
+    pub target: String,
+    /// `<target sha>..<head sha>`
+    pub commit_range: String,
+    pub commit_count: usize,
+}
+
+impl PushRange {
+    /// The commits on HEAD that `target` doesn't have
+    pub fn new(repo: &Repository, target: &str) -> anyhow::Result<Self> {
+        let target_sha = repo.run_command(&["rev-parse", target])?;
+        let head_sha = repo.run_command(&["rev-parse", "HEAD"])?;
+        Ok(Self {
+            target: target.to_string(),
+            commit_range: format!("{}..{}", target_sha.trim(), head_sha.trim()),
+            commit_count: repo.count_commits(target, "HEAD")?,
+        })
+    }
+
+    /// Template variables
+    pub fn vars(&self) -> [(&'static str, String); 3] {
+        [
+            ("target", self.target.clone()),
+            ("commit_range", self.commit_range.clone()),
+            ("commit_count", self.commit_count.to_string()),
+        ]
+    }
+}
+
+/// Execute pre-push commands sequentially (blocking, fail-fast)
+///
+/// Runs user hooks first, then project hooks. Any failure aborts the push.
+/// Approval is handled at the gate (command entry point).
+pub fn execute_pre_push_commands(ctx: &CommandContext, range: &PushRange) -> anyhow::Result<()> {
+    let pipeline = HookPipeline::new(*ctx);
+    let vars = range.vars();
+    let extra_vars: Vec<(&str, &str)> = vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+    // Run user hooks first (no approval required)
+    if let Some(user_config) = &ctx.config.pre_push {
+        pipeline.run_sequential(
+            user_config,
+            HookType::PrePush,
+            HookSource::User,
+            &extra_vars,
+            HookFailureStrategy::FailFast,
+            None,
+        )?;
+    }
+
+    // Then run project hooks (approval checked at gate, not here)
+    let project_config = ctx.repo.load_project_config()?;
+    if let Some(pre_push_config) = project_config.as_ref().and_then(|c| c.pre_push.as_ref()) {
+        pipeline.run_sequential(
+            pre_push_config,
+            HookType::PrePush,
+            HookSource::Project,
+            &extra_vars,
+            HookFailureStrategy::FailFast,
+            None,
+        )?;
+    }
+    Ok(())
+}
+
 impl<'a> CommandContext<'a> {
     /// Execute post-create commands sequentially (blocking)
     ///
@@ -651,6 +1070,7 @@ impl<'a> CommandContext<'a> {
                 HookSource::User,
                 &[],
                 None,
+                None,
             )?;
         }
 
@@ -670,6 +1090,7 @@ impl<'a> CommandContext<'a> {
             HookSource::Project,
             &[],
             None,
+            None,
         )
     }
 }
@@ -688,12 +1109,32 @@ pub fn handle_push(
     allow_merge_commits: bool,
     verb: &str,
     operations: Option<MergeOperations>,
+    verify: bool,
+    force: bool,
+    config: &WorktrunkConfig,
 ) -> anyhow::Result<()> {
     let repo = Repository::current();
+    // Detached HEAD pushes are fine; hooks see "HEAD" as the branch
+    let branch = repo.current_branch()?.unwrap_or_else(|| "HEAD".to_string());
+    let worktree_path = repo.worktree_root()?;
+    let repo_root = repo.worktree_base()?;
+    let ctx = CommandContext::new(&repo, config, &branch, &worktree_path, &repo_root, force);
 
     // Get target branch (default to default branch if not provided)
     let target_branch = repo.resolve_target_branch(target)?;
 
+    // "Approve at the Gate": `wt merge` approves pre-push with its other hooks up front,
+    // a standalone push approves it here before any work happens
+    let verify = if verify && operations.is_none() {
+        let approved = approve_hooks(&ctx, &[HookType::PrePush])?;
+        if !approved {
+            crate::output::print(info_message("Commands declined, pushing without hooks"))?;
+        }
+        approved
+    } else {
+        verify
+    };
+
     // A worktree for the target branch is optional for push:
     // - If present, we use it to check for overlapping dirty files.
     // - If absent, we skip that safety step but still allow the push (git itself is fine).
@@ -732,7 +1173,8 @@ pub fn handle_push(
         repo.prepare_target_worktree(target_worktree_path.as_ref(), &target_branch)?;
 
     // Count commits and show what will be pushed
-    let commit_count = repo.count_commits(&target_branch, "HEAD")?;
+    let range = PushRange::new(&repo, &target_branch)?;
+    let commit_count = range.commit_count;
 
     // Get diff statistics BEFORE push (will be needed for success message later)
     let stats_summary = if commit_count > 0 {
@@ -804,6 +1246,14 @@ pub fn handle_push(
         super::show_diffstat(&repo, &format!("{}..HEAD", target_branch))?;
     }
 
+    // Run pre-push commands right before the ref update; a failure aborts the push
+    if verify && let Err(e) = execute_pre_push_commands(&ctx, &range) {
+        if let Some(stash) = target_worktree_stash.take() {
+            stash.restore()?;
+        }
+        return Err(e);
+    }
+
     // Get git common dir for the push
     let git_common_dir = repo.git_common_dir()?;
 
@@ -208,12 +208,17 @@ fn complete_hook_commands() -> Vec<CompletionCandidate> {
         ctx.borrow().as_ref().and_then(|ctx| {
             // Look for the hook subcommand in the args
             for hook in &[
+                "pre-create",
                 "post-create",
                 "post-start",
+                "pre-switch",
+                "post-switch",
                 "pre-commit",
                 "pre-merge",
+                "pre-push",
                 "post-merge",
                 "pre-remove",
+                "post-remove",
             ] {
                 if ctx.contains(hook) {
                     return Some(*hook);
@@ -232,12 +237,17 @@ fn complete_hook_commands() -> Vec<CompletionCandidate> {
     // Load user config and add user hook names
     if let Ok(user_config) = WorktrunkConfig::load() {
         let user_command_config = match hook_type {
+            "pre-create" => &user_config.pre_create,
             "post-create" => &user_config.post_create,
             "post-start" => &user_config.post_start,
+            "pre-switch" => &user_config.pre_switch,
+            "post-switch" => &user_config.post_switch,
             "pre-commit" => &user_config.pre_commit,
             "pre-merge" => &user_config.pre_merge,
+            "pre-push" => &user_config.pre_push,
             "post-merge" => &user_config.post_merge,
             "pre-remove" => &user_config.pre_remove,
+            "post-remove" => &user_config.post_remove,
             _ => &None,
         };
 
@@ -258,12 +268,17 @@ fn complete_hook_commands() -> Vec<CompletionCandidate> {
         && let Ok(Some(project_config)) = ProjectConfig::load(&repo_root)
     {
         let project_command_config = match hook_type {
+            "pre-create" => &project_config.pre_create,
             "post-create" => &project_config.post_create,
             "post-start" => &project_config.post_start,
+            "pre-switch" => &project_config.pre_switch,
+            "post-switch" => &project_config.post_switch,
             "pre-commit" => &project_config.pre_commit,
             "pre-merge" => &project_config.pre_merge,
+            "pre-push" => &project_config.pre_push,
             "post-merge" => &project_config.post_merge,
             "pre-remove" => &project_config.pre_remove,
+            "post-remove" => &project_config.post_remove,
             _ => &None,
         };
 
@@ -452,6 +467,7 @@ fn adjust_completion_command(cmd: Command) -> Command {
             .mut_subcommand("post-start", |c| c.mut_arg("name", |arg| arg.last(true)))
             .mut_subcommand("pre-commit", |c| c.mut_arg("name", |arg| arg.last(true)))
             .mut_subcommand("pre-merge", |c| c.mut_arg("name", |arg| arg.last(true)))
+            .mut_subcommand("pre-push", |c| c.mut_arg("name", |arg| arg.last(true)))
             .mut_subcommand("post-merge", |c| c.mut_arg("name", |arg| arg.last(true)))
             .mut_subcommand("pre-remove", |c| c.mut_arg("name", |arg| arg.last(true)))
     })
@@ -6,6 +6,7 @@
 use crate::git::HookType;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Phase in which a command executes (alias to the canonical hook type)
 pub type CommandPhase = HookType;
@@ -21,6 +22,35 @@ pub struct Command {
     pub expanded: String,
     /// Phase in which this command executes
     pub phase: CommandPhase,
+    /// Per-command execution options (parallelism, etc.)
+    pub options: CommandOptions,
+}
+
+/// Execution options for a single command
+///
+/// Set per command with the table form (`lint = { command = "cargo clippy", parallel = true }`)
+/// or for every command in a hook with a boolean entry (`parallel = true`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOptions {
+    /// Run concurrently with adjacent parallel commands (blocking hooks only)
+    pub parallel: bool,
+    /// Names of commands in the same hook that must succeed before this one starts
+    pub needs: Vec<String>,
+    /// Expression that must evaluate truthy for the command to run (`if = "..."`)
+    pub condition: Option<String>,
+    /// Kill the command (and its process group) if it runs longer than this
+    pub timeout: Option<Duration>,
+    /// Extra attempts after a failure or timeout before the command counts as failed
+    pub retries: u32,
+}
+
+impl CommandOptions {
+    /// Most `retries` a command may have; more is almost certainly a typo
+    pub const MAX_RETRIES: u32 = 100;
+
+    fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
 }
 
 impl Command {
@@ -31,9 +61,16 @@ impl Command {
             expanded: template.clone(),
             template,
             phase,
+            options: CommandOptions::default(),
         }
     }
 
+    /// Set execution options
+    pub fn with_options(mut self, options: CommandOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Create a command with both template and expanded forms
     pub fn with_expansion(
         name: Option<String>,
@@ -46,6 +83,7 @@ impl Command {
             template,
             expanded,
             phase,
+            options: CommandOptions::default(),
         }
     }
 }
@@ -57,6 +95,13 @@ impl Command {
 /// - Single string: `post-create = "npm install"`
 /// - Named table: `[post-create]` followed by `install = "npm install"`
 ///
+/// Within a named table, a command may also be an inline table carrying options
+/// (`lint = { command = "cargo clippy", parallel = true }`), and boolean entries set
+/// options for every command in the hook (`parallel = true`). Commands can order
+/// themselves with `needs = ["install"]`, turning the hook into a dependency graph,
+/// run conditionally with `if = "'Cargo.lock' in changed_files"`, and bound their
+/// runtime with `timeout = "5m"` and `retries = 2`.
+///
 /// **Order preservation:** Named commands preserve TOML insertion order (requires
 /// `preserve_order` feature on toml crate and IndexMap for deserialization). This
 /// allows users to control execution order explicitly.
@@ -73,6 +118,72 @@ impl CommandConfig {
         &self.commands
     }
 
+    /// Whether any command declares `needs`, making the hook a dependency graph
+    pub fn has_dependencies(&self) -> bool {
+        self.commands
+            .iter()
+            .any(|cmd| !cmd.options.needs.is_empty())
+    }
+
+    /// Check `needs` references: every name must be a command in this hook, and
+    /// the dependencies must not form a cycle.
+    pub fn validate_needs(&self) -> Result<(), String> {
+        let names: Vec<&str> = self
+            .commands
+            .iter()
+            .map(|cmd| cmd.name.as_deref().unwrap_or_default())
+            .collect();
+
+        for (cmd, name) in self.commands.iter().zip(&names) {
+            if let Some(missing) = cmd
+                .options
+                .needs
+                .iter()
+                .find(|need| !names.contains(&need.as_str()))
+            {
+                return Err(format!(
+                    "command `{name}` needs unknown command `{missing}`"
+                ));
+            }
+        }
+
+        // Depth-first search; `stack` holds the current path so a back edge
+        // can be reported as the full cycle
+        fn visit<'a>(
+            index: usize,
+            config: &'a CommandConfig,
+            names: &[&'a str],
+            done: &mut [bool],
+            stack: &mut Vec<usize>,
+        ) -> Result<(), String> {
+            if done[index] {
+                return Ok(());
+            }
+            if let Some(pos) = stack.iter().position(|&i| i == index) {
+                let cycle: Vec<&str> = stack[pos..]
+                    .iter()
+                    .chain(std::iter::once(&index))
+                    .map(|&i| names[i])
+                    .collect();
+                return Err(format!("dependency cycle: {}", cycle.join(" → ")));
+            }
+            stack.push(index);
+            for need in &config.commands[index].options.needs {
+                let dep = names.iter().position(|n| n == need).unwrap();
+                visit(dep, config, names, done, stack)?;
+            }
+            stack.pop();
+            done[index] = true;
+            Ok(())
+        }
+
+        let mut done = vec![false; self.commands.len()];
+        for index in 0..self.commands.len() {
+            visit(index, self, &names, &mut done, &mut Vec::new())?;
+        }
+        Ok(())
+    }
+
     /// Returns commands with the specified phase
     pub fn commands_with_phase(&self, phase: CommandPhase) -> Vec<Command> {
         self.commands
@@ -95,7 +206,40 @@ impl<'de> Deserialize<'de> for CommandConfig {
         #[serde(untagged)]
         enum CommandConfigToml {
             Single(String),
-            Named(IndexMap<String, String>),
+            Named(IndexMap<String, NamedEntryToml>),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum NamedEntryToml {
+            Template(String),
+            Table(CommandTableToml),
+            /// Hook-level option such as `parallel = true`
+            Flag(bool),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct CommandTableToml {
+            command: String,
+            #[serde(default)]
+            parallel: bool,
+            #[serde(default)]
+            needs: Vec<String>,
+            #[serde(default, rename = "if")]
+            condition: Option<String>,
+            #[serde(default)]
+            timeout: Option<TimeoutToml>,
+            #[serde(default)]
+            retries: u32,
+        }
+
+        /// Seconds (`timeout = 300`) or a duration string (`timeout = "5m"`)
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum TimeoutToml {
+            Seconds(u64),
+            Text(String),
         }
 
         let toml = CommandConfigToml::deserialize(deserializer)?;
@@ -105,12 +249,69 @@ impl<'de> Deserialize<'de> for CommandConfig {
                 vec![Command::new(None, cmd, CommandPhase::PostCreate)]
             }
             CommandConfigToml::Named(map) => {
+                let mut hook_parallel = false;
+                let mut commands = Vec::new();
                 // IndexMap preserves insertion order from TOML
-                map.into_iter()
-                    .map(|(name, template)| {
-                        Command::new(Some(name), template, CommandPhase::PostCreate)
-                    })
-                    .collect()
+                for (name, entry) in map {
+                    match entry {
+                        NamedEntryToml::Template(template) => commands.push(Command::new(
+                            Some(name),
+                            template,
+                            CommandPhase::PostCreate,
+                        )),
+                        NamedEntryToml::Table(table) => {
+                            let timeout = table
+                                .timeout
+                                .map(|timeout| match timeout {
+                                    TimeoutToml::Seconds(secs) => Ok(Duration::from_secs(secs)),
+                                    TimeoutToml::Text(text) => parse_duration(&text),
+                                })
+                                .transpose()
+                                .and_then(|timeout| match timeout {
+                                    // Would kill the command as soon as it starts
+                                    Some(timeout) if timeout.is_zero() => {
+                                        Err("a timeout must be longer than zero".to_string())
+                                    }
+                                    timeout => Ok(timeout),
+                                })
+                                .map_err(|e| {
+                                    serde::de::Error::custom(format!(
+                                        "invalid timeout for `{name}`: {e}"
+                                    ))
+                                })?;
+                            if table.retries > CommandOptions::MAX_RETRIES {
+                                return Err(serde::de::Error::custom(format!(
+                                    "invalid retries for `{name}`: at most {} are allowed",
+                                    CommandOptions::MAX_RETRIES
+                                )));
+                            }
+                            commands.push(
+                                Command::new(Some(name), table.command, CommandPhase::PostCreate)
+                                    .with_options(CommandOptions {
+                                        parallel: table.parallel,
+                                        needs: table.needs,
+                                        condition: table.condition,
+                                        timeout,
+                                        retries: table.retries,
+                                    }),
+                            )
+                        }
+                        NamedEntryToml::Flag(value) => match name.as_str() {
+                            "parallel" => hook_parallel = value,
+                            _ => {
+                                return Err(serde::de::Error::custom(format!(
+                                    "unknown hook option `{name}` (expected a command string or table)"
+                                )));
+                            }
+                        },
+                    }
+                }
+                if hook_parallel {
+                    for cmd in &mut commands {
+                        cmd.options.parallel = true;
+                    }
+                }
+                commands
             }
         };
         Ok(CommandConfig { commands })
@@ -130,12 +331,74 @@ impl Serialize for CommandConfig {
             return self.commands[0].template.serialize(serializer);
         }
 
-        // Serialize as named map (all commands from Named format have names)
+        // Serialize as named map (all commands from Named format have names).
+        // If any command carries options, every command uses the table form: TOML
+        // writes plain values before tables, which would otherwise reorder commands.
+        let as_tables = self.commands.iter().any(|cmd| !cmd.options.is_default());
         let mut map = serializer.serialize_map(Some(self.commands.len()))?;
         for cmd in &self.commands {
             let key = cmd.name.as_ref().unwrap();
-            map.serialize_entry(key, &cmd.template)?;
+            if as_tables {
+                map.serialize_entry(key, &CommandTable::from(cmd))?;
+            } else {
+                map.serialize_entry(key, &cmd.template)?;
+            }
         }
         map.end()
     }
 }
+
+/// Parse a duration like `"90"`, `"90s"`, `"5m"` or `"1h"` (whole units only)
+fn parse_duration(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let (digits, multiplier) = match text.char_indices().last() {
+        Some((i, 's')) => (&text[..i], 1),
+        Some((i, 'm')) => (&text[..i], 60),
+        Some((i, 'h')) => (&text[..i], 60 * 60),
+        _ => (text, 1),
+    };
+    let value: u64 = digits
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{text}` is not a duration (expected e.g. 30s, 5m, 1h)"))?;
+    value
+        .checked_mul(multiplier)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("`{text}` is too long"))
+}
+
+/// Inline-table form of a command with non-default options
+#[derive(Serialize)]
+struct CommandTable<'a> {
+    command: &'a str,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    parallel: bool,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    needs: &'a [String],
+    #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+    condition: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<String>,
+    #[serde(skip_serializing_if = "is_zero")]
+    retries: u32,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+impl<'a> From<&'a Command> for CommandTable<'a> {
+    fn from(cmd: &'a Command) -> Self {
+        Self {
+            command: &cmd.template,
+            parallel: cmd.options.parallel,
+            needs: &cmd.options.needs,
+            condition: cmd.options.condition.as_deref(),
+            timeout: cmd
+                .options
+                .timeout
+                .map(|timeout| format!("{}s", timeout.as_secs())),
+            retries: cmd.options.retries,
+        }
+    }
+}
@@ -91,6 +91,80 @@ pub fn expand_template(
         .map_err(|e| format!("Template render error: {}", e))
 }
 
+/// Evaluate a hook condition expression using minijinja
+///
+/// Uses the same variables as [`expand_template`], but unescaped: conditions are
+/// evaluated by worktrunk, not passed to a shell. `branch` is the raw branch name
+/// (`hotfix/urgent`, not `hotfix-urgent`). `changed_files`, when given, is a list of
+/// paths, so `in` checks for an exact path. Undefined variables are falsy, so
+/// `upstream` can be tested even when no upstream is configured.
+///
+/// # Examples
+/// ```
+/// use worktrunk::config::evaluate_condition;
+/// use std::collections::HashMap;
+///
+/// let files = ["migrations/001.sql".to_string(), "src/lib.rs".to_string()];
+/// let extra = HashMap::new();
+/// assert!(evaluate_condition("'src/lib.rs' in changed_files", "myrepo", "main", &extra, Some(&files)).unwrap());
+/// assert!(evaluate_condition("changed_files | select('startingwith', 'migrations/') | list", "myrepo", "main", &extra, Some(&files)).unwrap());
+/// assert!(!evaluate_condition("branch is startingwith('hotfix/')", "myrepo", "feature/foo", &extra, None).unwrap());
+/// ```
+pub fn evaluate_condition(
+    expression: &str,
+    main_worktree: &str,
+    branch: &str,
+    extra: &std::collections::HashMap<&str, &str>,
+    changed_files: Option<&[String]>,
+) -> Result<bool, String> {
+    let mut context = std::collections::HashMap::new();
+    for (key, value) in extra {
+        context.insert(key.to_string(), minijinja::Value::from(*value));
+    }
+
+    // After `extra`, which may carry the sanitized branch used by templates
+    context.insert("branch".to_string(), minijinja::Value::from(branch));
+    context.insert(
+        "main_worktree".to_string(),
+        minijinja::Value::from(main_worktree),
+    );
+    context.insert("repo".to_string(), minijinja::Value::from(main_worktree));
+    if let Some(files) = changed_files {
+        context.insert(
+            "changed_files".to_string(),
+            minijinja::Value::from(files.to_vec()),
+        );
+    }
+
+    let env = Environment::new();
+    let expr = env
+        .compile_expression(expression)
+        .map_err(|e| format!("Condition syntax error: {}", e))?;
+
+    expr.eval(minijinja::Value::from_object(context))
+        .map(|value| value.is_true())
+        .map_err(|e| format!("Condition evaluation error: {}", e))
+}
+
+/// Whether a template refers to the variable `name`
+///
+/// Lets callers skip computing variables no template uses. A template that
+/// doesn't parse counts as not using it; expanding it reports the error.
+pub fn template_uses_variable(template: &str, name: &str) -> bool {
+    Environment::new()
+        .template_from_str(template)
+        .is_ok_and(|tmpl| tmpl.undeclared_variables(false).contains(name))
+}
+
+/// Whether a condition expression refers to the variable `name`
+///
+/// See [`template_uses_variable`].
+pub fn condition_uses_variable(expression: &str, name: &str) -> bool {
+    Environment::new()
+        .compile_expression(expression)
+        .is_ok_and(|expr| expr.undeclared_variables(false).contains(name))
+}
+
 /// Expand command template variables using minijinja
 ///
 /// Convenience function for expanding command templates with common variables.
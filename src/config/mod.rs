@@ -10,6 +10,7 @@
 //! - `worktree-path` - Template for worktree paths (relative to repo root)
 //! - `commit-generation` - LLM command and templates for commit messages
 //! - `approved-commands` - Commands approved for automatic execution
+//! - `projects."<id>".sparse` - Sparse-checkout patterns for one project
 //!
 //! **Managed by**: Each developer maintains their own user config
 //!
@@ -18,11 +19,16 @@
 //! **Purpose**: Project-specific hooks and commands, checked into git
 //!
 //! **Settings**:
+//! - `pre-create` - Validation before creating worktree (can veto the creation)
 //! - `post-create` - Sequential blocking commands when creating worktree
 //! - `post-start` - Parallel background commands after worktree created
+//! - `pre-switch` / `post-switch` - Commands around every switch between worktrees
 //! - `pre-commit` - Validation before committing changes during merge
 //! - `pre-merge` - Validation before merging to target branch
+//! - `pre-push` - Validation right before the target branch is updated
 //! - `post-merge` - Cleanup after successful merge
+//! - `pre-remove` / `post-remove` - Commands before and after removing worktree
+//! - `sparse` - Sparse-checkout patterns for new worktrees
 //!
 //! **Managed by**: Checked into the repository, shared across all developers
 //!
@@ -33,23 +39,32 @@
 //! - No merging or precedence rules needed
 //! - Loaded separately and used in different contexts
 //!
+//! The exception is `sparse`, where the user's per-project patterns take
+//! precedence over the project's.
+//!
 //! User config controls "how worktrunk behaves for me", project config controls
 //! "what commands run for this project".
 
 mod commands;
 mod expansion;
 mod project;
+mod sparse;
 #[cfg(test)]
 mod test;
 mod user;
 
 // Re-export public types
-pub use commands::{Command, CommandConfig, CommandPhase};
-pub use expansion::{expand_command_template, expand_template, sanitize_branch_name};
+pub use commands::{Command, CommandConfig, CommandOptions, CommandPhase};
+pub use expansion::{
+    condition_uses_variable, evaluate_condition, expand_command_template, expand_template,
+    sanitize_branch_name, template_uses_variable,
+};
 pub use project::{ProjectConfig, find_unknown_keys as find_unknown_project_keys};
+pub use sparse::SparseConfig;
 pub use user::{
-    CommitGenerationConfig, StageMode, UserProjectConfig, WorktrunkConfig,
-    find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
+    CommitGenerationConfig, LlmProvider, LogsConfig, MessageValidation, SelectConfig, SelectKeys,
+    SelectLayout, SelectPreview, StageMode, StatuslineConfig, StatuslineContext, UserProjectConfig,
+    WorktrunkConfig, find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
 };
 
 #[cfg(test)]
@@ -269,6 +284,23 @@ task2 = "echo 'Task 2 running' > task2.txt"
         assert!(config.post_start.is_some());
     }
 
+    #[test]
+    fn test_project_config_switch_hooks() {
+        let toml = r#"
+            pre-switch = "git diff --quiet"
+
+            [post-switch]
+            direnv = "direnv allow"
+            tmux = "tmux rename-window {{ new_branch }}"
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.pre_switch.unwrap().commands().len(), 1);
+        let post_switch = config.post_switch.unwrap();
+        let commands = post_switch.commands();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[1].name.as_deref(), Some("tmux"));
+    }
+
     #[test]
     fn test_pre_merge_command_single() {
         let toml = r#"pre-merge = "cargo test""#;
@@ -321,6 +353,230 @@ task2 = "echo 'Task 2 running' > task2.txt"
         );
     }
 
+    #[test]
+    fn test_command_config_parallel_hook_option() {
+        let toml = r#"
+            [pre-merge]
+            parallel = true
+            lint = "cargo clippy"
+            test = "cargo test"
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let commands = config.pre_merge.unwrap().commands().to_vec();
+        // The option is not a command
+        let names: Vec<_> = commands.iter().filter_map(|c| c.name.as_deref()).collect();
+        assert_eq!(names, vec!["lint", "test"]);
+        assert!(commands.iter().all(|c| c.options.parallel));
+    }
+
+    #[test]
+    fn test_command_config_parallel_per_command() {
+        let toml = r#"
+            [post-create]
+            install = "npm ci"
+            assets = { command = "npm run assets", parallel = true }
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let commands = config.post_create.unwrap().commands().to_vec();
+        assert_eq!(
+            commands[0],
+            Command::new(
+                Some("install".to_string()),
+                "npm ci".to_string(),
+                CommandPhase::PostCreate
+            )
+        );
+        assert_eq!(
+            commands[1],
+            Command::new(
+                Some("assets".to_string()),
+                "npm run assets".to_string(),
+                CommandPhase::PostCreate
+            )
+            .with_options(CommandOptions {
+                parallel: true,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_command_config_unknown_hook_option() {
+        let toml = r#"
+            [pre-merge]
+            sequential = true
+            test = "cargo test"
+        "#;
+        let err = toml::from_str::<ProjectConfig>(toml).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown hook option `sequential`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_command_config_roundtrip_parallel() {
+        let original = r#"
+            [pre-merge]
+            lint = { command = "cargo clippy", parallel = true }
+            test = "cargo test"
+        "#;
+        let config: ProjectConfig = toml::from_str(original).unwrap();
+        let serialized = toml::to_string(&config).unwrap();
+        let config2: ProjectConfig = toml::from_str(&serialized).unwrap();
+        assert_eq!(config, config2);
+    }
+
+    #[test]
+    fn test_command_config_needs() {
+        let toml = r#"
+            [post-create]
+            build = { command = "npm run build", needs = ["install"] }
+            install = "npm ci"
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let cmd_config = config.post_create.unwrap();
+        assert!(cmd_config.has_dependencies());
+        assert_eq!(cmd_config.commands()[0].options.needs, vec!["install"]);
+        assert!(cmd_config.validate_needs().is_ok());
+    }
+
+    #[test]
+    fn test_command_config_needs_unknown() {
+        let toml = r#"
+            [pre-merge]
+            build = { command = "cargo build", needs = ["instal"] }
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.pre_merge.unwrap().validate_needs().unwrap_err(),
+            "command `build` needs unknown command `instal`"
+        );
+    }
+
+    #[test]
+    fn test_command_config_needs_cycle() {
+        let toml = r#"
+            [pre-merge]
+            setup = "echo setup"
+            a = { command = "echo a", needs = ["setup", "c"] }
+            b = { command = "echo b", needs = ["a"] }
+            c = { command = "echo c", needs = ["b"] }
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.pre_merge.unwrap().validate_needs().unwrap_err(),
+            "dependency cycle: a → c → b → a"
+        );
+    }
+
+    #[test]
+    fn test_command_config_needs_self() {
+        let toml = r#"
+            [pre-merge]
+            a = { command = "echo a", needs = ["a"] }
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.pre_merge.unwrap().validate_needs().unwrap_err(),
+            "dependency cycle: a → a"
+        );
+    }
+
+    #[test]
+    fn test_command_config_timeout_and_retries() {
+        let toml = r#"
+            [post-create]
+            install = { command = "npm ci", timeout = "5m", retries = 2 }
+            build = { command = "npm run build", timeout = 90 }
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let commands = config.post_create.unwrap().commands().to_vec();
+        assert_eq!(
+            commands[0].options.timeout,
+            Some(std::time::Duration::from_secs(300))
+        );
+        assert_eq!(commands[0].options.retries, 2);
+        assert_eq!(
+            commands[1].options.timeout,
+            Some(std::time::Duration::from_secs(90))
+        );
+        assert_eq!(commands[1].options.retries, 0);
+    }
+
+    #[test]
+    fn test_command_config_invalid_timeout() {
+        let toml = r#"
+            [pre-merge]
+            test = { command = "cargo test", timeout = "soon" }
+        "#;
+        let err = toml::from_str::<ProjectConfig>(toml).unwrap_err();
+        assert!(
+            err.to_string().contains("invalid timeout for `test`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_command_config_timeout_out_of_range() {
+        for (timeout, expected) in [
+            (r#""99999999999999999h""#, "is too long"),
+            (r#""0s""#, "longer than zero"),
+            ("0", "longer than zero"),
+        ] {
+            let toml =
+                format!("[pre-merge]\ntest = {{ command = \"cargo test\", timeout = {timeout} }}");
+            let err = toml::from_str::<ProjectConfig>(&toml).unwrap_err();
+            assert!(err.to_string().contains(expected), "{timeout}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_command_config_retries_out_of_range() {
+        for retries in ["101", "4294967295"] {
+            let toml =
+                format!("[pre-merge]\ntest = {{ command = \"cargo test\", retries = {retries} }}");
+            let err = toml::from_str::<ProjectConfig>(&toml).unwrap_err();
+            assert!(
+                err.to_string().contains("invalid retries for `test`"),
+                "{retries}: {err}"
+            );
+        }
+        let toml = "[pre-merge]\ntest = { command = \"cargo test\", retries = 100 }";
+        assert!(toml::from_str::<ProjectConfig>(toml).is_ok());
+    }
+
+    #[test]
+    fn test_command_config_roundtrip_timeout() {
+        let original = r#"
+            [post-create]
+            install = { command = "npm ci", timeout = "1h", retries = 1 }
+            env = "cp .env.example .env"
+        "#;
+        let config: ProjectConfig = toml::from_str(original).unwrap();
+        let serialized = toml::to_string(&config).unwrap();
+        let config2: ProjectConfig = toml::from_str(&serialized).unwrap();
+        assert_eq!(config, config2);
+    }
+
+    #[test]
+    fn test_command_config_condition() {
+        let toml = r#"
+            [pre-merge]
+            migrate = { command = "npm run db:migrate", if = "changed_files | select('startingwith', 'migrations/')" }
+        "#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let commands = config.pre_merge.as_ref().unwrap().commands();
+        assert_eq!(
+            commands[0].options.condition.as_deref(),
+            Some("changed_files | select('startingwith', 'migrations/')")
+        );
+
+        // Round-trips through the `if` key
+        let serialized = toml::to_string(&config).unwrap();
+        assert!(serialized.contains("if = "), "{serialized}");
+    }
+
     #[test]
     fn test_command_config_roundtrip_single() {
         let original = r#"post-create = "npm install""#;
@@ -353,12 +609,15 @@ task2 = "echo 'Task 2 running' > task2.txt"
     fn test_user_project_config_equality() {
         let config1 = UserProjectConfig {
             approved_commands: vec!["npm install".to_string()],
+            ..Default::default()
         };
         let config2 = UserProjectConfig {
             approved_commands: vec!["npm install".to_string()],
+            ..Default::default()
         };
         let config3 = UserProjectConfig {
             approved_commands: vec!["npm test".to_string()],
+            ..Default::default()
         };
         assert_eq!(config1, config2);
         assert_ne!(config1, config3);
@@ -371,6 +630,7 @@ task2 = "echo 'Task 2 running' > task2.txt"
             "github.com/user/repo".to_string(),
             UserProjectConfig {
                 approved_commands: vec!["npm install".to_string()],
+                ..Default::default()
             },
         );
 
@@ -670,6 +930,7 @@ squash-template-file = "~/file.txt"
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
 
         let toml = toml::to_string(&config).unwrap();
@@ -783,4 +1044,213 @@ test = "cargo test"
             unknown
         );
     }
+
+    #[test]
+    fn test_logs_config() {
+        let toml_str = r#"
+worktree-path = "../test"
+
+[logs]
+keep = 3
+max-size = "512KB"
+"#;
+        let config: WorktrunkConfig = toml::from_str(toml_str).unwrap();
+        let logs = config.logs.expect("logs should be present");
+        assert_eq!(logs.keep(), 3);
+        assert_eq!(logs.max_size(), Ok(512 * 1024));
+
+        let defaults = LogsConfig::default();
+        assert_eq!(defaults.keep(), 5);
+        assert_eq!(defaults.max_size(), Ok(10 * 1024 * 1024));
+
+        let invalid = LogsConfig {
+            max_size: Some("lots".to_string()),
+            ..Default::default()
+        };
+        assert!(invalid.max_size().is_err());
+
+        let overflowing = LogsConfig {
+            max_size: Some("99999999999999GB".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            overflowing.max_size(),
+            Err("size `99999999999999GB` is too large".to_string())
+        );
+    }
+
+    #[test]
+    fn test_commit_generation_diff_budget() {
+        let toml_str = r#"
+worktree-path = "../test"
+
+[commit-generation]
+max-diff-size = "20KB"
+diff-exclude = ["**/*.snap"]
+"#;
+        let config: WorktrunkConfig = toml::from_str(toml_str).unwrap();
+        let commit_generation = config.commit_generation;
+        assert_eq!(commit_generation.max_diff_size(), Ok(20 * 1024));
+        assert_eq!(commit_generation.diff_exclude(), ["**/*.snap"]);
+
+        let defaults = CommitGenerationConfig::default();
+        assert_eq!(defaults.max_diff_size(), Ok(100 * 1024));
+        assert!(defaults.diff_exclude().contains(&"**/*.lock"));
+
+        let invalid = CommitGenerationConfig {
+            max_diff_size: Some("huge".to_string()),
+            ..Default::default()
+        };
+        assert!(invalid.max_diff_size().is_err());
+    }
+
+    #[test]
+    fn test_commit_generation_validation() {
+        let parse = |validate: &str| CommitGenerationConfig {
+            validate: Some(validate.to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            parse("conventional").validation(),
+            Ok(Some(MessageValidation::Conventional))
+        ));
+        let Ok(Some(MessageValidation::Pattern { regex, .. })) =
+            parse("JIRA-\\d+: .+").validation()
+        else {
+            panic!("expected a pattern");
+        };
+        // The whole line must match, not just part of it
+        assert!(regex.is_match("JIRA-12: Fix login"));
+        assert!(!regex.is_match("Fix JIRA-12: login"));
+        assert!(parse("(unclosed").validation().is_err());
+        assert!(parse("a)|(b").validation().is_err());
+
+        let defaults = CommitGenerationConfig::default();
+        assert!(matches!(defaults.validation(), Ok(None)));
+        assert_eq!(defaults.max_attempts(), 3);
+        let config = CommitGenerationConfig {
+            max_attempts: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(config.max_attempts(), 1);
+    }
+
+    #[test]
+    fn test_commit_generation_provider() {
+        let toml_str = r#"
+worktree-path = "../test"
+
+[commit-generation]
+provider = "openai-compatible"
+endpoint = "http://localhost:11434/v1/"
+model = "qwen2.5-coder"
+"#;
+        let config: WorktrunkConfig = toml::from_str(toml_str).unwrap();
+        let commit_generation = config.commit_generation;
+        assert_eq!(commit_generation.provider(), LlmProvider::OpenaiCompatible);
+        assert!(commit_generation.is_configured());
+        assert_eq!(commit_generation.endpoint(), "http://localhost:11434/v1");
+        // The default key isn't sent to a custom endpoint
+        assert_eq!(commit_generation.api_key_env(), None);
+
+        let anthropic = CommitGenerationConfig {
+            provider: Some(LlmProvider::Anthropic),
+            api_key_env: Some("MY_KEY".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(anthropic.endpoint(), "https://api.anthropic.com/v1");
+        assert_eq!(anthropic.api_key_env(), Some("MY_KEY"));
+        let anthropic = CommitGenerationConfig {
+            api_key_env: None,
+            ..anthropic
+        };
+        assert_eq!(anthropic.api_key_env(), Some("ANTHROPIC_API_KEY"));
+
+        // The command provider needs a command
+        let defaults = CommitGenerationConfig::default();
+        assert_eq!(defaults.provider(), LlmProvider::Command);
+        assert!(!defaults.is_configured());
+
+        assert!(
+            toml::from_str::<CommitGenerationConfig>(r#"provider = "carrier-pigeon""#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_select_config() {
+        let toml_str = r#"
+worktree-path = "../test"
+
+[select]
+preview = "branch-diff"
+layout = "down"
+height = "40"
+colors = "current_bg:237"
+
+[select.keys]
+remove = "ctrl-x"
+"#;
+        let config: WorktrunkConfig = toml::from_str(toml_str).unwrap();
+        let select = config.select.expect("select should be present");
+        assert_eq!(select.preview, Some(SelectPreview::BranchDiff));
+        assert_eq!(select.layout, Some(SelectLayout::Down));
+        assert_eq!(select.colors.as_deref(), Some("current_bg:237"));
+        assert_eq!(select.height_rows(50), Ok(40));
+        assert_eq!(select.height_rows(30), Ok(30));
+        let keys = select.keys.expect("keys should be present");
+        assert_eq!(keys.remove.as_deref(), Some("ctrl-x"));
+        assert_eq!(keys.merge, None);
+
+        let defaults = SelectConfig::default();
+        assert_eq!(defaults.height(), "90%");
+        assert_eq!(defaults.height_rows(50), Ok(45));
+
+        let invalid: SelectConfig = toml::from_str(r#"height = "tall""#).unwrap();
+        assert!(invalid.height_rows(50).is_err());
+        let invalid: SelectConfig = toml::from_str(r#"height = "150%""#).unwrap();
+        assert!(invalid.height_rows(50).is_err());
+    }
+
+    #[test]
+    fn test_statusline_config() {
+        let toml_str = r#"
+[statusline]
+template = "{{ branch }}{% if ci %} {{ ci }}{% endif %}"
+
+[statusline.context.my-agent]
+dir = "$.workspace.cwd"
+"#;
+        let config: WorktrunkConfig = toml::from_str(toml_str).unwrap();
+        let statusline = config.statusline.expect("statusline should be present");
+        assert_eq!(
+            statusline.template.as_deref(),
+            Some("{{ branch }}{% if ci %} {{ ci }}{% endif %}")
+        );
+        assert_eq!(
+            statusline.context.get("my-agent"),
+            Some(&StatuslineContext {
+                dir: "$.workspace.cwd".to_string(),
+                model: None,
+            })
+        );
+
+        let config = WorktrunkConfig::default();
+        assert_eq!(config.statusline, None);
+    }
+
+    #[test]
+    fn test_find_unknown_select_keys() {
+        let toml_str = r#"
+[select]
+layout = "down"
+theme = "dark"
+
+[select.keys]
+remove = "ctrl-x"
+delete = "ctrl-d"
+"#;
+        let mut unknown = find_unknown_user_keys(toml_str);
+        unknown.sort();
+        assert_eq!(unknown, ["select.keys.delete", "select.theme"]);
+    }
 }
@@ -6,6 +6,8 @@ use config::ConfigError;
 use serde::{Deserialize, Serialize};
 
 use super::commands::CommandConfig;
+use super::sparse::SparseConfig;
+use crate::git::HookType;
 
 /// Project-specific configuration with hooks.
 ///
@@ -26,11 +28,33 @@ use super::commands::CommandConfig;
 /// - `{{ short_commit }}` - Current HEAD commit SHA (short 7-character hash)
 /// - `{{ remote }}` - Primary remote name (e.g., "origin")
 /// - `{{ upstream }}` - Upstream tracking branch (e.g., "origin/feature"), if configured
+/// - `{{ changed_files }}` - Files changed since the branch diverged from the target
+///   (or default) branch, newline-separated; a list in `if` conditions
 ///
-/// Merge-related hooks (`pre-commit`, `pre-merge`, `post-merge`) also support:
+/// Merge-related hooks (`pre-commit`, `pre-merge`, `pre-push`, `post-merge`) also support:
 /// - `{{ target }}` - Target branch for the merge (e.g., "main")
+///
+/// `pre-push` also supports:
+/// - `{{ commit_range }}` - Commits being pushed, as `<target sha>..<head sha>`
+/// - `{{ commit_count }}` - Number of commits being pushed
+///
+/// Switch hooks (`pre-switch`, `post-switch`) also support:
+/// - `{{ previous_branch }}`, `{{ previous_worktree }}` - Worktree being switched away from
+/// - `{{ new_branch }}`, `{{ new_worktree }}` - Worktree being switched to
+///
+/// `pre-create` also supports `{{ base }}` - Base the new branch is created from
+/// (with `--create`). In `pre-create` and `post-remove`, `{{ worktree }}` is the
+/// worktree being created or removed, which doesn't exist at that point.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct ProjectConfig {
+    /// Commands to execute before a worktree is created (blocking, fail-fast validation)
+    /// Supports string (single command) or table (named, sequential)
+    /// Runs in the current worktree before `git worktree add`; non-zero exit aborts creation
+    ///
+    /// Available template variables: `{{ repo }}`, `{{ branch }}`, `{{ worktree }}`, `{{ worktree_name }}`, `{{ repo_root }}`, `{{ default_branch }}`, `{{ commit }}`, `{{ short_commit }}`, `{{ remote }}`, `{{ base }}`
+    #[serde(default, rename = "pre-create")]
+    pub pre_create: Option<CommandConfig>,
+
     /// Commands to execute sequentially before worktree is ready (blocking)
     /// Supports string (single command) or table (named, sequential)
     ///
@@ -45,6 +69,22 @@ pub struct ProjectConfig {
     #[serde(default, rename = "post-start")]
     pub post_start: Option<CommandConfig>,
 
+    /// Commands to execute before every `wt switch` (blocking, fail-fast)
+    /// Supports string (single command) or table (named, sequential)
+    /// Runs in the worktree being left; non-zero exit aborts the switch
+    ///
+    /// Available template variables: `{{ repo }}`, `{{ branch }}`, `{{ worktree }}`, `{{ worktree_name }}`, `{{ repo_root }}`, `{{ default_branch }}`, `{{ commit }}`, `{{ short_commit }}`, `{{ remote }}`, `{{ upstream }}`, `{{ previous_branch }}`, `{{ previous_worktree }}`, `{{ new_branch }}`, `{{ new_worktree }}`
+    #[serde(default, rename = "pre-switch")]
+    pub pre_switch: Option<CommandConfig>,
+
+    /// Commands to execute after every `wt switch` (blocking)
+    /// Supports string (single command) or table (named, sequential)
+    /// Runs in the worktree switched to
+    ///
+    /// Available template variables: `{{ repo }}`, `{{ branch }}`, `{{ worktree }}`, `{{ worktree_name }}`, `{{ repo_root }}`, `{{ default_branch }}`, `{{ commit }}`, `{{ short_commit }}`, `{{ remote }}`, `{{ upstream }}`, `{{ previous_branch }}`, `{{ previous_worktree }}`, `{{ new_branch }}`, `{{ new_worktree }}`
+    #[serde(default, rename = "post-switch")]
+    pub post_switch: Option<CommandConfig>,
+
     /// Commands to execute before committing changes during merge (blocking, fail-fast validation)
     /// Supports string (single command) or table (named, sequential)
     /// All commands must exit with code 0 for commit to proceed
@@ -62,6 +102,15 @@ pub struct ProjectConfig {
     #[serde(default, rename = "pre-merge")]
     pub pre_merge: Option<CommandConfig>,
 
+    /// Commands to execute before pushing to the target branch (blocking, fail-fast validation)
+    /// Supports string (single command) or table (named, sequential)
+    /// Runs right before the target branch is updated, in both `wt step push` and `wt merge`;
+    /// non-zero exit aborts the push
+    ///
+    /// Available template variables: `{{ repo }}`, `{{ branch }}`, `{{ worktree }}`, `{{ worktree_name }}`, `{{ repo_root }}`, `{{ default_branch }}`, `{{ commit }}`, `{{ short_commit }}`, `{{ remote }}`, `{{ upstream }}`, `{{ target }}`, `{{ commit_range }}`, `{{ commit_count }}`
+    #[serde(default, rename = "pre-push")]
+    pub pre_push: Option<CommandConfig>,
+
     /// Commands to execute after successful merge in the main worktree (blocking)
     /// Supports string (single command) or table (named, sequential)
     /// Runs after push and cleanup complete
@@ -78,6 +127,19 @@ pub struct ProjectConfig {
     #[serde(default, rename = "pre-remove")]
     pub pre_remove: Option<CommandConfig>,
 
+    /// Commands to execute after a worktree is removed
+    /// Supports string (single command) or table (named, sequential)
+    /// Runs in the main worktree once the worktree directory is gone; failures only warn
+    ///
+    /// Available template variables: `{{ repo }}`, `{{ branch }}`, `{{ worktree }}`, `{{ worktree_name }}`, `{{ repo_root }}`, `{{ default_branch }}`, `{{ remote }}`
+    #[serde(default, rename = "post-remove")]
+    pub post_remove: Option<CommandConfig>,
+
+    /// Sparse-checkout patterns for new worktrees
+    /// The user config's `[projects."<id>".sparse]` takes precedence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparse: Option<SparseConfig>,
+
     /// Captures unknown fields for validation warnings
     #[serde(flatten, default, skip_serializing)]
     unknown: std::collections::HashMap<String, toml::Value>,
@@ -98,9 +160,48 @@ impl ProjectConfig {
 
         let config: ProjectConfig = toml::from_str(&contents)
             .map_err(|e| ConfigError::Message(format!("Failed to parse TOML: {}", e)))?;
+        validate_hooks(config.hooks())?;
 
         Ok(Some(config))
     }
+
+    /// All hooks with their commands, keyed by hook type
+    pub fn hooks(&self) -> [(HookType, Option<&CommandConfig>); 11] {
+        [
+            (HookType::PreCreate, self.pre_create.as_ref()),
+            (HookType::PostCreate, self.post_create.as_ref()),
+            (HookType::PostStart, self.post_start.as_ref()),
+            (HookType::PreSwitch, self.pre_switch.as_ref()),
+            (HookType::PostSwitch, self.post_switch.as_ref()),
+            (HookType::PreCommit, self.pre_commit.as_ref()),
+            (HookType::PreMerge, self.pre_merge.as_ref()),
+            (HookType::PrePush, self.pre_push.as_ref()),
+            (HookType::PostMerge, self.post_merge.as_ref()),
+            (HookType::PreRemove, self.pre_remove.as_ref()),
+            (HookType::PostRemove, self.post_remove.as_ref()),
+        ]
+    }
+
+    /// Commands of one hook
+    pub fn hook(&self, hook_type: HookType) -> Option<&CommandConfig> {
+        self.hooks()
+            .into_iter()
+            .find_map(|(t, commands)| commands.filter(|_| t == hook_type))
+    }
+}
+
+/// Check `needs` references in each hook (unknown names, cycles)
+pub(crate) fn validate_hooks<'a>(
+    hooks: impl IntoIterator<Item = (HookType, Option<&'a CommandConfig>)>,
+) -> Result<(), ConfigError> {
+    for (hook_type, commands) in hooks {
+        if let Some(commands) = commands {
+            commands
+                .validate_needs()
+                .map_err(|e| ConfigError::Message(format!("Invalid {hook_type} hook: {e}")))?;
+        }
+    }
+    Ok(())
 }
 
 /// Find unknown keys in project config TOML content
@@ -0,0 +1,120 @@
+//! Sparse-checkout configuration
+//!
+//! Shared by the project config (`[sparse]` in `.config/wt.toml`) and the user
+//! config (`[projects."<id>".sparse]`). New worktrees check out only the
+//! configured directories, using git's cone-mode sparse checkout.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Directories to check out in new worktrees
+///
+/// # TOML Format
+/// ```toml
+/// [sparse]
+/// paths = ["services/api", "libs/common"]
+///
+/// # Branches matching a pattern check out other directories; the first match wins.
+/// # An empty list checks out everything.
+/// [sparse.branches]
+/// "web/*" = ["apps/web", "libs/common"]
+/// "release-*" = []
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SparseConfig {
+    /// Cone patterns (directories) for every new worktree
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+
+    /// Cone patterns for branches matching a name pattern (`*` matches any characters)
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub branches: IndexMap<String, Vec<String>>,
+}
+
+impl SparseConfig {
+    /// Patterns for `branch`: the first matching branch pattern, then `paths`
+    ///
+    /// Returns None when nothing applies. An empty list means a full checkout.
+    pub fn paths_for(&self, branch: &str) -> Option<&[String]> {
+        self.branches
+            .iter()
+            .find(|(pattern, _)| matches_pattern(pattern, branch))
+            .map(|(_, paths)| paths.as_slice())
+            .or_else(|| (!self.paths.is_empty()).then_some(self.paths.as_slice()))
+    }
+}
+
+/// Match `name` against `pattern`, where `*` matches any run of characters
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // split always yields at least one part
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts = parts.peekable();
+    if parts.peek().is_none() {
+        // No `*`: exact match
+        return rest.is_empty();
+    }
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            // Last part anchors to the end
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("main", "main"));
+        assert!(!matches_pattern("main", "main2"));
+        assert!(matches_pattern("web/*", "web/login"));
+        assert!(!matches_pattern("web/*", "api/web/login"));
+        assert!(matches_pattern("*-fix", "parser-fix"));
+        assert!(!matches_pattern("*-fix", "parser-fix2"));
+        assert!(matches_pattern("*", "anything"));
+        assert!(matches_pattern("a*b*c", "a-b-c"));
+        assert!(!matches_pattern("a*b*c", "a-c-b"));
+        // The suffix can't overlap the prefix
+        assert!(!matches_pattern("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn test_paths_for() {
+        let config: SparseConfig = toml::from_str(
+            r#"
+paths = ["services/api"]
+
+[branches]
+"web/*" = ["apps/web"]
+"release-*" = []
+"*" = ["docs"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.paths_for("web/login"),
+            Some(&["apps/web".into()][..])
+        );
+        assert_eq!(config.paths_for("release-1.0"), Some(&[][..]));
+        // Branch patterns win over `paths`, in order
+        assert_eq!(config.paths_for("feature"), Some(&["docs".into()][..]));
+
+        let config: SparseConfig = toml::from_str(r#"paths = ["services/api"]"#).unwrap();
+        assert_eq!(
+            config.paths_for("feature"),
+            Some(&["services/api".into()][..])
+        );
+        assert_eq!(SparseConfig::default().paths_for("feature"), None);
+    }
+}
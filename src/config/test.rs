@@ -3,7 +3,7 @@
 //! These tests target potential shell injection vulnerabilities and
 //! edge cases in template variable substitution.
 
-use super::expand_template;
+use super::{condition_uses_variable, evaluate_condition, expand_template, template_uses_variable};
 use std::collections::HashMap;
 
 #[test]
@@ -345,3 +345,84 @@ fn snapshot_complex_templates() {
 
     insta::assert_yaml_snapshot!(results);
 }
+
+#[test]
+fn test_evaluate_condition_uses_unescaped_values() {
+    // Values aren't shell-escaped for conditions, so comparisons see the raw string
+    let extras = HashMap::from([("target", "release candidate")]);
+    assert!(
+        evaluate_condition(
+            "target == 'release candidate'",
+            "myrepo",
+            "main",
+            &extras,
+            None
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_evaluate_condition_undefined_is_falsy() {
+    let extras = HashMap::new();
+    assert!(!evaluate_condition("upstream", "myrepo", "main", &extras, None).unwrap());
+    assert!(evaluate_condition("not upstream", "myrepo", "main", &extras, None).unwrap());
+}
+
+#[test]
+fn test_evaluate_condition_raw_branch() {
+    // Unlike templates, conditions see the branch name with its slashes, even
+    // when `extras` carries the sanitized one
+    let extras = HashMap::from([("branch", "hotfix-urgent")]);
+    let condition = |expression| {
+        evaluate_condition(expression, "myrepo", "hotfix/urgent", &extras, None).unwrap()
+    };
+    assert!(condition("branch is startingwith('hotfix/')"));
+    assert!(!condition("branch is startingwith('hotfix-')"));
+}
+
+#[test]
+fn test_evaluate_condition_changed_files_list() {
+    let extras = HashMap::new();
+    let files = ["migrations/001.sql".to_string(), "src/lib.rs".to_string()];
+    let condition = |expression| {
+        evaluate_condition(expression, "myrepo", "main", &extras, Some(&files)).unwrap()
+    };
+    // `in` is an exact path check, not a substring match
+    assert!(condition("'src/lib.rs' in changed_files"));
+    assert!(!condition("'lib.rs' in changed_files"));
+    assert!(!condition("'migrations/' in changed_files"));
+    assert!(condition(
+        "changed_files | select('startingwith', 'migrations/')"
+    ));
+    assert!(!condition(
+        "changed_files | select('startingwith', 'docs/')"
+    ));
+}
+
+#[test]
+fn test_uses_variable() {
+    assert!(template_uses_variable(
+        "echo {{ changed_files }}",
+        "changed_files"
+    ));
+    assert!(!template_uses_variable(
+        "echo {{ branch }}",
+        "changed_files"
+    ));
+    assert!(condition_uses_variable(
+        "'Cargo.lock' in changed_files",
+        "changed_files"
+    ));
+    assert!(!condition_uses_variable(
+        "branch == 'main'",
+        "changed_files"
+    ));
+}
+
+#[test]
+fn test_evaluate_condition_syntax_error() {
+    let extras = HashMap::new();
+    let err = evaluate_condition("branch ==", "myrepo", "main", &extras, None).unwrap_err();
+    assert!(err.starts_with("Condition syntax error"), "{err}");
+}
@@ -8,6 +8,9 @@ use std::path::PathBuf;
 use std::sync::OnceLock;
 
 use super::commands::CommandConfig;
+use super::project::validate_hooks;
+use super::sparse::SparseConfig;
+use crate::git::HookType;
 
 /// Deserialize a Vec<String> that can also accept a single String
 /// This enables setting array config fields via environment variables
@@ -148,9 +151,29 @@ pub struct WorktrunkConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge: Option<MergeConfig>,
 
+    /// Retention of background operation logs (`.git/wt-logs/`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs: Option<LogsConfig>,
+
+    /// Configuration for the `wt select` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select: Option<SelectConfig>,
+
+    /// Configuration for the `wt list statusline` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statusline: Option<StatuslineConfig>,
+
     // =========================================================================
     // User-level hooks (same syntax as project hooks, run before project hooks)
     // =========================================================================
+    /// Commands to execute before worktree creation (blocking, fail-fast)
+    #[serde(
+        default,
+        rename = "pre-create",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_create: Option<CommandConfig>,
+
     /// Commands to execute after worktree creation (blocking)
     #[serde(
         default,
@@ -167,6 +190,22 @@ pub struct WorktrunkConfig {
     )]
     pub post_start: Option<CommandConfig>,
 
+    /// Commands to execute before every switch (blocking, fail-fast)
+    #[serde(
+        default,
+        rename = "pre-switch",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_switch: Option<CommandConfig>,
+
+    /// Commands to execute after every switch (blocking, best-effort)
+    #[serde(
+        default,
+        rename = "post-switch",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub post_switch: Option<CommandConfig>,
+
     /// Commands to execute before committing during merge (blocking, fail-fast)
     #[serde(
         default,
@@ -179,6 +218,10 @@ pub struct WorktrunkConfig {
     #[serde(default, rename = "pre-merge", skip_serializing_if = "Option::is_none")]
     pub pre_merge: Option<CommandConfig>,
 
+    /// Commands to execute before pushing to the target branch (blocking, fail-fast)
+    #[serde(default, rename = "pre-push", skip_serializing_if = "Option::is_none")]
+    pub pre_push: Option<CommandConfig>,
+
     /// Commands to execute after successful merge (blocking, best-effort)
     #[serde(
         default,
@@ -195,6 +238,14 @@ pub struct WorktrunkConfig {
     )]
     pub pre_remove: Option<CommandConfig>,
 
+    /// Commands to execute after worktree removal (best-effort)
+    #[serde(
+        default,
+        rename = "post-remove",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub post_remove: Option<CommandConfig>,
+
     /// Captures unknown fields for validation warnings
     #[serde(flatten, default, skip_serializing)]
     pub(crate) unknown: std::collections::HashMap<String, toml::Value>,
@@ -213,7 +264,8 @@ pub struct CommitGenerationConfig {
     pub args: Vec<String>,
 
     /// Inline template for commit message prompt
-    /// Available variables: {{ git_diff }}, {{ branch }}, {{ recent_commits }}, {{ repo }}
+    /// Available variables: {{ git_diff }}, {{ diff_stat }}, {{ truncated }}, {{ changed_files }},
+    /// {{ suggested_scope }}, {{ branch }}, {{ recent_commits }}, {{ repo }}
     #[serde(default)]
     pub template: Option<String>,
 
@@ -231,15 +283,194 @@ pub struct CommitGenerationConfig {
     /// Supports tilde expansion (e.g., "~/.config/worktrunk/squash-template.txt")
     #[serde(default, rename = "squash-template-file")]
     pub squash_template_file: Option<String>,
+
+    /// Inline template for `wt step describe` pull request prompts
+    /// Available variables: as for squash-template
+    #[serde(default, rename = "pr-template")]
+    pub pr_template: Option<String>,
+
+    /// Path to PR template file (mutually exclusive with pr-template)
+    /// Supports tilde expansion (e.g., "~/.config/worktrunk/pr-template.txt")
+    #[serde(default, rename = "pr-template-file")]
+    pub pr_template_file: Option<String>,
+
+    /// Diff size above which the prompt gets a `--stat` summary and truncated
+    /// per-file hunks instead of the full diff, e.g. "100KB" (default: 100KB)
+    #[serde(default, rename = "max-diff-size")]
+    pub max_diff_size: Option<String>,
+
+    /// Paths left out of a truncated diff, as git glob pathspecs
+    /// (default: lockfiles and minified files)
+    #[serde(default, rename = "diff-exclude")]
+    pub diff_exclude: Option<Vec<String>>,
+
+    /// Check generated messages: "conventional" for Conventional Commits, or a
+    /// regex the whole first line (the subject) must match
+    #[serde(default)]
+    pub validate: Option<String>,
+
+    /// Requests to the LLM for a message that passes `validate` (default: 3)
+    #[serde(default, rename = "max-attempts")]
+    pub max_attempts: Option<u32>,
+
+    /// Where prompts are sent: "command" (default) runs `command`, while
+    /// "openai-compatible" and "anthropic" call an HTTP API directly
+    #[serde(default)]
+    pub provider: Option<LlmProvider>,
+
+    /// Base URL of the API, e.g. "http://localhost:11434/v1"
+    /// (default: the provider's public API)
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// Model to request from the API (required for HTTP providers)
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// Environment variable holding the API key
+    /// (default: OPENAI_API_KEY or ANTHROPIC_API_KEY, sent only when set)
+    #[serde(default, rename = "api-key-env")]
+    pub api_key_env: Option<String>,
+}
+
+/// How commit messages are generated (`commit-generation.provider`)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LlmProvider {
+    /// Run `command` with the prompt on stdin
+    #[default]
+    Command,
+    /// POST to an OpenAI-compatible `/chat/completions` endpoint (OpenAI,
+    /// llama.cpp, Ollama, vLLM, ...)
+    OpenaiCompatible,
+    /// POST to an Anthropic-style `/messages` endpoint
+    Anthropic,
+}
+
+impl LlmProvider {
+    /// Name as written in the config
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Command => "command",
+            Self::OpenaiCompatible => "openai-compatible",
+            Self::Anthropic => "anthropic",
+        }
+    }
+}
+
+/// How generated commit messages are checked (`commit-generation.validate`)
+#[derive(Debug, Clone)]
+pub enum MessageValidation {
+    /// Conventional Commits: `type(scope): subject`
+    Conventional,
+    /// A regex the whole first line must match; the body is free-form
+    Pattern {
+        /// As configured
+        pattern: String,
+        /// Anchored at both ends
+        regex: regex::Regex,
+    },
 }
 
 impl CommitGenerationConfig {
-    /// Returns true if an LLM command is configured
+    const DEFAULT_MAX_DIFF_SIZE: u64 = 100 * 1024;
+    const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+    const DEFAULT_DIFF_EXCLUDE: &[&str] = &[
+        "**/*.lock",
+        "**/package-lock.json",
+        "**/pnpm-lock.yaml",
+        "**/go.sum",
+        "**/*.min.js",
+        "**/*.min.css",
+        "**/*.map",
+    ];
+
+    /// Returns true if an LLM command or API is configured
     pub fn is_configured(&self) -> bool {
-        self.command
-            .as_ref()
-            .map(|s| !s.trim().is_empty())
-            .unwrap_or(false)
+        match self.provider() {
+            LlmProvider::Command => self
+                .command
+                .as_ref()
+                .map(|s| !s.trim().is_empty())
+                .unwrap_or(false),
+            LlmProvider::OpenaiCompatible | LlmProvider::Anthropic => true,
+        }
+    }
+
+    /// Where prompts are sent (default: command)
+    pub fn provider(&self) -> LlmProvider {
+        self.provider.unwrap_or_default()
+    }
+
+    /// Public API of the provider, and the environment variable holding its key
+    fn public_api(&self) -> (&'static str, &'static str) {
+        match self.provider() {
+            LlmProvider::Anthropic => ("https://api.anthropic.com/v1", "ANTHROPIC_API_KEY"),
+            _ => ("https://api.openai.com/v1", "OPENAI_API_KEY"),
+        }
+    }
+
+    /// Base URL of the provider's API
+    pub fn endpoint(&self) -> &str {
+        match &self.endpoint {
+            Some(endpoint) => endpoint.trim_end_matches('/'),
+            None => self.public_api().0,
+        }
+    }
+
+    /// Environment variable holding the API key, if any
+    ///
+    /// The provider's default variable only applies to its public API, so a key
+    /// from the environment isn't sent to another `endpoint` unless configured.
+    pub fn api_key_env(&self) -> Option<&str> {
+        let (public_endpoint, default_env) = self.public_api();
+        match &self.api_key_env {
+            Some(name) => Some(name),
+            None if self.endpoint() == public_endpoint => Some(default_env),
+            None => None,
+        }
+    }
+
+    /// Diff size budget in bytes
+    pub fn max_diff_size(&self) -> Result<u64, String> {
+        match &self.max_diff_size {
+            Some(size) => parse_size(size),
+            None => Ok(Self::DEFAULT_MAX_DIFF_SIZE),
+        }
+    }
+
+    /// How to check generated messages, if at all
+    pub fn validation(&self) -> Result<Option<MessageValidation>, String> {
+        match self.validate.as_deref() {
+            None => Ok(None),
+            Some("conventional") => Ok(Some(MessageValidation::Conventional)),
+            // Compiled alone first, so an unbalanced `)` can't escape the anchoring group
+            Some(pattern) => regex::Regex::new(pattern)
+                .and_then(|_| regex::Regex::new(&format!("^(?:{pattern})$")))
+                .map(|regex| {
+                    Some(MessageValidation::Pattern {
+                        pattern: pattern.to_string(),
+                        regex,
+                    })
+                })
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Requests for a valid message before giving up (at least 1)
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+            .unwrap_or(Self::DEFAULT_MAX_ATTEMPTS)
+            .max(1)
+    }
+
+    /// Glob patterns of paths to leave out of a truncated diff
+    pub fn diff_exclude(&self) -> Vec<&str> {
+        match &self.diff_exclude {
+            Some(patterns) => patterns.iter().map(String::as_str).collect(),
+            None => Self::DEFAULT_DIFF_EXCLUDE.to_vec(),
+        }
     }
 }
 
@@ -268,6 +499,10 @@ pub struct UserProjectConfig {
         skip_serializing_if = "Vec::is_empty"
     )]
     pub approved_commands: Vec<String>,
+
+    /// Sparse-checkout patterns for new worktrees, overriding the project's
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparse: Option<SparseConfig>,
 }
 
 /// Configuration for the `wt list` command
@@ -295,6 +530,10 @@ pub struct CommitConfig {
     /// Values: "all", "tracked", "none"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stage: Option<StageMode>,
+
+    /// Review generated messages before committing, as with `--edit` (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review: Option<bool>,
 }
 
 /// Configuration for the `wt merge` command
@@ -319,6 +558,220 @@ pub struct MergeConfig {
     pub verify: Option<bool>,
 }
 
+/// Retention of background operation logs
+///
+/// Each run of a background operation (post-start command, background removal)
+/// writes a new log; older runs are kept as `{branch}-{name}.log.1` (newest)
+/// through `{branch}-{name}.log.{keep-1}`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct LogsConfig {
+    /// Runs to keep per operation, including the latest (default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep: Option<usize>,
+
+    /// Total size cap for an operation's previous runs, e.g. "10MB" (default: 10MB)
+    ///
+    /// The oldest runs are dropped first. The latest run is never truncated.
+    #[serde(rename = "max-size", skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<String>,
+}
+
+impl LogsConfig {
+    const DEFAULT_KEEP: usize = 5;
+    const DEFAULT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
+    /// Runs to keep per operation (at least 1, the latest)
+    pub fn keep(&self) -> usize {
+        self.keep.unwrap_or(Self::DEFAULT_KEEP).max(1)
+    }
+
+    /// Size cap in bytes for an operation's previous runs
+    pub fn max_size(&self) -> Result<u64, String> {
+        match &self.max_size {
+            Some(size) => parse_size(size),
+            None => Ok(Self::DEFAULT_MAX_SIZE),
+        }
+    }
+}
+
+/// Configuration for the `wt list statusline` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct StatuslineConfig {
+    /// Template for the statusline, replacing the fixed layout
+    ///
+    /// Variables: `branch`, `status`, `working`, `ahead`, `behind`, `commits`,
+    /// `upstream`, `ci`, `dir` and `model`. Missing values are empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+
+    /// Context readers for `--context <name>`, mapping an agent's stdin JSON
+    /// to the statusline's fields. Overrides built-in readers of the same name.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub context: std::collections::BTreeMap<String, StatuslineContext>,
+}
+
+/// Where an agent's stdin JSON keeps the statusline's fields
+///
+/// Paths are JSONPath-style: `$.workspace.current_dir`, `model.name`, `roots[0]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StatuslineContext {
+    /// Path to the working directory
+    pub dir: String,
+
+    /// Path to the model name, shown after the status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+/// Configuration for the `wt select` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SelectConfig {
+    /// Preview tab shown first (default: uncommitted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<SelectPreview>,
+
+    /// Where the preview goes (default: auto, from the terminal's shape)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout: Option<SelectLayout>,
+
+    /// Height of the selector: rows, or a percentage of the terminal (default: "90%")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<String>,
+
+    /// Skim color spec applied over the default colors, e.g. "current_bg:236,current:252"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<String>,
+
+    /// Key bindings, in skim's key syntax (e.g. "alt-p", "ctrl-d")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<SelectKeys>,
+
+    /// Captures unknown fields for validation warnings
+    #[serde(flatten, default, skip_serializing)]
+    pub(crate) unknown: std::collections::HashMap<String, toml::Value>,
+}
+
+impl SelectConfig {
+    const DEFAULT_HEIGHT: &'static str = "90%";
+
+    /// Height as skim takes it: rows, or a percentage of the terminal
+    pub fn height(&self) -> &str {
+        self.height
+            .as_deref()
+            .unwrap_or(Self::DEFAULT_HEIGHT)
+            .trim()
+    }
+
+    /// Rows the selector takes in a terminal with `terminal_rows` rows
+    pub fn height_rows(&self, terminal_rows: usize) -> Result<usize, String> {
+        let height = self.height();
+        let rows = match height.strip_suffix('%') {
+            Some(percent) => percent
+                .parse::<usize>()
+                .ok()
+                .filter(|p| (1..=100).contains(p))
+                .map(|p| terminal_rows * p / 100),
+            None => height
+                .parse::<usize>()
+                .ok()
+                .filter(|&rows| rows > 0)
+                .map(|rows| rows.min(terminal_rows)),
+        };
+        rows.ok_or_else(|| format!("invalid height `{height}` (expected e.g. \"90%\" or \"30\")"))
+    }
+}
+
+/// Preview tab `wt select` opens with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SelectPreview {
+    /// Uncommitted changes (tab 1)
+    #[default]
+    Uncommitted,
+    /// Commit log (tab 2)
+    Log,
+    /// Changes vs the default branch (tab 3)
+    BranchDiff,
+    /// CI checks (tab 4)
+    Ci,
+    /// Post-start logs (tab 5)
+    Logs,
+}
+
+/// Placement of the `wt select` preview
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectLayout {
+    /// Right of the list in wide terminals, below it in tall ones
+    #[default]
+    Auto,
+    Right,
+    Down,
+}
+
+/// Key bindings for `wt select`; unset keys keep their defaults
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct SelectKeys {
+    /// Show/hide the preview (default: alt-p)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toggle_preview: Option<String>,
+
+    /// Scroll the preview up half a page (default: ctrl-u)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scroll_up: Option<String>,
+
+    /// Scroll the preview down half a page (default: ctrl-d)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scroll_down: Option<String>,
+
+    /// Create a worktree named after the query (default: alt-c)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create: Option<String>,
+
+    /// Remove the marked worktrees (default: alt-r)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<String>,
+
+    /// Merge the selected worktree (default: alt-m)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge: Option<String>,
+
+    /// Open the marked worktrees in `$EDITOR` (default: alt-e)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit: Option<String>,
+
+    /// Copy the marked worktree paths (default: alt-y)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copy: Option<String>,
+
+    /// Captures unknown fields for validation warnings
+    #[serde(flatten, default, skip_serializing)]
+    pub(crate) unknown: std::collections::HashMap<String, toml::Value>,
+}
+
+/// Parse a size such as `"512KB"`, `"10MB"`, `"1GB"` or a plain number of bytes
+fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (digits, unit) = value.split_at(split);
+    let number: u64 = digits
+        .parse()
+        .map_err(|_| format!("invalid size `{value}` (expected e.g. \"10MB\")"))?;
+    let multiplier = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" => 1024,
+        "M" | "MB" => 1024 * 1024,
+        "G" | "GB" => 1024 * 1024 * 1024,
+        _ => return Err(format!("invalid size `{value}` (expected e.g. \"10MB\")")),
+    };
+    number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("size `{value}` is too large"))
+}
+
 /// Default worktree path template (used by serde)
 fn default_worktree_path() -> String {
     "../{{ main_worktree }}.{{ branch }}".to_string()
@@ -333,12 +786,20 @@ impl Default for WorktrunkConfig {
             list: None,
             commit: None,
             merge: None,
+            logs: None,
+            select: None,
+            statusline: None,
+            pre_create: None,
             post_create: None,
             post_start: None,
+            pre_switch: None,
+            post_switch: None,
             pre_commit: None,
             pre_merge: None,
+            pre_push: None,
             post_merge: None,
             pre_remove: None,
+            post_remove: None,
             unknown: std::collections::HashMap::new(),
         }
     }
@@ -410,9 +871,81 @@ impl WorktrunkConfig {
             ));
         }
 
+        if config.commit_generation.pr_template.is_some()
+            && config.commit_generation.pr_template_file.is_some()
+        {
+            return Err(ConfigError::Message(
+                "commit-generation.pr-template and commit-generation.pr-template-file are mutually exclusive".into(),
+            ));
+        }
+
+        config
+            .commit_generation
+            .max_diff_size()
+            .map_err(|e| ConfigError::Message(format!("commit-generation.max-diff-size: {e}")))?;
+        config
+            .commit_generation
+            .validation()
+            .map_err(|e| ConfigError::Message(format!("commit-generation.validate: {e}")))?;
+
+        let provider = config.commit_generation.provider();
+        if provider != LlmProvider::Command && config.commit_generation.model.is_none() {
+            return Err(ConfigError::Message(format!(
+                "commit-generation.model is required with provider = \"{}\"",
+                provider.as_str()
+            )));
+        }
+
+        if let Some(logs) = &config.logs {
+            logs.max_size()
+                .map_err(|e| ConfigError::Message(format!("logs.max-size: {e}")))?;
+        }
+
+        if let Some(select) = &config.select {
+            select
+                .height_rows(100)
+                .map_err(|e| ConfigError::Message(format!("select.height: {e}")))?;
+        }
+
+        if let Some(template) = config
+            .statusline
+            .as_ref()
+            .and_then(|statusline| statusline.template.as_deref())
+        {
+            minijinja::Environment::new()
+                .template_from_str(template)
+                .map_err(|e| ConfigError::Message(format!("statusline.template: {e}")))?;
+        }
+
+        validate_hooks(config.hooks())?;
+
         Ok(config)
     }
 
+    /// All user hooks with their commands, keyed by hook type
+    pub fn hooks(&self) -> [(HookType, Option<&CommandConfig>); 11] {
+        [
+            (HookType::PreCreate, self.pre_create.as_ref()),
+            (HookType::PostCreate, self.post_create.as_ref()),
+            (HookType::PostStart, self.post_start.as_ref()),
+            (HookType::PreSwitch, self.pre_switch.as_ref()),
+            (HookType::PostSwitch, self.post_switch.as_ref()),
+            (HookType::PreCommit, self.pre_commit.as_ref()),
+            (HookType::PreMerge, self.pre_merge.as_ref()),
+            (HookType::PrePush, self.pre_push.as_ref()),
+            (HookType::PostMerge, self.post_merge.as_ref()),
+            (HookType::PreRemove, self.pre_remove.as_ref()),
+            (HookType::PostRemove, self.post_remove.as_ref()),
+        ]
+    }
+
+    /// Commands of one hook
+    pub fn hook(&self, hook_type: HookType) -> Option<&CommandConfig> {
+        self.hooks()
+            .into_iter()
+            .find_map(|(t, commands)| commands.filter(|_| t == hook_type))
+    }
+
     /// Format a worktree path using this configuration's template.
     ///
     /// # Arguments
@@ -486,7 +1019,7 @@ impl WorktrunkConfig {
             project_config.approved_commands.retain(|c| c != command);
             let changed = len_before != project_config.approved_commands.len();
 
-            if project_config.approved_commands.is_empty() {
+            if *project_config == UserProjectConfig::default() {
                 self.projects.remove(project);
             }
 
@@ -509,10 +1042,15 @@ impl WorktrunkConfig {
         project: &str,
         config_path: Option<&std::path::Path>,
     ) -> Result<(), ConfigError> {
-        if self.projects.remove(project).is_some() {
-            self.save_impl(config_path)?;
+        let Some(project_config) = self.projects.get_mut(project) else {
+            return Ok(());
+        };
+        project_config.approved_commands.clear();
+        // Keep other per-project settings
+        if *project_config == UserProjectConfig::default() {
+            self.projects.remove(project);
         }
-        Ok(())
+        self.save_impl(config_path)
     }
 
     /// Save the current configuration to the default config file location
@@ -592,6 +1130,13 @@ impl WorktrunkConfig {
                     if !projects.contains_key(project_id) {
                         projects[project_id] = toml_edit::Item::Table(toml_edit::Table::new());
                     }
+                    if project_config.approved_commands.is_empty() {
+                        // Other settings keep the table, e.g. `sparse`
+                        if let Some(table) = projects[project_id].as_table_like_mut() {
+                            table.remove("approved-commands");
+                        }
+                        continue;
+                    }
                     let commands =
                         Self::format_multiline_array(project_config.approved_commands.iter());
                     projects[project_id]["approved-commands"] = toml_edit::value(commands);
@@ -668,7 +1213,8 @@ pub fn get_config_path() -> Option<PathBuf> {
 
 /// Find unknown keys in user config TOML content
 ///
-/// Returns a list of unrecognized top-level keys that will be silently ignored.
+/// Returns a list of unrecognized keys that will be silently ignored: top-level keys,
+/// and keys of the `[select]` section as `select.<key>`.
 /// Uses serde deserialization with flatten to automatically detect unknown fields.
 pub fn find_unknown_keys(contents: &str) -> Vec<String> {
     // Deserialize into WorktrunkConfig - unknown fields are captured in the `unknown` map
@@ -676,5 +1222,21 @@ pub fn find_unknown_keys(contents: &str) -> Vec<String> {
         return vec![];
     };
 
-    config.unknown.into_keys().collect()
+    let mut unknown: Vec<String> = config.unknown.into_keys().collect();
+    if let Some(select) = config.select {
+        unknown.extend(
+            select
+                .unknown
+                .into_keys()
+                .map(|key| format!("select.{key}")),
+        );
+        if let Some(keys) = select.keys {
+            unknown.extend(
+                keys.unknown
+                    .into_keys()
+                    .map(|key| format!("select.keys.{key}")),
+            );
+        }
+    }
+    unknown
 }
@@ -65,6 +65,10 @@ pub enum GitError {
         branch: String,
         remote: String,
     },
+    AmbiguousRemoteBranch {
+        branch: String,
+        remotes: Vec<String>,
+    },
     WorktreePathOccupied {
         branch: String,
         path: PathBuf,
@@ -122,6 +126,20 @@ pub enum GitError {
         command: String,
         error: String,
     },
+    LlmRequestFailed {
+        model: String,
+        url: String,
+        error: String,
+    },
+    InvalidCommitMessage {
+        message: String,
+        error: String,
+        attempts: u32,
+    },
+    CommitReviewNotInteractive,
+    CommitAborted {
+        message_path: PathBuf,
+    },
     ProjectConfigNotFound {
         config_path: PathBuf,
     },
@@ -219,6 +237,23 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::AmbiguousRemoteBranch { branch, remotes } => {
+                let refs = remotes
+                    .iter()
+                    .map(|remote| cformat!("<bright-black>wt switch {remote}/{branch}</>"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "{}\n\n{}",
+                    error_message(cformat!(
+                        "Branch <bold>{branch}</> exists on several remotes ({})",
+                        remotes.join(", ")
+                    )),
+                    hint_message(format!("Pick one with {refs}"))
+                )
+            }
+
             GitError::WorktreePathOccupied {
                 branch,
                 path,
@@ -460,6 +495,63 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::LlmRequestFailed { model, url, error } => {
+                let error_header = error_message("Commit generation request failed");
+                let error_block = format_error_block(error_header, error);
+                let url_gutter = format_with_gutter(url, "", None);
+                write!(
+                    f,
+                    "{}\n\n{}\n{}",
+                    error_block.trim_end(),
+                    info_message(cformat!("Requested <bold>{model}</> from:")),
+                    url_gutter.trim_end()
+                )
+            }
+
+            GitError::InvalidCommitMessage {
+                message,
+                error,
+                attempts,
+            } => {
+                let tries = if *attempts == 1 { "try" } else { "tries" };
+                write!(
+                    f,
+                    "{}\n{}\n\n{}",
+                    error_message(cformat!(
+                        "Generated commit message is invalid after <bold>{attempts}</> {tries}: {error}"
+                    )),
+                    format_with_gutter(message, "", None).trim_end(),
+                    hint_message(cformat!(
+                        "Adjust the prompt template, or <bright-black>validate</> and <bright-black>max-attempts</> in <bright-black>[commit-generation]</>"
+                    ))
+                )
+            }
+
+            GitError::CommitReviewNotInteractive => {
+                write!(
+                    f,
+                    "{}\n\n{}",
+                    error_message(
+                        "Cannot review the commit message in non-interactive environment"
+                    ),
+                    hint_message(cformat!(
+                        "Commit without <bright-black>--edit</>, or set <bright-black>commit.review = false</>"
+                    ))
+                )
+            }
+
+            GitError::CommitAborted { message_path } => {
+                write!(
+                    f,
+                    "{}\n\n{}",
+                    error_message("Commit aborted"),
+                    hint_message(cformat!(
+                        "Message saved to <bright-black>{}</>; the next review starts from it",
+                        format_path_for_display(message_path)
+                    ))
+                )
+            }
+
             GitError::ProjectConfigNotFound { config_path } => {
                 let path_display = format_path_for_display(config_path);
                 write!(
@@ -496,6 +588,12 @@ pub enum WorktrunkError {
         error: String,
         exit_code: Option<i32>,
     },
+    /// Hook command exceeded its configured timeout and was killed
+    HookCommandTimedOut {
+        hook_type: HookType,
+        command_name: Option<String>,
+        elapsed: std::time::Duration,
+    },
     /// Command was not approved by user (silent error)
     CommandNotApproved,
 }
@@ -528,6 +626,30 @@ impl std::fmt::Display for WorktrunkError {
                     ))
                 )
             }
+            WorktrunkError::HookCommandTimedOut {
+                hook_type,
+                command_name,
+                elapsed,
+            } => {
+                let secs = elapsed.as_secs();
+                let err_msg = if let Some(name) = command_name {
+                    error_message(cformat!(
+                        "{hook_type} command timed out: <bold>{name}</> was killed after {secs}s"
+                    ))
+                } else {
+                    error_message(format!(
+                        "{hook_type} command timed out: killed after {secs}s"
+                    ))
+                };
+                write!(
+                    f,
+                    "{}\n\n{}",
+                    err_msg,
+                    hint_message(cformat!(
+                        "Use <bright-black>--no-verify</> to skip {hook_type} commands"
+                    ))
+                )
+            }
             WorktrunkError::CommandNotApproved => {
                 Ok(()) // on_skip callback handles the printing
             }
@@ -542,6 +664,8 @@ pub fn exit_code(err: &anyhow::Error) -> Option<i32> {
     err.downcast_ref::<WorktrunkError>().and_then(|e| match e {
         WorktrunkError::ChildProcessExited { code, .. } => Some(*code),
         WorktrunkError::HookCommandFailed { exit_code, .. } => *exit_code,
+        // Same convention as timeout(1)
+        WorktrunkError::HookCommandTimedOut { .. } => Some(124),
         WorktrunkError::CommandNotApproved => None,
     })
 }
@@ -134,12 +134,17 @@ pub(crate) use parse::DefaultBranchName;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, strum::Display)]
 #[strum(serialize_all = "kebab-case")]
 pub enum HookType {
+    PreCreate,
     PostCreate,
     PostStart,
+    PreSwitch,
+    PostSwitch,
     PreCommit,
     PreMerge,
+    PrePush,
     PostMerge,
     PreRemove,
+    PostRemove,
 }
 
 /// Worktree information
@@ -212,6 +212,11 @@ impl Repository {
 
         // Try remote branch
         let remote = self.primary_remote()?;
+        self.remote_branch_exists(&remote, branch)
+    }
+
+    /// Check if a remote-tracking branch exists (e.g., `origin/feature`).
+    pub fn remote_branch_exists(&self, remote: &str, branch: &str) -> anyhow::Result<bool> {
         Ok(self
             .run_command(&[
                 "rev-parse",
@@ -248,6 +253,28 @@ impl Repository {
         Ok(remotes)
     }
 
+    /// List the configured remotes (e.g., `["origin", "upstream"]`).
+    pub fn remotes(&self) -> anyhow::Result<Vec<String>> {
+        let output = self.run_command(&["remote"])?;
+        Ok(output.lines().map(|line| line.trim().to_string()).collect())
+    }
+
+    /// Split `<remote>/<branch>` into its remote and branch, if `<remote>` is configured.
+    ///
+    /// The longest matching remote wins, so remote names containing `/` work.
+    /// Returns None for names that don't start with a configured remote.
+    pub fn split_remote_ref(&self, name: &str) -> anyhow::Result<Option<(String, String)>> {
+        let split = self
+            .remotes()?
+            .into_iter()
+            .filter_map(|remote| {
+                let branch = name.strip_prefix(&remote)?.strip_prefix('/')?;
+                (!branch.is_empty()).then(|| (remote.clone(), branch.to_string()))
+            })
+            .max_by_key(|(remote, _)| remote.len());
+        Ok(split)
+    }
+
     /// Get the current branch name, or None if in detached HEAD state.
     pub fn current_branch(&self) -> anyhow::Result<Option<String>> {
         let stdout = self.run_command(&["branch", "--show-current"])?;
@@ -598,6 +625,24 @@ impl Repository {
             .unwrap_or(false)
     }
 
+    /// Check if this worktree has a sparse checkout.
+    pub fn is_sparse_checkout(&self) -> bool {
+        self.run_command(&["config", "--bool", "core.sparseCheckout"])
+            .map(|s| s.trim() == "true")
+            .unwrap_or(false)
+    }
+
+    /// Sparse-checkout patterns of this worktree (directories, in cone mode).
+    ///
+    /// Returns an empty list if the worktree isn't sparse.
+    pub fn sparse_checkout_paths(&self) -> anyhow::Result<Vec<String>> {
+        if !self.is_sparse_checkout() {
+            return Ok(Vec::new());
+        }
+        let output = self.run_command(&["sparse-checkout", "list"])?;
+        Ok(output.lines().map(str::to_string).collect())
+    }
+
     /// Start the fsmonitor daemon for this worktree.
     ///
     /// This is idempotent - if the daemon is already running, this is a no-op.
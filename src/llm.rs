@@ -1,20 +1,30 @@
 use anyhow::Context;
+use serde::Deserialize;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{self, Stdio};
-use worktrunk::config::CommitGenerationConfig;
+use worktrunk::config::{CommitGenerationConfig, LlmProvider, MessageValidation};
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
+use worktrunk::styling::warning_message;
 
 use minijinja::Environment;
 
 /// Context data for building LLM prompts
 ///
 /// All fields are available to both commit and squash templates.
-/// Squash-specific fields (`commits`, `target_branch`) are empty/None for regular commits.
+/// Squash and PR fields (`commits`, `target_branch`) are empty/None for regular commits.
 struct TemplateContext<'a> {
     /// The diff to describe (staged changes for commit, combined diff for squash)
     git_diff: &'a str,
+    /// `git diff --stat` summary of the same changes
+    diff_stat: &'a str,
+    /// Whether `git_diff` was cut down to fit `max-diff-size`
+    truncated: bool,
+    /// Paths of all changed files, including ones left out of a truncated diff
+    changed_files: &'a [String],
+    /// Conventional Commits scope suggested by the changed paths
+    suggested_scope: Option<&'a str>,
     /// Current branch name
     branch: &'a str,
     /// Recent commit subjects for style reference
@@ -27,6 +37,567 @@ struct TemplateContext<'a> {
     target_branch: Option<&'a str>,
 }
 
+/// The diff for a prompt, fitted to the configured size budget
+struct PromptDiff {
+    /// The full diff, or per-file truncated hunks when over budget
+    git_diff: String,
+    diff_stat: String,
+    truncated: bool,
+    changed_files: Vec<String>,
+    suggested_scope: Option<String>,
+}
+
+impl PromptDiff {
+    /// Collect `git diff <range_args>`, truncating it when over `max-diff-size`
+    ///
+    /// An oversized diff leaves out `diff-exclude` paths and binary files, and
+    /// shares the budget (less the `--stat` summary) among the remaining files.
+    fn collect(
+        repo: &Repository,
+        range_args: &[&str],
+        config: &CommitGenerationConfig,
+    ) -> anyhow::Result<Self> {
+        let git = |args: &[&str]| {
+            let mut full_args = vec!["--no-pager", "diff"];
+            full_args.extend_from_slice(args);
+            full_args.extend_from_slice(range_args);
+            repo.run_command(&full_args)
+        };
+
+        let git_diff = git(&[])?;
+        let diff_stat = git(&["--stat"])?;
+        let changed_files: Vec<String> = git(&["--name-only"])?.lines().map(String::from).collect();
+        let suggested_scope = suggest_scope(&changed_files);
+
+        let budget = config.max_diff_size().map_err(anyhow::Error::msg)? as usize;
+        if git_diff.len() <= budget {
+            return Ok(Self {
+                git_diff,
+                diff_stat,
+                truncated: false,
+                changed_files,
+                suggested_scope,
+            });
+        }
+
+        // Pathspecs go after the range, so build this one in full
+        let excludes: Vec<String> = config
+            .diff_exclude()
+            .iter()
+            .map(|pattern| format!(":(top,exclude,glob){pattern}"))
+            .collect();
+        let mut args = vec!["--no-pager", "diff"];
+        args.extend_from_slice(range_args);
+        args.extend(["--", ":/"]);
+        args.extend(excludes.iter().map(String::as_str));
+        let filtered = repo.run_command(&args)?;
+
+        let files: Vec<&str> = split_diff_files(&filtered)
+            .into_iter()
+            .filter(|file| !file.contains("\nBinary files "))
+            .collect();
+        Ok(Self {
+            git_diff: truncate_diff_files(&files, budget.saturating_sub(diff_stat.len())),
+            diff_stat,
+            truncated: true,
+            changed_files,
+            suggested_scope,
+        })
+    }
+
+    fn context<'a>(
+        &'a self,
+        branch: &'a str,
+        recent_commits: Option<&'a Vec<String>>,
+        repo_name: &'a str,
+    ) -> TemplateContext<'a> {
+        TemplateContext {
+            git_diff: &self.git_diff,
+            diff_stat: &self.diff_stat,
+            truncated: self.truncated,
+            changed_files: &self.changed_files,
+            suggested_scope: self.suggested_scope.as_deref(),
+            branch,
+            recent_commits,
+            repo_name,
+            commits: &[],
+            target_branch: None,
+        }
+    }
+}
+
+/// Directory names too generic to be a commit scope
+const GENERIC_DIRS: &[&str] = &[
+    "src", "lib", "source", "pkg", "internal", "crates", "packages", "apps", "libs",
+];
+
+/// Conventional Commits scope for a change: the deepest directory shared by all
+/// changed paths, skipping generic names like `src`
+///
+/// `src/commands/list/model.rs` and `src/commands/list/render.rs` give `list`;
+/// changes spread across top-level directories give none.
+fn suggest_scope(changed_files: &[String]) -> Option<String> {
+    let mut dirs = changed_files.iter().map(|path| {
+        let mut components: Vec<&str> = path.split('/').collect();
+        components.pop(); // The file name
+        components
+    });
+    let mut common = dirs.next()?;
+    for dir in dirs {
+        let shared = common.iter().zip(&dir).take_while(|(a, b)| a == b).count();
+        common.truncate(shared);
+    }
+    common
+        .iter()
+        .rev()
+        .find(|dir| !GENERIC_DIRS.contains(dir))
+        .map(|dir| dir.to_string())
+}
+
+/// Commit types accepted by `validate = "conventional"`
+const CONVENTIONAL_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// Longest first line accepted by `validate = "conventional"`
+const CONVENTIONAL_MAX_HEADER: usize = 72;
+
+/// Check a generated message, returning why it's invalid
+fn check_message(validation: &MessageValidation, message: &str) -> Result<(), String> {
+    match validation {
+        MessageValidation::Pattern { pattern, regex } => {
+            let subject = message.lines().next().unwrap_or_default();
+            if regex.is_match(subject) {
+                Ok(())
+            } else {
+                Err(format!("it doesn't match `{pattern}`"))
+            }
+        }
+        MessageValidation::Conventional => check_conventional(message),
+    }
+}
+
+/// Check `type(scope)!: subject`, the subject length, and the blank line before a body
+fn check_conventional(message: &str) -> Result<(), String> {
+    let mut lines = message.lines();
+    let header = lines.next().unwrap_or_default();
+    let Some((prefix, subject)) = header.split_once(": ") else {
+        return Err("the first line must look like `type(scope): subject`".to_string());
+    };
+
+    let prefix = prefix.strip_suffix('!').unwrap_or(prefix);
+    let commit_type = match prefix.split_once('(') {
+        Some((commit_type, scope)) => {
+            let scope = scope
+                .strip_suffix(')')
+                .ok_or("the scope must be closed with `)`")?;
+            if scope.is_empty() || scope.contains(char::is_whitespace) {
+                return Err(format!("the scope `{scope}` must be a single word"));
+            }
+            commit_type
+        }
+        None => prefix,
+    };
+    if !CONVENTIONAL_TYPES.contains(&commit_type) {
+        return Err(format!(
+            "`{commit_type}` isn't a commit type (use one of {})",
+            CONVENTIONAL_TYPES.join(", ")
+        ));
+    }
+
+    if subject.trim().is_empty() {
+        return Err("the subject is empty".to_string());
+    }
+    if subject.ends_with('.') {
+        return Err("the subject must not end with a period".to_string());
+    }
+    let length = header.chars().count();
+    if length > CONVENTIONAL_MAX_HEADER {
+        return Err(format!(
+            "the first line is {length} characters (at most {CONVENTIONAL_MAX_HEADER})"
+        ));
+    }
+    if lines.next().is_some_and(|line| !line.trim().is_empty()) {
+        return Err("the first line must be followed by a blank line".to_string());
+    }
+    Ok(())
+}
+
+/// Ask the provider until its message passes `validate`, feeding back why
+/// the previous one was rejected
+fn execute_validated(
+    provider: &Provider,
+    prompt: &str,
+    config: &CommitGenerationConfig,
+) -> anyhow::Result<String> {
+    let Some(validation) = config.validation().map_err(anyhow::Error::msg)? else {
+        return provider.generate(prompt);
+    };
+
+    let attempts = config.max_attempts();
+    let mut message = provider.generate(prompt)?;
+    let mut checked = check_message(&validation, &message);
+    for attempt in 2..=attempts {
+        let Err(error) = &checked else {
+            break;
+        };
+        crate::output::print(warning_message(format!(
+            "Generated message is invalid: {error}; retrying ({attempt}/{attempts})..."
+        )))?;
+        let retry_prompt = format!(
+            "{prompt}\n\nYour previous message was rejected because {error}:\n\n{message}\n\nWrite a new message that fixes this.\n"
+        );
+        message = provider.generate(&retry_prompt)?;
+        checked = check_message(&validation, &message);
+    }
+
+    match checked {
+        Ok(()) => Ok(message),
+        Err(error) => Err(worktrunk::git::GitError::InvalidCommitMessage {
+            message,
+            error,
+            attempts,
+        }
+        .into()),
+    }
+}
+
+/// Where prompts are sent for a message (`commit-generation.provider`)
+enum Provider<'a> {
+    /// An external command, given the prompt on stdin
+    Command {
+        command: &'a str,
+        args: &'a [String],
+    },
+    /// An OpenAI-compatible `/chat/completions` API
+    OpenaiCompatible(HttpApi<'a>),
+    /// An Anthropic-style `/messages` API
+    Anthropic(HttpApi<'a>),
+}
+
+/// An HTTP API that generates messages
+struct HttpApi<'a> {
+    endpoint: &'a str,
+    model: &'a str,
+    /// Environment variable holding the API key, if one is sent
+    api_key_env: Option<&'a str>,
+    /// Whether `api_key_env` was configured, so the key must be set
+    require_key: bool,
+}
+
+impl<'a> Provider<'a> {
+    /// The configured provider, or None when commit generation isn't configured
+    fn from_config(config: &'a CommitGenerationConfig) -> anyhow::Result<Option<Self>> {
+        if !config.is_configured() {
+            return Ok(None);
+        }
+        let provider = config.provider();
+        let http_api = || -> anyhow::Result<HttpApi<'a>> {
+            Ok(HttpApi {
+                endpoint: config.endpoint(),
+                model: config.model.as_deref().with_context(|| {
+                    format!(
+                        "commit-generation.model is required with provider = \"{}\"",
+                        provider.as_str()
+                    )
+                })?,
+                api_key_env: config.api_key_env(),
+                require_key: config.api_key_env.is_some(),
+            })
+        };
+        Ok(Some(match provider {
+            // is_configured() checked the command
+            LlmProvider::Command => Self::Command {
+                command: config.command.as_deref().unwrap_or_default(),
+                args: &config.args,
+            },
+            LlmProvider::OpenaiCompatible => Self::OpenaiCompatible(http_api()?),
+            LlmProvider::Anthropic => Self::Anthropic(http_api()?),
+        }))
+    }
+
+    /// Generate a message for `prompt`
+    fn generate(&self, prompt: &str) -> anyhow::Result<String> {
+        let message = match self {
+            Self::Command { command, args } => return execute_llm_command(command, args, prompt),
+            Self::OpenaiCompatible(api) => api.chat_completion(&self.url(), prompt)?,
+            Self::Anthropic(api) => api.messages(&self.url(), prompt)?,
+        };
+
+        let message = message.trim();
+        if message.is_empty() {
+            return Err(worktrunk::git::GitError::Other {
+                message: "LLM returned empty message".into(),
+            }
+            .into());
+        }
+        Ok(message.to_owned())
+    }
+
+    /// URL requested by HTTP providers
+    fn url(&self) -> String {
+        match self {
+            Self::Command { .. } => String::new(),
+            Self::OpenaiCompatible(api) => format!("{}/chat/completions", api.endpoint),
+            Self::Anthropic(api) => format!("{}/messages", api.endpoint),
+        }
+    }
+
+    /// The command run, or the model and URL requested
+    fn describe(&self) -> String {
+        match self {
+            Self::Command { command, args } => format_command_display(command, args),
+            Self::OpenaiCompatible(api) | Self::Anthropic(api) => {
+                format!("{} ({})", api.model, self.url())
+            }
+        }
+    }
+
+    /// Wrap a failure to generate a message, keeping validation failures as they are
+    fn failed(&self, error: anyhow::Error) -> anyhow::Error {
+        let error = match error.downcast::<worktrunk::git::GitError>() {
+            Ok(error @ worktrunk::git::GitError::InvalidCommitMessage { .. }) => {
+                return error.into();
+            }
+            Ok(error) => error.to_string(),
+            Err(error) => error.to_string(),
+        };
+        match self {
+            Self::Command { command, args } => worktrunk::git::GitError::LlmCommandFailed {
+                command: format_command_display(command, args),
+                error,
+            }
+            .into(),
+            Self::OpenaiCompatible(api) | Self::Anthropic(api) => {
+                worktrunk::git::GitError::LlmRequestFailed {
+                    model: api.model.to_string(),
+                    url: self.url(),
+                    error,
+                }
+                .into()
+            }
+        }
+    }
+}
+
+/// Time allowed for an HTTP provider to respond; local models can be slow
+#[cfg(feature = "llm-http")]
+const HTTP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Max tokens requested from Anthropic-style APIs, which require a limit
+const ANTHROPIC_MAX_TOKENS: u32 = 1024;
+
+/// `anthropic-version` header for Anthropic-style APIs
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+#[derive(Deserialize)]
+struct ChatCompletion {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicContent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    text: String,
+}
+
+impl HttpApi<'_> {
+    /// The API key from `api_key_env`, if set
+    fn api_key(&self) -> anyhow::Result<Option<String>> {
+        let Some(name) = self.api_key_env else {
+            return Ok(None);
+        };
+        match std::env::var(name) {
+            Ok(key) if !key.is_empty() => Ok(Some(key)),
+            _ if self.require_key => anyhow::bail!("{name} is not set"),
+            _ => Ok(None),
+        }
+    }
+
+    /// Request a message from an OpenAI-compatible `/chat/completions` API
+    fn chat_completion(&self, url: &str, prompt: &str) -> anyhow::Result<String> {
+        let mut headers = Vec::new();
+        if let Some(key) = self.api_key()? {
+            headers.push(("authorization", format!("Bearer {key}")));
+        }
+        let body = serde_json::json!({
+            "model": self.model,
+            "messages": [{ "role": "user", "content": prompt }],
+        });
+        let response: ChatCompletion = self.post(url, &headers, &body, prompt)?;
+        Ok(response
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .unwrap_or_default())
+    }
+
+    /// Request a message from an Anthropic-style `/messages` API
+    fn messages(&self, url: &str, prompt: &str) -> anyhow::Result<String> {
+        let mut headers = vec![("anthropic-version", ANTHROPIC_VERSION.to_string())];
+        if let Some(key) = self.api_key()? {
+            headers.push(("x-api-key", key));
+        }
+        let body = serde_json::json!({
+            "model": self.model,
+            "max_tokens": ANTHROPIC_MAX_TOKENS,
+            "messages": [{ "role": "user", "content": prompt }],
+        });
+        let response: AnthropicResponse = self.post(url, &headers, &body, prompt)?;
+        Ok(response
+            .content
+            .into_iter()
+            .filter(|block| block.kind == "text")
+            .map(|block| block.text)
+            .collect())
+    }
+
+    /// POST a JSON request, parsing the JSON response
+    #[cfg(feature = "llm-http")]
+    fn post<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        body: &serde_json::Value,
+        prompt: &str,
+    ) -> anyhow::Result<T> {
+        log::debug!("$ POST {url} (model {})", self.model);
+        log::debug!("  Prompt:");
+        for line in prompt.lines() {
+            log::debug!("    {}", line);
+        }
+
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(HTTP_TIMEOUT))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        let mut request = agent.post(url).header("content-type", "application/json");
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        let mut response = request.send(body.to_string())?;
+        let status = response.status();
+        let text = response.body_mut().read_to_string()?;
+
+        if !status.is_success() {
+            anyhow::bail!("HTTP {}: {}", status.as_u16(), api_error_message(&text));
+        }
+        serde_json::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("Unexpected response ({e}): {}", text.trim()))
+    }
+
+    #[cfg(not(feature = "llm-http"))]
+    fn post<T: for<'de> Deserialize<'de>>(
+        &self,
+        _url: &str,
+        _headers: &[(&str, String)],
+        _body: &serde_json::Value,
+        _prompt: &str,
+    ) -> anyhow::Result<T> {
+        anyhow::bail!("HTTP providers need wt built with the `llm-http` feature")
+    }
+}
+
+/// The error in an API's error response: `error.message` as returned by
+/// OpenAI and Anthropic, a bare `error` string, or the whole body
+#[cfg(feature = "llm-http")]
+fn api_error_message(body: &str) -> String {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ApiError {
+        Detailed { message: String },
+        Message(String),
+    }
+    #[derive(Deserialize)]
+    struct ErrorResponse {
+        error: ApiError,
+    }
+
+    match serde_json::from_str::<ErrorResponse>(body) {
+        Ok(ErrorResponse {
+            error: ApiError::Detailed { message } | ApiError::Message(message),
+        }) => message,
+        Err(_) => body.trim().to_string(),
+    }
+}
+
+/// The configured provider, as shown by `wt config show --doctor`
+pub fn describe_provider(config: &CommitGenerationConfig) -> anyhow::Result<Option<String>> {
+    Ok(Provider::from_config(config)?.map(|provider| provider.describe()))
+}
+
+/// Split a diff into per-file sections, each starting at its `diff --git` line
+fn split_diff_files(diff: &str) -> Vec<&str> {
+    let starts: Vec<usize> = diff
+        .match_indices("diff --git ")
+        .map(|(index, _)| index)
+        .filter(|&index| index == 0 || diff.as_bytes()[index - 1] == b'\n')
+        .collect();
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| &diff[start..starts.get(i + 1).copied().unwrap_or(diff.len())])
+        .collect()
+}
+
+/// Fit per-file diffs into about `budget` bytes, cutting the largest ones
+///
+/// Files smaller than an even share of the budget stay whole, and what they
+/// don't use is shared by the rest. Cut files end at a line boundary, with a
+/// note of how many lines were left out.
+fn truncate_diff_files(files: &[&str], budget: usize) -> String {
+    let mut by_size: Vec<usize> = (0..files.len()).collect();
+    by_size.sort_by_key(|&i| files[i].len());
+
+    let mut allotted = vec![0; files.len()];
+    let mut remaining = budget;
+    for (rank, &i) in by_size.iter().enumerate() {
+        let share = remaining / (files.len() - rank);
+        allotted[i] = files[i].len().min(share);
+        remaining -= allotted[i];
+    }
+
+    let mut output = String::new();
+    for (file, &allotted) in files.iter().zip(&allotted) {
+        if allotted >= file.len() {
+            output.push_str(file);
+            continue;
+        }
+        // Cut after the last whole line that fits, keeping at least the header line
+        let header_end = file.find('\n').map_or(file.len(), |i| i + 1);
+        let cut = file.as_bytes()[..allotted]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(header_end, |i| (i + 1).max(header_end));
+        output.push_str(&file[..cut]);
+        let omitted = file[cut..].lines().count();
+        if omitted > 0 {
+            output.push_str(&format!("[... {omitted} more lines]\n"));
+        }
+    }
+    output
+}
+
 /// Format a command and its arguments into a display string
 fn format_command_display(command: &str, args: &[String]) -> String {
     if args.is_empty() {
@@ -52,13 +623,21 @@ const DEFAULT_TEMPLATE: &str = r#"Write a commit message for the staged changes
 - Describe the change, not the intent or benefit
 </style>
 
-<diff>
+{% if truncated %}<diff_stat>
+{{ diff_stat }}
+</diff_stat>
+
+The diff below is truncated to fit: large files are cut short, and lockfiles,
+binary and generated files are left out.
+
+{% endif %}<diff>
 {{ git_diff }}
 </diff>
 
 <context>
 Branch: {{ branch }}
-{% if recent_commits %}<recent_commits>
+{% if suggested_scope %}Scope, if the repo uses scopes: {{ suggested_scope }}
+{% endif %}{% if recent_commits %}<recent_commits>
 {% for commit in recent_commits %}- {{ commit }}
 {% endfor %}</recent_commits>{% endif %}
 </context>
@@ -84,15 +663,57 @@ const DEFAULT_SQUASH_TEMPLATE: &str = r#"Combine these commits into a single com
 {% for commit in commits %}- {{ commit }}
 {% endfor %}</commits>
 
-<diff>
+{% if truncated %}<diff_stat>
+{{ diff_stat }}
+</diff_stat>
+
+The diff below is truncated to fit: large files are cut short, and lockfiles,
+binary and generated files are left out.
+
+{% endif %}<diff>
+{{ git_diff }}
+</diff>
+"#;
+
+/// Default template for `wt step describe` pull request prompts
+///
+/// Synced to dev/config.example.toml by `cargo test readme_sync`
+const DEFAULT_PR_TEMPLATE: &str = r#"Write a pull request title and description for the changes below.
+
+<format>
+- First line: the title, under 72 chars, plain text
+- Blank line, then a markdown body with these sections:
+  ## Summary: what changed and why, in a few bullets
+  ## Testing: how the change was tested, or how a reviewer can test it
+  ## Risks: what could break, and what reviewers should look at closely
+- Output only the title and body, no code fences around them
+</format>
+
+<style>
+- Describe the change as it is now, not the history of the commits
+- Be specific and brief; write "None" for a section with nothing to say
+- Match the style of the commit subjects in the title
+</style>
+
+<commits branch="{{ branch }}" target="{{ target_branch }}">
+{% for commit in commits %}- {{ commit }}
+{% endfor %}</commits>
+
+{% if truncated %}<diff_stat>
+{{ diff_stat }}
+</diff_stat>
+
+The diff below is truncated to fit: large files are cut short, and lockfiles,
+binary and generated files are left out.
+
+{% endif %}<diff>
 {{ git_diff }}
 </diff>
 "#;
 
 /// Execute an LLM command with the given prompt via stdin.
 ///
-/// This is the canonical way to execute LLM commands in this codebase.
-/// All LLM execution should go through this function to maintain consistency.
+/// The `command` provider; all LLM requests go through [`Provider::generate`].
 fn execute_llm_command(command: &str, args: &[String], prompt: &str) -> anyhow::Result<String> {
     // Build command args
     let mut cmd = process::Command::new(command);
@@ -147,17 +768,65 @@ fn execute_llm_command(command: &str, args: &[String], prompt: &str) -> anyhow::
 enum TemplateType {
     Commit,
     Squash,
+    PullRequest,
+}
+
+/// Subjects of the last few non-merge commits, up to `until` (default HEAD),
+/// for style reference
+fn recent_subjects(repo: &Repository, until: Option<&str>) -> Option<Vec<String>> {
+    let mut args = vec!["log", "--pretty=format:%s", "-n", "5", "--no-merges"];
+    args.extend(until);
+    repo.run_command(&args).ok().and_then(|output| {
+        if output.trim().is_empty() {
+            None
+        } else {
+            Some(output.lines().map(String::from).collect::<Vec<_>>())
+        }
+    })
+}
+
+/// Template source: inline, from a file, or the default
+fn load_template(
+    inline: &Option<String>,
+    file: &Option<String>,
+    keys: (&str, &str),
+    default: &str,
+) -> anyhow::Result<String> {
+    let (inline_key, file_key) = keys;
+    Ok(match (inline, file) {
+        (Some(inline), None) => inline.clone(),
+        (None, Some(path)) => {
+            let expanded_path = PathBuf::from(shellexpand::tilde(path).as_ref());
+            std::fs::read_to_string(&expanded_path).map_err(|e| {
+                anyhow::Error::from(worktrunk::git::GitError::Other {
+                    message: format!(
+                        "Failed to read {file_key} '{}': {}",
+                        format_path_for_display(&expanded_path),
+                        e
+                    ),
+                })
+            })?
+        }
+        (None, None) => default.to_string(),
+        (Some(_), Some(_)) => {
+            unreachable!("Config validation should prevent both {inline_key} and {file_key}")
+        }
+    })
 }
 
 /// Build prompt from template using minijinja
 ///
 /// Template variables available to both commit and squash templates:
 /// - `git_diff`: The diff to describe
+/// - `diff_stat`: `git diff --stat` summary
+/// - `truncated`: Whether `git_diff` was cut down to fit `max-diff-size`
+/// - `changed_files`: Paths of all changed files
+/// - `suggested_scope`: Conventional Commits scope from the changed paths, if any
 /// - `branch`: Current branch name
 /// - `recent_commits`: Recent commit subjects for style reference
 /// - `repo`: Repository name
 ///
-/// Squash-specific variables (empty for regular commits):
+/// Squash and PR variables (empty for regular commits):
 /// - `commits`: Commits being squashed
 /// - `target_branch`: Target branch for merge
 fn build_prompt(
@@ -167,52 +836,33 @@ fn build_prompt(
 ) -> anyhow::Result<String> {
     // Get template source based on type
     let (template, type_name) = match template_type {
-        TemplateType::Commit => {
-            let tmpl = match (&config.template, &config.template_file) {
-                (Some(inline), None) => inline.clone(),
-                (None, Some(path)) => {
-                    let expanded_path = PathBuf::from(shellexpand::tilde(path).as_ref());
-                    std::fs::read_to_string(&expanded_path).map_err(|e| {
-                        anyhow::Error::from(worktrunk::git::GitError::Other {
-                            message: format!(
-                                "Failed to read template-file '{}': {}",
-                                format_path_for_display(&expanded_path),
-                                e
-                            ),
-                        })
-                    })?
-                }
-                (None, None) => DEFAULT_TEMPLATE.to_string(),
-                (Some(_), Some(_)) => {
-                    unreachable!("Config validation should prevent both template and template-file")
-                }
-            };
-            (tmpl, "Template")
-        }
-        TemplateType::Squash => {
-            let tmpl = match (&config.squash_template, &config.squash_template_file) {
-                (Some(inline), None) => inline.clone(),
-                (None, Some(path)) => {
-                    let expanded_path = PathBuf::from(shellexpand::tilde(path).as_ref());
-                    std::fs::read_to_string(&expanded_path).map_err(|e| {
-                        anyhow::Error::from(worktrunk::git::GitError::Other {
-                            message: format!(
-                                "Failed to read squash-template-file '{}': {}",
-                                format_path_for_display(&expanded_path),
-                                e
-                            ),
-                        })
-                    })?
-                }
-                (None, None) => DEFAULT_SQUASH_TEMPLATE.to_string(),
-                (Some(_), Some(_)) => {
-                    unreachable!(
-                        "Config validation should prevent both squash-template and squash-template-file"
-                    )
-                }
-            };
-            (tmpl, "Squash template")
-        }
+        TemplateType::Commit => (
+            load_template(
+                &config.template,
+                &config.template_file,
+                ("template", "template-file"),
+                DEFAULT_TEMPLATE,
+            )?,
+            "Template",
+        ),
+        TemplateType::Squash => (
+            load_template(
+                &config.squash_template,
+                &config.squash_template_file,
+                ("squash-template", "squash-template-file"),
+                DEFAULT_SQUASH_TEMPLATE,
+            )?,
+            "Squash template",
+        ),
+        TemplateType::PullRequest => (
+            load_template(
+                &config.pr_template,
+                &config.pr_template_file,
+                ("pr-template", "pr-template-file"),
+                DEFAULT_PR_TEMPLATE,
+            )?,
+            "PR template",
+        ),
     };
 
     // Validate non-empty
@@ -232,6 +882,10 @@ fn build_prompt(
 
     let rendered = tmpl.render(minijinja::context! {
         git_diff => context.git_diff,
+        diff_stat => context.diff_stat,
+        truncated => context.truncated,
+        changed_files => context.changed_files,
+        suggested_scope => context.suggested_scope,
         branch => context.branch,
         recent_commits => context.recent_commits.unwrap_or(&vec![]),
         repo => context.repo_name,
@@ -242,21 +896,28 @@ fn build_prompt(
     Ok(rendered)
 }
 
+/// Append the user's guidance for a regenerated message to the prompt
+fn append_hint(mut prompt: String, hint: Option<&str>) -> String {
+    if let Some(hint) = hint {
+        prompt.push_str(&format!(
+            "\n\nThe user asked for a new message. Follow their guidance: {hint}\n"
+        ));
+    }
+    prompt
+}
+
+/// Generate a message for the staged changes
+///
+/// `hint` is guidance from the user when regenerating a reviewed message; it's
+/// appended to the prompt. The fallback message ignores it.
 pub fn generate_commit_message(
     commit_generation_config: &CommitGenerationConfig,
+    hint: Option<&str>,
 ) -> anyhow::Result<String> {
-    // Check if commit generation is configured (non-empty command)
-    if commit_generation_config.is_configured() {
-        let command = commit_generation_config.command.as_ref().unwrap();
-        let args = &commit_generation_config.args;
+    if let Some(provider) = Provider::from_config(commit_generation_config)? {
         // Commit generation is explicitly configured - fail if it doesn't work
-        return try_generate_commit_message(command, args, commit_generation_config).map_err(|e| {
-            worktrunk::git::GitError::LlmCommandFailed {
-                command: format_command_display(command, args),
-                error: e.to_string(),
-            }
-            .into()
-        });
+        return try_generate_commit_message(&provider, commit_generation_config, hint)
+            .map_err(|e| provider.failed(e));
     }
 
     // Fallback: generate a descriptive commit message based on changed files
@@ -289,14 +950,14 @@ pub fn generate_commit_message(
 }
 
 fn try_generate_commit_message(
-    command: &str,
-    args: &[String],
+    provider: &Provider,
     config: &CommitGenerationConfig,
+    hint: Option<&str>,
 ) -> anyhow::Result<String> {
     let repo = Repository::current();
 
-    // Get staged diff
-    let diff_output = repo.run_command(&["--no-pager", "diff", "--staged"])?;
+    // Get staged diff, fitted to the size budget
+    let diff = PromptDiff::collect(&repo, &["--staged"], config)?;
 
     // Get current branch
     let current_branch = repo.current_branch()?.unwrap_or_else(|| "HEAD".to_string());
@@ -309,31 +970,18 @@ fn try_generate_commit_message(
         .unwrap_or("repo");
 
     // Get recent commit messages for style reference
-    let recent_commits = repo
-        .run_command(&["log", "--pretty=format:%s", "-n", "5", "--no-merges"])
-        .ok()
-        .and_then(|output| {
-            if output.trim().is_empty() {
-                None
-            } else {
-                Some(output.lines().map(String::from).collect::<Vec<_>>())
-            }
-        });
+    let recent_commits = recent_subjects(&repo, None);
 
     // Build prompt from template
-    let context = TemplateContext {
-        git_diff: &diff_output,
-        branch: &current_branch,
-        recent_commits: recent_commits.as_ref(),
-        repo_name,
-        commits: &[],
-        target_branch: None,
-    };
+    let context = diff.context(&current_branch, recent_commits.as_ref(), repo_name);
     let prompt = build_prompt(config, TemplateType::Commit, &context)?;
 
-    execute_llm_command(command, args, &prompt)
+    execute_validated(provider, &append_hint(prompt, hint), config)
 }
 
+/// Generate a message for squashing `subjects` onto `merge_base`
+///
+/// `hint` is appended to the prompt, as for [`generate_commit_message`].
 pub fn generate_squash_message(
     target_branch: &str,
     merge_base: &str,
@@ -341,53 +989,30 @@ pub fn generate_squash_message(
     current_branch: &str,
     repo_name: &str,
     commit_generation_config: &CommitGenerationConfig,
+    hint: Option<&str>,
 ) -> anyhow::Result<String> {
-    // Check if commit generation is configured (non-empty command)
-    if commit_generation_config.is_configured() {
-        let command = commit_generation_config.command.as_ref().unwrap();
-        let args = &commit_generation_config.args;
-
+    if let Some(provider) = Provider::from_config(commit_generation_config)? {
         // Get the combined diff for all commits being squashed
         let repo = Repository::current();
-        let diff_output = repo.run_command(&["--no-pager", "diff", merge_base, "HEAD"])?;
+        let diff = PromptDiff::collect(&repo, &[merge_base, "HEAD"], commit_generation_config)?;
 
         // Get recent commit messages for style reference (from before the commits being squashed)
-        let recent_commits = repo
-            .run_command(&[
-                "log",
-                "--pretty=format:%s",
-                "-n",
-                "5",
-                "--no-merges",
-                merge_base,
-            ])
-            .ok()
-            .and_then(|output| {
-                if output.trim().is_empty() {
-                    None
-                } else {
-                    Some(output.lines().map(String::from).collect::<Vec<_>>())
-                }
-            });
+        let recent_commits = recent_subjects(&repo, Some(merge_base));
 
         // Build prompt from template with all variables
         let context = TemplateContext {
-            git_diff: &diff_output,
-            branch: current_branch,
-            recent_commits: recent_commits.as_ref(),
-            repo_name,
             commits: subjects,
             target_branch: Some(target_branch),
+            ..diff.context(current_branch, recent_commits.as_ref(), repo_name)
         };
         let prompt = build_prompt(commit_generation_config, TemplateType::Squash, &context)?;
 
-        return execute_llm_command(command, args, &prompt).map_err(|e| {
-            worktrunk::git::GitError::LlmCommandFailed {
-                command: format_command_display(command, args),
-                error: e.to_string(),
-            }
-            .into()
-        });
+        return execute_validated(
+            &provider,
+            &append_hint(prompt, hint),
+            commit_generation_config,
+        )
+        .map_err(|e| provider.failed(e));
     }
 
     // Fallback: deterministic commit message (only when not configured)
@@ -400,6 +1025,42 @@ pub fn generate_squash_message(
     Ok(commit_message)
 }
 
+/// Generate a pull request title and description for `subjects`, the commits
+/// since `merge_base`
+///
+/// Returns the title on the first line, then a blank line and the markdown
+/// body. Unlike commit messages, there's no fallback without an LLM.
+pub fn generate_pr_description(
+    target_branch: &str,
+    merge_base: &str,
+    subjects: &[String],
+    current_branch: &str,
+    repo_name: &str,
+    commit_generation_config: &CommitGenerationConfig,
+) -> anyhow::Result<String> {
+    let Some(provider) = Provider::from_config(commit_generation_config)? else {
+        anyhow::bail!(
+            "Commit generation is not configured. Add [commit-generation] to your config."
+        );
+    };
+
+    let repo = Repository::current();
+    let diff = PromptDiff::collect(&repo, &[merge_base, "HEAD"], commit_generation_config)?;
+    let recent_commits = recent_subjects(&repo, Some(merge_base));
+    let context = TemplateContext {
+        commits: subjects,
+        target_branch: Some(target_branch),
+        ..diff.context(current_branch, recent_commits.as_ref(), repo_name)
+    };
+    let prompt = build_prompt(
+        commit_generation_config,
+        TemplateType::PullRequest,
+        &context,
+    )?;
+
+    provider.generate(&prompt).map_err(|e| provider.failed(e))
+}
+
 /// Synthetic diff for testing commit generation
 const SYNTHETIC_DIFF: &str = r#"diff --git a/src/main.rs b/src/main.rs
 index abc1234..def5678 100644
@@ -421,14 +1082,11 @@ index abc1234..def5678 100644
 pub fn test_commit_generation(
     commit_generation_config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    if !commit_generation_config.is_configured() {
+    let Some(provider) = Provider::from_config(commit_generation_config)? else {
         anyhow::bail!(
             "Commit generation is not configured. Add [commit-generation] to your config."
         );
-    }
-
-    let command = commit_generation_config.command.as_ref().unwrap();
-    let args = &commit_generation_config.args;
+    };
 
     // Build prompt with synthetic data
     let recent_commits = vec![
@@ -438,6 +1096,10 @@ pub fn test_commit_generation(
     ];
     let context = TemplateContext {
         git_diff: SYNTHETIC_DIFF,
+        diff_stat: " src/main.rs | 4 ++++\n 1 file changed, 4 insertions(+)\n",
+        truncated: false,
+        changed_files: &["src/main.rs".to_string()],
+        suggested_scope: None,
         branch: "feature/example",
         recent_commits: Some(&recent_commits),
         repo_name: "test-repo",
@@ -446,13 +1108,7 @@ pub fn test_commit_generation(
     };
     let prompt = build_prompt(commit_generation_config, TemplateType::Commit, &context)?;
 
-    execute_llm_command(command, args, &prompt).map_err(|e| {
-        worktrunk::git::GitError::LlmCommandFailed {
-            command: format_command_display(command, args),
-            error: e.to_string(),
-        }
-        .into()
-    })
+    provider.generate(&prompt).map_err(|e| provider.failed(e))
 }
 
 #[cfg(test)]
@@ -468,6 +1124,10 @@ mod tests {
     ) -> TemplateContext<'a> {
         TemplateContext {
             git_diff,
+            diff_stat: "",
+            truncated: false,
+            changed_files: &[],
+            suggested_scope: None,
             branch,
             recent_commits,
             repo_name,
@@ -487,6 +1147,10 @@ mod tests {
     ) -> TemplateContext<'a> {
         TemplateContext {
             git_diff,
+            diff_stat: "",
+            truncated: false,
+            changed_files: &[],
+            suggested_scope: None,
             branch,
             recent_commits,
             repo_name,
@@ -495,6 +1159,264 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_append_hint() {
+        assert_eq!(append_hint("Prompt".into(), None), "Prompt");
+        let prompt = append_hint("Prompt".into(), Some("mention the parser"));
+        assert!(prompt.starts_with("Prompt\n\n"));
+        assert!(prompt.ends_with("Follow their guidance: mention the parser\n"));
+    }
+
+    #[test]
+    fn test_provider_from_config() {
+        let config = CommitGenerationConfig {
+            command: Some("llm".to_string()),
+            args: vec!["-m".to_string(), "haiku".to_string()],
+            ..Default::default()
+        };
+        let provider = Provider::from_config(&config).unwrap().unwrap();
+        assert_eq!(provider.describe(), "llm -m haiku");
+
+        let config = CommitGenerationConfig {
+            provider: Some(LlmProvider::OpenaiCompatible),
+            endpoint: Some("http://localhost:8080/v1".to_string()),
+            model: Some("qwen2.5-coder".to_string()),
+            ..Default::default()
+        };
+        let provider = Provider::from_config(&config).unwrap().unwrap();
+        assert_eq!(
+            provider.describe(),
+            "qwen2.5-coder (http://localhost:8080/v1/chat/completions)"
+        );
+
+        let config = CommitGenerationConfig {
+            provider: Some(LlmProvider::Anthropic),
+            model: Some("claude-haiku-4-5".to_string()),
+            ..Default::default()
+        };
+        let provider = Provider::from_config(&config).unwrap().unwrap();
+        assert_eq!(provider.url(), "https://api.anthropic.com/v1/messages");
+
+        let config = CommitGenerationConfig {
+            provider: Some(LlmProvider::Anthropic),
+            ..Default::default()
+        };
+        assert!(Provider::from_config(&config).is_err());
+        assert!(
+            Provider::from_config(&CommitGenerationConfig::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_api_key() {
+        let api = |api_key_env, require_key| HttpApi {
+            endpoint: "http://localhost",
+            model: "model",
+            api_key_env,
+            require_key,
+        };
+        // An unset default key is left out, for local servers
+        assert_eq!(
+            api(Some("WT_TEST_UNSET_API_KEY"), false).api_key().unwrap(),
+            None
+        );
+        // A configured one must be set
+        let error = api(Some("WT_TEST_UNSET_API_KEY"), true)
+            .api_key()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "WT_TEST_UNSET_API_KEY is not set");
+        // PATH is always set
+        assert!(api(Some("PATH"), true).api_key().unwrap().is_some());
+        // No variable, no key
+        assert_eq!(api(None, false).api_key().unwrap(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "llm-http")]
+    fn test_api_error_message() {
+        assert_eq!(
+            api_error_message(
+                r#"{"type":"error","error":{"type":"authentication_error","message":"invalid x-api-key"}}"#
+            ),
+            "invalid x-api-key"
+        );
+        assert_eq!(
+            api_error_message(r#"{"error":"model 'llama3' not found"}"#),
+            "model 'llama3' not found"
+        );
+        assert_eq!(api_error_message("Bad Gateway\n"), "Bad Gateway");
+    }
+
+    fn file_diff(path: &str, lines: usize) -> String {
+        let mut diff = format!("diff --git a/{path} b/{path}\n@@ -0,0 +1,{lines} @@\n");
+        for i in 0..lines {
+            diff.push_str(&format!("+line {i}\n"));
+        }
+        diff
+    }
+
+    #[test]
+    fn test_suggest_scope() {
+        let scope = |paths: &[&str]| {
+            let paths: Vec<String> = paths.iter().map(|p| p.to_string()).collect();
+            suggest_scope(&paths)
+        };
+        assert_eq!(
+            scope(&["src/commands/list/model.rs", "src/commands/list/render.rs"]),
+            Some("list".into())
+        );
+        assert_eq!(
+            scope(&["src/commands/list/model.rs", "src/commands/merge.rs"]),
+            Some("commands".into())
+        );
+        // Generic directories are skipped
+        assert_eq!(
+            scope(&["crates/parser/src/lib.rs", "crates/parser/src/lexer.rs"]),
+            Some("parser".into())
+        );
+        assert_eq!(scope(&["src/main.rs", "src/llm.rs"]), None);
+        assert_eq!(scope(&["README.md", "docs/index.md"]), None);
+        assert_eq!(scope(&[]), None);
+    }
+
+    #[test]
+    fn test_check_conventional() {
+        let check = |message: &str| check_message(&MessageValidation::Conventional, message);
+        assert_eq!(check("feat: Add parser"), Ok(()));
+        assert_eq!(check("fix(list)!: Drop the stale column"), Ok(()));
+        assert_eq!(check("docs(readme): Fix typo\n\nBody text"), Ok(()));
+
+        assert_eq!(
+            check("Add parser"),
+            Err("the first line must look like `type(scope): subject`".into())
+        );
+        assert!(
+            check("feature: Add parser")
+                .unwrap_err()
+                .contains("isn't a commit type")
+        );
+        assert_eq!(
+            check("feat(my scope): Add parser"),
+            Err("the scope `my scope` must be a single word".into())
+        );
+        assert_eq!(
+            check("feat(list: Add parser"),
+            Err("the scope must be closed with `)`".into())
+        );
+        assert_eq!(
+            check("feat: Add parser."),
+            Err("the subject must not end with a period".into())
+        );
+        assert!(
+            check(&format!("feat: {}", "a".repeat(80)))
+                .unwrap_err()
+                .contains("86 characters")
+        );
+        assert_eq!(
+            check("feat: Add parser\nBody without a blank line"),
+            Err("the first line must be followed by a blank line".into())
+        );
+    }
+
+    #[test]
+    fn test_check_pattern() {
+        let config = CommitGenerationConfig {
+            validate: Some(r"[A-Z]+-\d+: .+".into()),
+            ..Default::default()
+        };
+        let validation = config.validation().unwrap().unwrap();
+        assert_eq!(check_message(&validation, "ABC-12: Fix login"), Ok(()));
+        assert_eq!(
+            check_message(&validation, "Fix login"),
+            Err(r"it doesn't match `[A-Z]+-\d+: .+`".into())
+        );
+        // Matching part of the message isn't enough
+        assert!(check_message(&validation, "Fix ABC-12: login").is_err());
+        // Only the subject is checked, so a body doesn't break `.+`
+        assert_eq!(
+            check_message(
+                &validation,
+                "ABC-12: Fix login\n\nThe session cookie expired early.\nNow it doesn't."
+            ),
+            Ok(())
+        );
+        assert!(check_message(&validation, "Fix login\n\nABC-12: in the body").is_err());
+    }
+
+    #[test]
+    fn test_split_diff_files() {
+        let diff = format!("{}{}", file_diff("a.rs", 2), file_diff("b.rs", 1));
+        let files = split_diff_files(&diff);
+        assert_eq!(files, [file_diff("a.rs", 2), file_diff("b.rs", 1)]);
+        // "diff --git" inside a hunk doesn't start a file
+        let diff = "diff --git a/x b/x\n+ diff --git in a line\n";
+        assert_eq!(split_diff_files(diff), [diff]);
+        assert!(split_diff_files("").is_empty());
+    }
+
+    #[test]
+    fn test_truncate_diff_files() {
+        let small = file_diff("small.rs", 2);
+        let large = file_diff("large.rs", 1000);
+        let output = truncate_diff_files(&[&large, &small], 1000);
+
+        // The small file stays whole; the large one gets the rest of the budget
+        assert!(output.ends_with(&small));
+        let large_part = output.strip_suffix(&small).unwrap();
+        assert!(large_part.starts_with("diff --git a/large.rs b/large.rs\n@@"));
+        assert!(large_part.len() <= 1000 - small.len() + "[... 1000 more lines]\n".len());
+        let kept = large_part.lines().filter(|l| l.starts_with('+')).count();
+        assert!(large_part.ends_with(&format!("[... {} more lines]\n", 1000 - kept)));
+
+        // Everything fits
+        assert_eq!(truncate_diff_files(&[&small], 1000), small);
+
+        // No room at all still names the file
+        let output = truncate_diff_files(&[&large], 0);
+        assert_eq!(
+            output,
+            "diff --git a/large.rs b/large.rs\n[... 1001 more lines]\n"
+        );
+    }
+
+    #[test]
+    fn test_build_prompt_truncated_diff() {
+        let config = CommitGenerationConfig::default();
+        let changed_files = vec!["Cargo.lock".to_string(), "src/lib.rs".to_string()];
+        let context = TemplateContext {
+            diff_stat: " Cargo.lock | 9000 +++\n src/lib.rs | 2 +-\n",
+            truncated: true,
+            changed_files: &changed_files,
+            ..commit_context("diff content", "main", None, "myrepo")
+        };
+        let prompt = build_prompt(&config, TemplateType::Commit, &context).unwrap();
+        assert!(prompt.contains("<diff_stat>\n Cargo.lock | 9000 +++"));
+        assert!(prompt.contains("The diff below is truncated"));
+
+        // Untruncated prompts don't mention it
+        let context = commit_context("diff content", "main", None, "myrepo");
+        let prompt = build_prompt(&config, TemplateType::Commit, &context).unwrap();
+        assert!(!prompt.contains("diff_stat"));
+        assert!(prompt.contains("</style>\n\n<diff>"));
+
+        // Custom templates can use every variable
+        let config = CommitGenerationConfig {
+            template: Some(
+                "{% if truncated %}{{ changed_files | join(', ') }}{% endif %}".to_string(),
+            ),
+            ..Default::default()
+        };
+        let context = TemplateContext {
+            truncated: true,
+            changed_files: &changed_files,
+            ..commit_context("diff content", "main", None, "myrepo")
+        };
+        let prompt = build_prompt(&config, TemplateType::Commit, &context).unwrap();
+        assert_eq!(prompt, "Cargo.lock, src/lib.rs");
+    }
+
     #[test]
     fn test_build_commit_prompt_with_default_template() {
         let config = CommitGenerationConfig::default();
@@ -545,6 +1467,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("my diff", "feature", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -561,6 +1484,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -576,6 +1500,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -600,6 +1525,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let commits = vec!["commit1".to_string(), "commit2".to_string()];
         let context = commit_context("my diff", "feature", Some(&commits), "myrepo");
@@ -628,6 +1554,30 @@ mod tests {
         assert!(prompt.contains("diff content"));
     }
 
+    #[test]
+    fn test_build_pr_prompt() {
+        let commits = vec!["Read input".to_string(), "Add parser".to_string()];
+        let context = squash_context("diff content", "feature", None, "repo", &commits, "main");
+
+        let prompt = build_prompt(
+            &CommitGenerationConfig::default(),
+            TemplateType::PullRequest,
+            &context,
+        )
+        .unwrap();
+        assert!(prompt.contains("- Add parser\n- Read input\n"));
+        assert!(prompt.contains(r#"target="main""#));
+        assert!(prompt.contains("## Risks"));
+        assert!(prompt.contains("diff content"));
+
+        let config = CommitGenerationConfig {
+            pr_template: Some("PR for {{ branch }} into {{ target_branch }}".to_string()),
+            ..Default::default()
+        };
+        let prompt = build_prompt(&config, TemplateType::PullRequest, &context).unwrap();
+        assert_eq!(prompt, "PR for feature into main");
+    }
+
     #[test]
     fn test_build_squash_prompt_with_custom_template() {
         let config = CommitGenerationConfig {
@@ -640,6 +1590,7 @@ mod tests {
                     .to_string(),
             ),
             squash_template_file: None,
+            ..Default::default()
         };
         let commits = vec!["A".to_string(), "B".to_string()];
         let context = squash_context("diff", "feature", None, "repo", &commits, "main");
@@ -667,6 +1618,7 @@ mod tests {
             template_file: None,
             squash_template: Some("{% for x in commits %}{{ x }".to_string()),
             squash_template_file: None,
+            ..Default::default()
         };
         let commits: Vec<String> = vec![];
         let context = squash_context("diff", "feature", None, "repo", &commits, "main");
@@ -683,6 +1635,7 @@ mod tests {
             template_file: None,
             squash_template: Some("  \n  ".to_string()),
             squash_template_file: None,
+            ..Default::default()
         };
         let commits: Vec<String> = vec![];
         let context = squash_context("diff", "feature", None, "repo", &commits, "main");
@@ -709,6 +1662,7 @@ mod tests {
                     .to_string(),
             ),
             squash_template_file: None,
+            ..Default::default()
         };
         let commits = vec!["A".to_string(), "B".to_string()];
         let recent = vec!["prev1".to_string(), "prev2".to_string()];
@@ -754,6 +1708,7 @@ Diff follows:
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let commits = vec![
             "feat: add auth".to_string(),
@@ -801,6 +1756,7 @@ No recent commits
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("diff", "main", None, "test");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -833,6 +1789,7 @@ Single commit: {{ commits[0] }}
                     .to_string(),
             ),
             squash_template_file: None,
+            ..Default::default()
         };
 
         // Test with multiple commits
@@ -882,6 +1839,7 @@ Single commit: {{ commits[0] }}
             template_file: Some(template_path.to_string_lossy().to_string()),
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("my diff", "feature", None, "myrepo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -904,6 +1862,7 @@ Single commit: {{ commits[0] }}
             template_file: Some("/nonexistent/path/template.txt".to_string()),
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -928,6 +1887,7 @@ Single commit: {{ commits[0] }}
             template_file: None,
             squash_template: None,
             squash_template_file: Some(template_path.to_string_lossy().to_string()),
+            ..Default::default()
         };
         let commits = vec!["A".to_string(), "B".to_string()];
         let context = squash_context("diff", "feature", None, "repo", &commits, "main");
@@ -951,6 +1911,7 @@ Single commit: {{ commits[0] }}
             template_file: Some("~/nonexistent_template_for_test.txt".to_string()),
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -976,6 +1937,7 @@ Single commit: {{ commits[0] }}
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            ..Default::default()
         };
         let context = commit_context("diff", "feature", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -6,9 +6,7 @@ use std::process;
 use worktrunk::config::{WorktrunkConfig, set_config_path};
 use worktrunk::git::{Repository, exit_code, set_base_path};
 use worktrunk::path::format_path_for_display;
-use worktrunk::styling::{
-    format_with_gutter, hint_message, info_message, println, success_message, warning_message,
-};
+use worktrunk::styling::{hint_message, info_message, println, success_message, warning_message};
 
 mod cli;
 mod commands;
@@ -24,14 +22,15 @@ pub use crate::cli::OutputFormat;
 use commands::command_executor::CommandContext;
 #[cfg(unix)]
 use commands::handle_select;
-use commands::worktree::{SwitchResult, handle_push};
+use commands::jobs::StopScope;
+use commands::worktree::{SwitchEnds, SwitchResult, execute_switch_commands, handle_push};
 use commands::{
     ConfigAction, RebaseResult, SquashResult, add_approvals, approve_hooks, clear_approvals,
     compute_worktree_path, handle_cache_clear, handle_cache_refresh, handle_cache_show,
-    handle_config_create, handle_config_show, handle_configure_shell, handle_hook_show,
-    handle_init, handle_list, handle_merge, handle_rebase, handle_remove, handle_remove_by_path,
-    handle_remove_current, handle_show_theme, handle_squash, handle_switch,
-    handle_unconfigure_shell, handle_var_clear, handle_var_get, handle_var_set,
+    handle_config_create, handle_config_show, handle_configure_shell, handle_hook_logs,
+    handle_hook_show, handle_hook_stop, handle_init, handle_list, handle_merge, handle_rebase,
+    handle_remove, handle_remove_by_path, handle_remove_current, handle_show_theme, handle_squash,
+    handle_switch, handle_unconfigure_shell, handle_var_clear, handle_var_get, handle_var_set,
     resolve_worktree_path_first, run_hook, step_commit,
 };
 use output::{execute_user_command, handle_remove_output, handle_switch_output};
@@ -896,25 +895,29 @@ fn main() {
                 force,
                 verify,
                 stage,
+                edit,
             } => WorktrunkConfig::load()
                 .context("Failed to load config")
                 .and_then(|config| {
                     let stage_final = stage
-                        .or_else(|| config.commit.and_then(|c| c.stage))
+                        .or_else(|| config.commit.as_ref().and_then(|c| c.stage))
                         .unwrap_or_default();
-                    step_commit(force, !verify, stage_final)
+                    let review = edit || config.commit.and_then(|c| c.review).unwrap_or(false);
+                    step_commit(force, !verify, stage_final, review)
                 }),
             StepCommand::Squash {
                 target,
                 force,
                 verify,
                 stage,
+                edit,
             } => WorktrunkConfig::load()
                 .context("Failed to load config")
                 .and_then(|config| {
                     let stage_final = stage
-                        .or_else(|| config.commit.and_then(|c| c.stage))
+                        .or_else(|| config.commit.as_ref().and_then(|c| c.stage))
                         .unwrap_or_default();
+                    let review = edit || config.commit.and_then(|c| c.review).unwrap_or(false);
 
                     // "Approve at the Gate": approve pre-commit hooks upfront (unless --no-verify)
                     // Shadow verify: if user declines approval, skip hooks but continue squash
@@ -934,7 +937,7 @@ fn main() {
                         false
                     };
 
-                    match handle_squash(target.as_deref(), force, !verify, stage_final)? {
+                    match handle_squash(target.as_deref(), force, !verify, stage_final, review)? {
                         SquashResult::Squashed | SquashResult::NoNetChanges => {}
                         SquashResult::NoCommitsAhead(branch) => {
                             crate::output::print(info_message(format!(
@@ -952,7 +955,25 @@ fn main() {
             StepCommand::Push {
                 target,
                 allow_merge_commits,
-            } => handle_push(target.as_deref(), allow_merge_commits, "Pushed to", None),
+                force,
+                verify,
+            } => WorktrunkConfig::load()
+                .context("Failed to load config")
+                .and_then(|config| {
+                    handle_push(
+                        target.as_deref(),
+                        allow_merge_commits,
+                        "Pushed to",
+                        None,
+                        verify,
+                        force,
+                        &config,
+                    )
+                }),
+            StepCommand::Sparse { action } => commands::sparse::handle_step_sparse(action),
+            StepCommand::Describe { target, output, pr } => {
+                commands::describe::handle_step_describe(target.as_deref(), output.as_deref(), pr)
+            }
             StepCommand::Rebase { target } => {
                 handle_rebase(target.as_deref()).and_then(|result| match result {
                     RebaseResult::Rebased => Ok(()),
@@ -970,24 +991,45 @@ fn main() {
                 hook_type,
                 expanded,
             } => handle_hook_show(hook_type.as_deref(), expanded),
+            HookCommand::Logs {
+                name,
+                branch,
+                follow,
+            } => handle_hook_logs(name.as_deref(), branch.as_deref(), follow),
+            HookCommand::Stop { name, branch } => handle_hook_stop(&name, branch.as_deref()),
+            HookCommand::PreCreate { name, force } => {
+                run_hook(HookType::PreCreate, force, name.as_deref())
+            }
             HookCommand::PostCreate { name, force } => {
                 run_hook(HookType::PostCreate, force, name.as_deref())
             }
             HookCommand::PostStart { name, force } => {
                 run_hook(HookType::PostStart, force, name.as_deref())
             }
+            HookCommand::PreSwitch { name, force } => {
+                run_hook(HookType::PreSwitch, force, name.as_deref())
+            }
+            HookCommand::PostSwitch { name, force } => {
+                run_hook(HookType::PostSwitch, force, name.as_deref())
+            }
             HookCommand::PreCommit { name, force } => {
                 run_hook(HookType::PreCommit, force, name.as_deref())
             }
             HookCommand::PreMerge { name, force } => {
                 run_hook(HookType::PreMerge, force, name.as_deref())
             }
+            HookCommand::PrePush { name, force } => {
+                run_hook(HookType::PrePush, force, name.as_deref())
+            }
             HookCommand::PostMerge { name, force } => {
                 run_hook(HookType::PostMerge, force, name.as_deref())
             }
             HookCommand::PreRemove { name, force } => {
                 run_hook(HookType::PreRemove, force, name.as_deref())
             }
+            HookCommand::PostRemove { name, force } => {
+                run_hook(HookType::PostRemove, force, name.as_deref())
+            }
             HookCommand::Approvals { action } => match action {
                 ApprovalsCommand::Add { all } => add_approvals(all),
                 ApprovalsCommand::Clear { global } => clear_approvals(global),
@@ -1004,8 +1046,15 @@ fn main() {
             progressive,
             no_progressive,
         } => match subcommand {
-            Some(ListSubcommand::Statusline { claude_code }) => {
-                commands::statusline::run(claude_code)
+            Some(ListSubcommand::Statusline {
+                claude_code,
+                context,
+                cached,
+                refresh,
+                format,
+            }) => {
+                let context = context.or_else(|| claude_code.then(|| "claude-code".to_string()));
+                commands::statusline::run(context.as_deref(), cached, refresh, format)
             }
             None => {
                 use commands::list::progressive::RenderMode;
@@ -1054,16 +1103,25 @@ fn main() {
             branch,
             create,
             base,
+            fetch,
             execute,
             force,
             verify,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
-                // "Approve at the Gate": collect and approve hooks upfront when creating
+                // `pr:<n>` / `mr:<n>` check out an existing head branch
+                if create && commands::pr_ref::is_review_ref(&branch) {
+                    anyhow::bail!(
+                        "--create can't be used with {branch}; it switches to the PR's branch"
+                    );
+                }
+                let branch = commands::pr_ref::resolve_review_ref(&Repository::current(), &branch)?;
+
+                // "Approve at the Gate": collect and approve hooks upfront
                 // This ensures approval happens once at the command entry point
-                // If user declines, skip hooks but continue with worktree creation
-                let approved = if create && verify {
+                // If user declines, skip hooks but continue with the switch
+                let approved = if verify {
                     let repo = Repository::current();
                     let repo_root = repo.worktree_base()?;
                     // Compute worktree path for template expansion in approval prompt
@@ -1076,7 +1134,18 @@ fn main() {
                         &repo_root,
                         force,
                     );
-                    approve_hooks(&ctx, &[HookType::PostCreate, HookType::PostStart])?
+                    let hook_types: &[HookType] = if create {
+                        &[
+                            HookType::PreSwitch,
+                            HookType::PreCreate,
+                            HookType::PostCreate,
+                            HookType::PostStart,
+                            HookType::PostSwitch,
+                        ]
+                    } else {
+                        &[HookType::PreSwitch, HookType::PostSwitch]
+                    };
+                    approve_hooks(&ctx, hook_types)?
                 } else {
                     true // No hooks to approve = considered approved
                 };
@@ -1086,14 +1155,23 @@ fn main() {
 
                 // Show message if user declined approval
                 if !approved {
-                    crate::output::print(info_message(
-                        "Commands declined, continuing worktree creation",
-                    ))?;
+                    crate::output::print(info_message(if create {
+                        "Commands declined, continuing worktree creation"
+                    } else {
+                        "Commands declined, continuing switch"
+                    }))?;
                 }
 
                 // Execute switch operation (creates worktree, runs post-create hooks if approved)
-                let (result, resolved_branch) =
-                    handle_switch(&branch, create, base.as_deref(), force, skip_hooks, &config)?;
+                let (result, resolved_branch) = handle_switch(
+                    &branch,
+                    create,
+                    base.as_deref(),
+                    fetch,
+                    force,
+                    skip_hooks,
+                    &config,
+                )?;
 
                 // Show success message (temporal locality: immediately after worktree creation)
                 // Pass cli.internal to indicate whether shell integration is active
@@ -1121,6 +1199,16 @@ fn main() {
                     ctx.spawn_post_start_commands(true)?;
                 }
 
+                // post-switch hooks run on every switch to another worktree
+                if !skip_hooks && !matches!(result, SwitchResult::AlreadyAt(_)) {
+                    let ends = SwitchEnds::from_current(
+                        &Repository::current(),
+                        &resolved_branch,
+                        result.path(),
+                    );
+                    execute_switch_commands(HookType::PostSwitch, &config, &ends, force)?;
+                }
+
                 // Execute user command after post-start hooks have been spawned
                 if let Some(cmd) = execute {
                     execute_user_command(&cmd)?;
@@ -1134,10 +1222,18 @@ fn main() {
             force_delete,
             background,
             verify,
+            stop_jobs,
+            stop_processes,
             force,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
+                let stop = match (stop_jobs, stop_processes) {
+                    (_, true) => StopScope::All,
+                    (true, false) => StopScope::Jobs,
+                    (false, false) => StopScope::None,
+                };
+
                 // Validate conflicting flags
                 if !delete_branch && force_delete {
                     return Err(worktrunk::git::GitError::Other {
@@ -1146,7 +1242,7 @@ fn main() {
                     .into());
                 }
 
-                // "Approve at the Gate": collect and approve pre-remove hooks upfront
+                // "Approve at the Gate": collect and approve pre-remove and post-remove hooks upfront
                 // This ensures approval happens once at the command entry point
                 //
                 // TODO(pre-remove-context): The approval context uses current worktree (cwd + current_branch),
@@ -1167,7 +1263,8 @@ fn main() {
                         &repo_root,
                         force,
                     );
-                    let approved = approve_hooks(&ctx, &[HookType::PreRemove])?;
+                    let approved =
+                        approve_hooks(&ctx, &[HookType::PreRemove, HookType::PostRemove])?;
                     // If declined, skip hooks but continue with removal
                     if !approved {
                         crate::output::print(info_message(
@@ -1184,7 +1281,7 @@ fn main() {
                     // Uses path-based removal to handle detached HEAD state
                     let result = handle_remove_current(!delete_branch, force_delete, background)?;
                     // Approval was handled at the gate
-                    handle_remove_output(&result, None, background, verify)
+                    handle_remove_output(&result, None, background, verify, stop, &config)
                 } else {
                     use worktrunk::git::ResolvedWorktree;
                     // When removing multiple worktrees, we need to handle the current worktree last
@@ -1221,12 +1318,19 @@ fn main() {
                                 force_delete,
                                 background,
                             )?;
-                            handle_remove_output(&result, Some(branch_name), background, verify)?;
+                            handle_remove_output(
+                                &result,
+                                Some(branch_name),
+                                background,
+                                verify,
+                                stop,
+                                &config,
+                            )?;
                         } else {
                             // Non-current worktree is detached - remove by path (no branch to delete)
                             let result =
                                 handle_remove_by_path(path, None, force_delete, background)?;
-                            handle_remove_output(&result, None, background, verify)?;
+                            handle_remove_output(&result, None, background, verify, stop, &config)?;
                         }
                     }
 
@@ -1234,14 +1338,28 @@ fn main() {
                     for branch in &branch_only {
                         let result =
                             handle_remove(branch, !delete_branch, force_delete, background)?;
-                        handle_remove_output(&result, Some(branch), background, verify)?;
+                        handle_remove_output(
+                            &result,
+                            Some(branch),
+                            background,
+                            verify,
+                            stop,
+                            &config,
+                        )?;
                     }
 
                     // Remove current worktree last (if it was in the list)
                     if let Some((_path, branch)) = current {
                         let result =
                             handle_remove_current(!delete_branch, force_delete, background)?;
-                        handle_remove_output(&result, branch.as_deref(), background, verify)?;
+                        handle_remove_output(
+                            &result,
+                            branch.as_deref(),
+                            background,
+                            verify,
+                            stop,
+                            &config,
+                        )?;
                     }
 
                     Ok(())
@@ -1307,22 +1425,7 @@ fn main() {
     let _ = output::terminate_output();
 
     if let Err(e) = result {
-        use worktrunk::styling::ERROR_EMOJI;
-
-        // GitError and WorktrunkError produce styled output via Display
-        if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
-            let _ = output::print(err.to_string());
-        } else if let Some(err) = e.downcast_ref::<worktrunk::git::WorktrunkError>() {
-            let _ = output::print(err.to_string());
-        } else {
-            // Anyhow error - format with emoji, multi-line root cause gets gutter
-            let msg = e.to_string();
-            let root_cause = e.root_cause().to_string();
-            let _ = output::print(cformat!("{ERROR_EMOJI} <red>{msg}</>"));
-            if msg != root_cause && root_cause.contains('\n') {
-                let _ = output::gutter(format_with_gutter(&root_cause, "", None));
-            }
-        }
+        let _ = output::print_error(&e);
 
         // Preserve exit code from child processes (especially for signals like SIGINT)
         let code = exit_code(&e).unwrap_or(1);
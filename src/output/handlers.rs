@@ -2,10 +2,15 @@
 
 use color_print::cformat;
 use std::path::Path;
+use std::time::Duration;
 
 use crate::commands::command_executor::CommandContext;
 use crate::commands::execute_pre_remove_commands;
-use crate::commands::process::spawn_detached;
+use crate::commands::jobs::{
+    STOP_GRACE_PERIOD, StopScope, StoppedProcesses, stop_worktree_processes,
+};
+use crate::commands::merge::{execute_post_remove_commands, has_post_remove_commands};
+use crate::commands::process::{clear_done_markers, spawn_detached, spawn_detached_after};
 use crate::commands::worktree::{RemoveResult, SwitchResult};
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::GitError;
@@ -14,8 +19,8 @@ use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::Shell;
 use worktrunk::styling::{
-    HINT_EMOJI, error_message, format_with_gutter, info_message, progress_message, success_message,
-    warning_message,
+    HINT_EMOJI, error_message, format_with_gutter, hint_message, info_message, progress_message,
+    success_message, warning_message,
 };
 
 /// Format a switch success message with a consistent location phrase
@@ -31,18 +36,21 @@ fn format_switch_success_message(
     from_remote: Option<&str>,
 ) -> String {
     // Determine action and source based on how the worktree was created
-    // Priority: explicit --create > DWIM from remote > existing local branch
+    // Priority: explicit --create > tracking a remote branch > existing local branch
     let (action, source) = if created_branch {
-        ("Created new worktree for", base_branch)
+        (
+            "Created new worktree for",
+            base_branch.map(|base| ("from", base)),
+        )
     } else if let Some(remote) = from_remote {
-        ("Created worktree for", Some(remote))
+        ("Created worktree for", Some(("tracking", remote)))
     } else {
         ("Switched to worktree for", None)
     };
 
     match source {
-        Some(src) => cformat!(
-            "<green>{action} <bold>{branch}</> from <bold>{src}</> at <bold>{}</></>",
+        Some((relation, src)) => cformat!(
+            "<green>{action} <bold>{branch}</> {relation} <bold>{src}</> at <bold>{}</></>",
             format_path_for_display(path)
         ),
         None => cformat!(
@@ -255,6 +263,26 @@ fn shell_integration_hint() -> String {
     cformat!("Run <bright-black>wt config shell install</> to enable automatic cd")
 }
 
+/// Print a command error
+///
+/// GitError and WorktrunkError produce styled output via Display; other errors get
+/// the error emoji, with a multi-line root cause in a gutter.
+pub fn print_error(e: &anyhow::Error) -> std::io::Result<()> {
+    if let Some(err) = e.downcast_ref::<GitError>() {
+        super::print(err.to_string())
+    } else if let Some(err) = e.downcast_ref::<worktrunk::git::WorktrunkError>() {
+        super::print(err.to_string())
+    } else {
+        let msg = e.to_string();
+        let root_cause = e.root_cause().to_string();
+        super::print(error_message(&msg))?;
+        if msg != root_cause && root_cause.contains('\n') {
+            super::gutter(format_with_gutter(&root_cause, "", None))?;
+        }
+        Ok(())
+    }
+}
+
 /// Handle output for a switch operation
 ///
 /// `is_directive_mode` indicates whether shell integration is active (via --internal flag).
@@ -378,6 +406,8 @@ pub fn handle_remove_output(
     branch: Option<&str>,
     background: bool,
     verify: bool,
+    stop: StopScope,
+    config: &WorktrunkConfig,
 ) -> anyhow::Result<()> {
     match result {
         RemoveResult::RemovedWorktree {
@@ -399,6 +429,8 @@ pub fn handle_remove_output(
             branch,
             background,
             verify,
+            stop,
+            config,
         ),
         RemoveResult::BranchOnly {
             branch_name,
@@ -452,6 +484,41 @@ fn handle_branch_only_output(
     Ok(())
 }
 
+/// Report processes stopped before removing a worktree
+fn print_stopped_processes(processes: &StoppedProcesses) -> anyhow::Result<()> {
+    let bold_list = |names: &[String]| {
+        names
+            .iter()
+            .map(|name| cformat!("<bold>{name}</>"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    if !processes.stopped.is_empty() {
+        super::print(info_message(format!(
+            "Stopped processes running in worktree: {}",
+            bold_list(&processes.stopped)
+        )))?;
+    }
+    if !processes.killed.is_empty() {
+        super::print(warning_message(format!(
+            "Killed {}: still running {}s after SIGTERM",
+            bold_list(&processes.killed),
+            STOP_GRACE_PERIOD.as_secs()
+        )))?;
+    }
+    if !processes.untouched.is_empty() {
+        super::print(warning_message(format!(
+            "Processes still running in worktree: {}",
+            bold_list(&processes.untouched)
+        )))?;
+        super::print(hint_message(cformat!(
+            "Use <bright-black>--stop-processes</> to stop them as well"
+        )))?;
+    }
+    Ok(())
+}
+
 /// Handle output for RemovedWorktree removal
 #[allow(clippy::too_many_arguments)]
 fn handle_removed_worktree_output(
@@ -465,6 +532,8 @@ fn handle_removed_worktree_output(
     branch: Option<&str>,
     background: bool,
     verify: bool,
+    stop: StopScope,
+    config: &WorktrunkConfig,
 ) -> anyhow::Result<()> {
     // 1. Emit cd directive if needed - shell will execute this immediately
     if changed_directory {
@@ -477,12 +546,12 @@ fn handle_removed_worktree_output(
     // Execute pre-remove hooks in the worktree being removed
     // Non-zero exit aborts removal (FailFast strategy)
     // For detached HEAD, branch expands to empty string in templates
-    if verify && let Ok(config) = WorktrunkConfig::load() {
+    if verify {
         let target_repo = Repository::at(worktree_path);
         let hook_branch = branch_name.unwrap_or("");
         let ctx = CommandContext::new(
             &target_repo,
-            &config,
+            config,
             hook_branch,
             worktree_path,
             main_path,
@@ -491,6 +560,14 @@ fn handle_removed_worktree_output(
         execute_pre_remove_commands(&ctx, None)?;
     }
 
+    // Dev servers and other processes would otherwise outlive the worktree's directory
+    print_stopped_processes(&stop_worktree_processes(
+        &repo,
+        worktree_path,
+        branch_name,
+        stop,
+    ))?;
+
     // Handle detached HEAD case (no branch known)
     let Some(branch_name) = branch_name else {
         // No branch associated - just remove the worktree
@@ -499,13 +576,13 @@ fn handle_removed_worktree_output(
                 "Removing worktree in background (detached HEAD, no branch to delete)",
             ))?;
             let remove_command = build_remove_command(worktree_path, None);
-            spawn_detached(
-                &repo,
+            spawn_background_removal(
                 main_path,
+                worktree_path,
                 &remove_command,
-                "detached",
-                "remove",
                 None,
+                verify,
+                config,
             )?;
         } else {
             let target_repo = worktrunk::git::Repository::at(worktree_path);
@@ -521,6 +598,7 @@ fn handle_removed_worktree_output(
             super::print(success_message(
                 "Removed worktree (detached HEAD, no branch to delete)",
             ))?;
+            run_post_remove_commands(main_path, worktree_path, None, verify, config)?;
         }
         super::flush()?;
         return Ok(());
@@ -589,13 +667,13 @@ fn handle_removed_worktree_output(
             build_remove_command(worktree_path, should_delete_branch.then_some(branch_name));
 
         // Spawn the removal in background - runs from main_path (where we cd'd to)
-        spawn_detached(
-            &repo,
+        spawn_background_removal(
             main_path,
+            worktree_path,
             &remove_command,
-            branch_name,
-            "remove",
-            None,
+            Some(branch_name),
+            verify,
+            config,
         )?;
 
         super::flush()?;
@@ -640,12 +718,82 @@ fn handle_removed_worktree_output(
             integration_reason,
             target_branch,
         )))?;
+        run_post_remove_commands(main_path, worktree_path, Some(branch_name), verify, config)?;
 
         super::flush()?;
         Ok(())
     }
 }
 
+/// Spawn a background removal, with post-remove commands queued to run once it succeeds
+///
+/// Detached HEAD worktrees are logged under `detached`.
+fn spawn_background_removal(
+    main_path: &Path,
+    worktree_path: &Path,
+    remove_command: &str,
+    branch: Option<&str>,
+    verify: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let main_repo = Repository::at(main_path);
+    let label = branch.unwrap_or("detached");
+    let ctx =
+        verify.then(|| CommandContext::new(&main_repo, config, label, main_path, main_path, false));
+
+    match ctx.filter(has_post_remove_commands) {
+        Some(ctx) => {
+            clear_done_markers(&main_repo, label, &["remove".to_string()])?;
+            spawn_detached_after(
+                &main_repo,
+                main_path,
+                remove_command,
+                label,
+                "remove",
+                &[],
+                None,
+                config.logs.as_ref(),
+            )?;
+            execute_post_remove_commands(&ctx, worktree_path, branch, Some("remove"))
+        }
+        None => {
+            spawn_detached(
+                &main_repo,
+                main_path,
+                remove_command,
+                label,
+                "remove",
+                None,
+                config.logs.as_ref(),
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// Run post-remove commands in the main worktree after a synchronous removal
+fn run_post_remove_commands(
+    main_path: &Path,
+    worktree_path: &Path,
+    branch: Option<&str>,
+    verify: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    if !verify {
+        return Ok(());
+    }
+    let main_repo = Repository::at(main_path);
+    let ctx = CommandContext::new(
+        &main_repo,
+        config,
+        branch.unwrap_or(""),
+        main_path,
+        main_path,
+        false,
+    );
+    execute_post_remove_commands(&ctx, worktree_path, branch, None)
+}
+
 /// Execute a command with streaming output
 ///
 /// Uses Stdio::inherit to preserve TTY behavior - this ensures commands like cargo detect they're
@@ -672,14 +820,21 @@ fn handle_removed_worktree_output(
 /// - This follows Unix conventions (e.g., exit code 130 for SIGINT)
 ///
 /// The child process receives SIGINT directly from the terminal (via Stdio::inherit).
+///
+/// ## Timeouts
+///
+/// With a `timeout`, the command runs in its own process group so that on expiry the
+/// whole group (the shell and everything it started) can be killed; the error is then
+/// a [`CommandTimedOut`]. Because the group no longer receives terminal signals,
+/// SIGINT/SIGTERM received by worktrunk while waiting are forwarded to it.
 pub(crate) fn execute_streaming(
     command: &str,
     working_dir: &std::path::Path,
     redirect_stdout_to_stderr: bool,
     stdin_content: Option<&str>,
+    timeout: Option<Duration>,
 ) -> anyhow::Result<()> {
     use std::io::Write;
-    use worktrunk::git::WorktrunkError;
     use worktrunk::shell_exec::ShellConfig;
 
     let shell = ShellConfig::get();
@@ -716,6 +871,7 @@ pub(crate) fn execute_streaming(
     };
 
     let mut cmd = shell.command(&command_to_run);
+    isolate_for_timeout(&mut cmd, timeout);
     let mut child = cmd
         .current_dir(working_dir)
         .stdin(stdin_mode)
@@ -745,11 +901,176 @@ pub(crate) fn execute_streaming(
     }
 
     // Wait for command to complete
-    let status = child.wait().map_err(|e| {
+    let status = wait_with_timeout(&mut child, timeout)?;
+
+    exit_status_result(status)
+}
+
+/// A command exceeded its timeout; its process group was killed
+#[derive(Debug)]
+pub(crate) struct CommandTimedOut {
+    pub elapsed: Duration,
+}
+
+impl std::fmt::Display for CommandTimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "timed out after {}s", self.elapsed.as_secs())
+    }
+}
+
+impl std::error::Error for CommandTimedOut {}
+
+/// Put a command that has a timeout into its own process group, so the whole group
+/// can be killed on expiry without killing worktrunk itself.
+fn isolate_for_timeout(cmd: &mut std::process::Command, timeout: Option<Duration>) {
+    #[cfg(unix)]
+    if timeout.is_some() {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    #[cfg(not(unix))]
+    let _ = (cmd, timeout);
+}
+
+/// Send a signal to a child's process group (the child is the group leader).
+///
+/// Uses the `kill` utility rather than libc to stay free of unsafe code.
+#[cfg(unix)]
+fn signal_process_group(child: &std::process::Child, signal: &str) {
+    let _ = std::process::Command::new("kill")
+        .args([
+            format!("-{signal}"),
+            "--".into(),
+            format!("-{}", child.id()),
+        ])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status();
+}
+
+/// Wait for a child, killing its process group if `timeout` expires first.
+fn wait_with_timeout(
+    child: &mut std::process::Child,
+    timeout: Option<Duration>,
+) -> anyhow::Result<std::process::ExitStatus> {
+    let wait_error = |e: std::io::Error| {
         anyhow::Error::from(worktrunk::git::GitError::Other {
             message: format!("Failed to wait for command: {}", e),
         })
-    })?;
+    };
+
+    let Some(timeout) = timeout else {
+        return child.wait().map_err(wait_error);
+    };
+
+    // The child's group doesn't get terminal signals, so catch them and forward
+    #[cfg(unix)]
+    let signals = TimedWaitSignals::enter();
+
+    let start = std::time::Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) => {}
+            Err(e) => break Err(wait_error(e)),
+        }
+
+        #[cfg(unix)]
+        if signals.interrupted() {
+            signal_process_group(child, "INT");
+            break child.wait().map_err(wait_error);
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            #[cfg(unix)]
+            signal_process_group(child, "KILL");
+            #[cfg(not(unix))]
+            let _ = child.kill();
+            let _ = child.wait();
+            break Err(CommandTimedOut { elapsed }.into());
+        }
+
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+/// SIGINT/SIGTERM handling while commands with a timeout run
+///
+/// Those commands run in their own process group, so worktrunk catches terminal
+/// signals and forwards them. The handlers are registered once per process and
+/// never removed: outside a timed wait they run the signal's default action, so
+/// Ctrl-C still stops worktrunk after a timed hook has finished.
+#[cfg(unix)]
+struct TimedWaitSignals {
+    interrupted: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Set while no timed wait runs, which makes signals take their default action
+    idle: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Timed waits in progress; parallel hooks wait concurrently
+    active: std::sync::Mutex<usize>,
+}
+
+#[cfg(unix)]
+impl TimedWaitSignals {
+    fn get() -> &'static Self {
+        use std::sync::Arc;
+        use std::sync::atomic::AtomicBool;
+
+        static SIGNALS: std::sync::OnceLock<TimedWaitSignals> = std::sync::OnceLock::new();
+        SIGNALS.get_or_init(|| {
+            let signals = TimedWaitSignals {
+                interrupted: Arc::new(AtomicBool::new(false)),
+                idle: Arc::new(AtomicBool::new(true)),
+                active: std::sync::Mutex::new(0),
+            };
+            for sig in [signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM] {
+                let _ = signal_hook::flag::register(sig, Arc::clone(&signals.interrupted));
+                let _ =
+                    signal_hook::flag::register_conditional_default(sig, Arc::clone(&signals.idle));
+            }
+            signals
+        })
+    }
+
+    /// Start catching signals until the returned guard is dropped
+    fn enter() -> TimedWaitGuard {
+        use std::sync::atomic::Ordering;
+
+        let signals = Self::get();
+        let mut active = signals.active.lock().unwrap_or_else(|e| e.into_inner());
+        if *active == 0 {
+            signals.interrupted.store(false, Ordering::SeqCst);
+            signals.idle.store(false, Ordering::SeqCst);
+        }
+        *active += 1;
+        TimedWaitGuard(signals)
+    }
+}
+
+#[cfg(unix)]
+struct TimedWaitGuard(&'static TimedWaitSignals);
+
+#[cfg(unix)]
+impl TimedWaitGuard {
+    fn interrupted(&self) -> bool {
+        self.0.interrupted.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for TimedWaitGuard {
+    fn drop(&mut self) {
+        let mut active = self.0.active.lock().unwrap_or_else(|e| e.into_inner());
+        *active -= 1;
+        if *active == 0 {
+            self.0.idle.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+}
+
+/// Convert a child's exit status into `ChildProcessExited` on failure
+fn exit_status_result(status: std::process::ExitStatus) -> anyhow::Result<()> {
+    use worktrunk::git::WorktrunkError;
 
     // Check if child was killed by a signal (Unix only)
     // This handles Ctrl-C: when SIGINT is sent, the child receives it and terminates,
@@ -776,6 +1097,119 @@ pub(crate) fn execute_streaming(
     Ok(())
 }
 
+/// How long to keep reading a captured command's output after it exits.
+///
+/// Background processes the command started hold the pipes open indefinitely.
+const CAPTURE_DRAIN_GRACE: Duration = Duration::from_millis(500);
+
+/// Execute a command with its output captured instead of streamed
+///
+/// Used when several commands run concurrently: their output would interleave on the
+/// terminal, so each command's stdout and stderr are merged into one buffer that the
+/// caller prints once the command finishes.
+///
+/// Safe to call from worker threads - it doesn't touch the output system.
+///
+/// Returns the captured output alongside the command result; output is returned even
+/// when the command fails so the caller can show what went wrong.
+pub(crate) fn execute_captured(
+    command: &str,
+    working_dir: &std::path::Path,
+    stdin_content: Option<&str>,
+    timeout: Option<Duration>,
+) -> (Vec<u8>, anyhow::Result<()>) {
+    use std::io::{Read, Write};
+    use worktrunk::shell_exec::ShellConfig;
+
+    let shell = ShellConfig::get();
+
+    // POSIX: merge stderr into stdout at shell level so the buffer keeps the
+    // original interleaving. Other shells: capture both streams and concatenate.
+    let command_to_run = if shell.is_posix() {
+        format!("{{ {}\n}} 2>&1", command)
+    } else {
+        command.to_string()
+    };
+
+    let stdin_mode = if stdin_content.is_some() {
+        std::process::Stdio::piped()
+    } else {
+        std::process::Stdio::null()
+    };
+
+    let mut cmd = shell.command(&command_to_run);
+    isolate_for_timeout(&mut cmd, timeout);
+    let child = cmd
+        .current_dir(working_dir)
+        .stdin(stdin_mode)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .env_remove("VERGEN_GIT_DESCRIBE")
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            return (
+                Vec::new(),
+                Err(worktrunk::git::GitError::Other {
+                    message: format!("Failed to execute command with {}: {}", shell.name, e),
+                }
+                .into()),
+            );
+        }
+    };
+
+    // Drain both pipes on their own threads so the child never blocks on a full
+    // pipe while we write its stdin or poll for its deadline
+    struct Drain {
+        buf: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+        done: std::sync::mpsc::Receiver<()>,
+    }
+    fn drain(pipe: Option<impl Read + Send + 'static>) -> Drain {
+        let buf = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let (tx, done) = std::sync::mpsc::channel();
+        let sink = buf.clone();
+        std::thread::spawn(move || {
+            if let Some(mut pipe) = pipe {
+                let mut chunk = [0u8; 8192];
+                while let Ok(n @ 1..) = pipe.read(&mut chunk) {
+                    sink.lock().unwrap().extend_from_slice(&chunk[..n]);
+                }
+            }
+            let _ = tx.send(());
+        });
+        Drain { buf, done }
+    }
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    // Write the context from another thread too: a hook that prints more than a
+    // pipe buffer before reading stdin would otherwise block both sides. The
+    // writer is never joined, since a backgrounded process may hold stdin open.
+    if let Some((content, mut stdin)) = stdin_content.zip(child.stdin.take()) {
+        let content = content.to_string();
+        std::thread::spawn(move || {
+            let _ = stdin.write_all(content.as_bytes());
+        });
+    }
+
+    let status = wait_with_timeout(&mut child, timeout);
+
+    // A process the hook backgrounded (`npm run dev &`) inherits the pipes and
+    // keeps them open after the hook exits, so only wait briefly for EOF and
+    // keep whatever was read by then
+    let deadline = std::time::Instant::now() + CAPTURE_DRAIN_GRACE;
+    let collect = |drain: Drain| {
+        let _ = drain
+            .done
+            .recv_timeout(deadline.saturating_duration_since(std::time::Instant::now()));
+        std::mem::take(&mut *drain.buf.lock().unwrap())
+    };
+    let mut combined = collect(stdout);
+    combined.extend_from_slice(&collect(stderr));
+    (combined, status.and_then(exit_status_result))
+}
+
 /// Execute a command in a worktree directory
 ///
 /// Merges stdout into stderr using shell redirection (1>&2) to ensure deterministic output ordering.
@@ -803,6 +1237,7 @@ pub fn execute_command_in_worktree(
     worktree_path: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
+    timeout: Option<Duration>,
 ) -> anyhow::Result<()> {
     use std::io::Write;
     use worktrunk::styling::{eprint, stderr};
@@ -818,7 +1253,7 @@ pub fn execute_command_in_worktree(
     stderr().flush().ok(); // Ignore flush errors - reset is best-effort, command execution should proceed
 
     // Execute with stdout→stderr redirect for deterministic ordering
-    execute_streaming(command, worktree_path, true, stdin_content)?;
+    execute_streaming(command, worktree_path, true, stdin_content, timeout)?;
 
     // Flush to ensure all output appears before we continue
     super::flush()?;
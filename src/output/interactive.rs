@@ -92,7 +92,7 @@ impl OutputHandler for InteractiveOutput {
         // On non-Unix platforms, fall back to spawn-and-wait
         // This uses the shell abstraction (Git Bash if available)
         let exec_dir = self.target_dir.as_deref().unwrap_or_else(|| Path::new("."));
-        execute_streaming(&command, exec_dir, false, None)?;
+        execute_streaming(&command, exec_dir, false, None, None)?;
         Ok(())
     }
 
@@ -69,6 +69,8 @@ pub use global::{
     initialize, print, shell_integration_hint, table, terminate_output,
 };
 // Re-export output handlers
+pub(crate) use handlers::execute_captured;
 pub use handlers::{
     execute_command_in_worktree, execute_user_command, handle_remove_output, handle_switch_output,
+    print_error,
 };
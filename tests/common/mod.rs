@@ -726,6 +726,26 @@ exit 1
         self.mock_bin_path = Some(mock_bin);
     }
 
+    /// Add a mock command (e.g. a `gh` that returns canned JSON) to the mock bin directory
+    ///
+    /// Overwrites any mock of the same name from `setup_mock_gh()`. Use
+    /// `configure_mock_commands()` to put it on PATH.
+    pub fn setup_mock_command(&mut self, name: &str, script: &str) {
+        let mock_bin = self.temp_dir.path().join("mock-bin");
+        std::fs::create_dir_all(&mock_bin).unwrap();
+
+        let script_path = mock_bin.join(name);
+        std::fs::write(&script_path, script).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        self.mock_bin_path = Some(mock_bin);
+    }
+
     /// Configure a command to use mock gh/glab commands
     ///
     /// Must call `setup_mock_gh()` first. Prepends the mock bin directory to PATH
@@ -21,7 +21,7 @@ use std::path::Path;
 /// Execute a command in a PTY with interactive input
 ///
 /// Returns (combined_output, exit_code)
-fn exec_in_pty_with_input(
+pub(crate) fn exec_in_pty_with_input(
     command: &str,
     args: &[&str],
     working_dir: &Path,
@@ -0,0 +1,221 @@
+//! Commit generation through the built-in HTTP providers, against a stub server
+
+use crate::common::{TestRepo, make_snapshot_cmd, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request received by [`StubServer`]
+struct StubRequest {
+    /// Request line and headers, with lowercase header names
+    head: String,
+    body: serde_json::Value,
+}
+
+/// Local HTTP server answering each request with the next canned response
+struct StubServer {
+    /// Base URL to configure as `endpoint`
+    endpoint: String,
+    requests: Arc<Mutex<Vec<StubRequest>>>,
+}
+
+impl StubServer {
+    /// Serve `responses` (status and JSON body) in order, one per connection
+    fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/v1", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for (status, response) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line.trim_end().to_lowercase());
+                    head.push('\n');
+                }
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length:"))
+                    .map_or(0, |length| length.trim().parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                recorded.lock().unwrap().push(StubRequest {
+                    head,
+                    body: serde_json::from_slice(&body).unwrap(),
+                });
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+
+        Self { endpoint, requests }
+    }
+
+    fn requests(&self) -> std::sync::MutexGuard<'_, Vec<StubRequest>> {
+        self.requests.lock().unwrap()
+    }
+}
+
+/// Repo with an untracked file to commit, configured to use `provider` at `server`
+fn setup_repo(server: &StubServer, provider: &str) -> TestRepo {
+    setup_repo_with_config(server, provider, "")
+}
+
+/// [`setup_repo`], sending the key from `WT_TEST_API_KEY`
+fn setup_repo_with_key(server: &StubServer, provider: &str) -> TestRepo {
+    setup_repo_with_config(server, provider, "api-key-env = \"WT_TEST_API_KEY\"\n")
+}
+
+fn setup_repo_with_config(server: &StubServer, provider: &str, extra: &str) -> TestRepo {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    std::fs::write(repo.root_path().join("parser.rs"), "fn parse() {}").unwrap();
+    repo.write_test_config(&format!(
+        r#"[commit-generation]
+provider = "{provider}"
+endpoint = "{}"
+model = "test-model"
+{extra}"#,
+        server.endpoint
+    ));
+    repo
+}
+
+fn head_message(repo: &TestRepo) -> String {
+    let output = repo
+        .git_command(&["log", "-1", "--format=%B"])
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn test_step_commit_openai_compatible() {
+    let server = StubServer::start(vec![(
+        200,
+        r#"{"choices":[{"index":0,"message":{"role":"assistant","content":"feat: Add parser\n"}}]}"#,
+    )]);
+    let repo = setup_repo_with_key(&server, "openai-compatible");
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["commit"], None);
+        cmd.env("WT_TEST_API_KEY", "test-key");
+        assert_cmd_snapshot!("step_commit_openai_compatible", cmd);
+    });
+
+    assert_eq!(head_message(&repo), "feat: Add parser");
+    let requests = server.requests();
+    let request = &requests[0];
+    assert!(request.head.starts_with("post /v1/chat/completions "));
+    assert!(request.head.contains("authorization: bearer test-key\n"));
+    assert_eq!(request.body["model"], "test-model");
+    let prompt = request.body["messages"][0]["content"].as_str().unwrap();
+    assert!(prompt.contains("fn parse() {}"), "{prompt}");
+}
+
+#[test]
+fn test_step_commit_openai_compatible_without_key() {
+    let server = StubServer::start(vec![(
+        200,
+        r#"{"choices":[{"message":{"content":"Add parser"}}]}"#,
+    )]);
+    let repo = setup_repo(&server, "openai-compatible");
+
+    // The default key is only for OpenAI's API, so none is sent to other endpoints
+    let output = make_snapshot_cmd(&repo, "step", &["commit"], None)
+        .env("OPENAI_API_KEY", "ambient-key")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(head_message(&repo), "Add parser");
+    assert!(!server.requests()[0].head.contains("authorization:"));
+}
+
+#[test]
+fn test_step_commit_anthropic() {
+    let server = StubServer::start(vec![(
+        200,
+        r#"{"type":"message","role":"assistant","content":[{"type":"text","text":"feat: Add parser"}],"stop_reason":"end_turn"}"#,
+    )]);
+    let repo = setup_repo_with_key(&server, "anthropic");
+
+    let output = make_snapshot_cmd(&repo, "step", &["commit"], None)
+        .env("WT_TEST_API_KEY", "test-key")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    assert_eq!(head_message(&repo), "feat: Add parser");
+    let requests = server.requests();
+    let request = &requests[0];
+    assert!(request.head.starts_with("post /v1/messages "));
+    assert!(request.head.contains("x-api-key: test-key\n"));
+    assert!(request.head.contains("anthropic-version: 2023-06-01\n"));
+    assert_eq!(request.body["model"], "test-model");
+    assert!(request.body["max_tokens"].is_u64());
+}
+
+#[test]
+fn test_step_commit_http_error() {
+    let server = StubServer::start(vec![(
+        404,
+        r#"{"error":{"message":"model 'test-model' not found","type":"invalid_request_error"}}"#,
+    )]);
+    let repo = setup_repo(&server, "openai-compatible");
+
+    let mut settings = setup_snapshot_settings(&repo);
+    settings.add_filter(&server.endpoint, "http://127.0.0.1:[PORT]/v1");
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["commit"], None);
+        assert_cmd_snapshot!("step_commit_http_error", cmd);
+    });
+}
+
+#[test]
+fn test_step_commit_http_missing_key() {
+    let server = StubServer::start(vec![]);
+    let repo = setup_repo_with_key(&server, "anthropic");
+
+    let output = make_snapshot_cmd(&repo, "step", &["commit"], None)
+        .env_remove("WT_TEST_API_KEY")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("WT_TEST_API_KEY is not set"), "{stderr}");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_http_provider_requires_model() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.write_test_config(
+        r#"[commit-generation]
+provider = "openai-compatible"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "step", &["commit"], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to load config"), "{stderr}");
+}
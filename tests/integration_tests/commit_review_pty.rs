@@ -0,0 +1,160 @@
+//! PTY-based tests for reviewing generated commit messages (`--edit`)
+//!
+//! The review prompt reads from a TTY, so these run in a PTY like `approval_pty.rs`.
+//! They assert on the resulting commits rather than snapshotting the interleaved output.
+
+use super::approval_pty::exec_in_pty_with_input;
+use crate::common::TestRepo;
+use insta_cmd::get_cargo_bin;
+use std::fs;
+
+/// LLM mock: follows a "mention tests" hint, otherwise describes the parser fix
+const LLM_CONFIG: &str = r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "p=$(cat); case \"$p\" in *'guidance: mention tests'*) echo 'test: cover the parser' ;; *) echo 'fix: handle empty input' ;; esac"]
+"#;
+
+fn setup_review_repo() -> TestRepo {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.write_test_config(LLM_CONFIG);
+    fs::write(repo.root_path().join("parser.rs"), "fn parse() {}").unwrap();
+    repo
+}
+
+/// Run `wt <args>` in the repo with `input` typed at the prompts
+fn run_review(
+    repo: &TestRepo,
+    args: &[&str],
+    extra_env: &[(&str, &str)],
+    input: &str,
+) -> (String, i32) {
+    let mut env_vars = repo.test_env_vars();
+    env_vars.extend(
+        extra_env
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    );
+    exec_in_pty_with_input(
+        get_cargo_bin("wt").to_str().unwrap(),
+        args,
+        repo.root_path(),
+        &env_vars,
+        input,
+    )
+}
+
+fn head_message(repo: &TestRepo) -> String {
+    let output = repo
+        .git_command(&["log", "-1", "--format=%B"])
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn saved_message_path(repo: &TestRepo) -> std::path::PathBuf {
+    repo.root_path().join(".git/WT_COMMIT_EDITMSG")
+}
+
+#[test]
+fn test_commit_review_accept() {
+    let repo = setup_review_repo();
+
+    let (output, exit_code) = run_review(&repo, &["step", "commit", "--edit"], &[], "y\n");
+
+    assert_eq!(exit_code, 0, "{output}");
+    assert!(output.contains("Commit with this message?"), "{output}");
+    assert_eq!(head_message(&repo), "fix: handle empty input");
+    assert!(!saved_message_path(&repo).exists());
+}
+
+#[test]
+fn test_commit_review_edit() {
+    let repo = setup_review_repo();
+
+    // The editor gets the message file as its last argument
+    let (output, exit_code) = run_review(
+        &repo,
+        &["step", "commit", "--edit"],
+        &[(
+            "GIT_EDITOR",
+            "printf 'fix: handle empty input\\n\\n# A comment\\nReturn early.\\n' >",
+        )],
+        "e\ny\n",
+    );
+
+    assert_eq!(exit_code, 0, "{output}");
+    assert_eq!(
+        head_message(&repo),
+        "fix: handle empty input\n\nReturn early."
+    );
+}
+
+#[test]
+fn test_commit_review_regenerate_with_hint() {
+    let repo = setup_review_repo();
+
+    let (output, exit_code) = run_review(
+        &repo,
+        &["step", "commit", "--edit"],
+        &[],
+        "r\nmention tests\ny\n",
+    );
+
+    assert_eq!(exit_code, 0, "{output}");
+    assert!(output.contains("Regenerating commit message"), "{output}");
+    assert_eq!(head_message(&repo), "test: cover the parser");
+}
+
+#[test]
+fn test_commit_review_abort_and_resume() {
+    let repo = setup_review_repo();
+
+    let (output, exit_code) = run_review(
+        &repo,
+        &["step", "commit", "--edit"],
+        &[("GIT_EDITOR", "printf 'fix: keep my wording\\n' >")],
+        "e\nn\n",
+    );
+    assert_ne!(exit_code, 0, "{output}");
+    assert!(output.contains("Commit aborted"), "{output}");
+    assert_eq!(head_message(&repo), "Initial commit");
+    assert_eq!(
+        fs::read_to_string(saved_message_path(&repo)).unwrap(),
+        "fix: keep my wording\n"
+    );
+
+    // `commit.review` in the config works like --edit, and starts from the saved message
+    repo.write_test_config(&format!("{LLM_CONFIG}\n[commit]\nreview = true\n"));
+    let (output, exit_code) = run_review(&repo, &["step", "commit"], &[], "y\n");
+    assert_eq!(exit_code, 0, "{output}");
+    assert!(output.contains("aborted review"), "{output}");
+    assert_eq!(head_message(&repo), "fix: keep my wording");
+    assert!(!saved_message_path(&repo).exists());
+}
+
+#[test]
+fn test_squash_review_edit() {
+    let repo = setup_review_repo();
+    repo.git_command(&["checkout", "-q", "-b", "feature"])
+        .output()
+        .unwrap();
+    repo.commit("First");
+    repo.commit("Second");
+
+    let (output, exit_code) = run_review(
+        &repo,
+        &["step", "squash", "main", "--edit"],
+        &[("GIT_EDITOR", "printf 'feat: squashed parser work\\n' >")],
+        "e\ny\n",
+    );
+
+    assert_eq!(exit_code, 0, "{output}");
+    assert_eq!(head_message(&repo), "feat: squashed parser work");
+    let output = repo
+        .git_command(&["rev-list", "--count", "main..HEAD"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "1");
+}
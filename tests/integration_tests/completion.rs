@@ -700,12 +700,14 @@ fn test_complete_step_subcommands() {
     // Git operations
     assert!(subcommands.contains(&"commit"), "Missing commit");
     assert!(subcommands.contains(&"squash"), "Missing squash");
+    assert!(subcommands.contains(&"describe"), "Missing describe");
     assert!(subcommands.contains(&"push"), "Missing push");
     assert!(subcommands.contains(&"rebase"), "Missing rebase");
+    assert!(subcommands.contains(&"sparse"), "Missing sparse");
     assert_eq!(
         subcommands.len(),
-        4,
-        "Should have exactly 4 step subcommands (git operations)"
+        6,
+        "Should have exactly 6 step subcommands (git operations)"
     );
 }
 
@@ -721,17 +723,24 @@ fn test_complete_hook_subcommands() {
     let subcommands = value_suggestions(&stdout);
     // Hook types and commands
     assert!(subcommands.contains(&"show"), "Missing show");
+    assert!(subcommands.contains(&"logs"), "Missing logs");
+    assert!(subcommands.contains(&"stop"), "Missing stop");
+    assert!(subcommands.contains(&"pre-create"), "Missing pre-create");
     assert!(subcommands.contains(&"post-create"), "Missing post-create");
     assert!(subcommands.contains(&"post-start"), "Missing post-start");
+    assert!(subcommands.contains(&"pre-switch"), "Missing pre-switch");
+    assert!(subcommands.contains(&"post-switch"), "Missing post-switch");
     assert!(subcommands.contains(&"pre-commit"), "Missing pre-commit");
     assert!(subcommands.contains(&"pre-merge"), "Missing pre-merge");
     assert!(subcommands.contains(&"post-merge"), "Missing post-merge");
+    assert!(subcommands.contains(&"pre-push"), "Missing pre-push");
     assert!(subcommands.contains(&"pre-remove"), "Missing pre-remove");
+    assert!(subcommands.contains(&"post-remove"), "Missing post-remove");
     assert!(subcommands.contains(&"approvals"), "Missing approvals");
     assert_eq!(
         subcommands.len(),
-        8,
-        "Should have exactly 8 hook subcommands"
+        15,
+        "Should have exactly 15 hook subcommands"
     );
 
     // Test 2: Partial input "po" - filters to post-* subcommands
@@ -742,6 +751,8 @@ fn test_complete_hook_subcommands() {
     assert!(subcommands.contains(&"post-create"));
     assert!(subcommands.contains(&"post-start"));
     assert!(subcommands.contains(&"post-merge"));
+    assert!(subcommands.contains(&"post-switch"));
+    assert!(subcommands.contains(&"post-remove"));
     assert!(!subcommands.contains(&"pre-commit"));
     assert!(!subcommands.contains(&"pre-merge"));
 }
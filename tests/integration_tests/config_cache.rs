@@ -122,6 +122,31 @@ fn test_config_cache_clear_ci_empty() {
     assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"⚪ No CI cache entries to clear");
 }
 
+#[test]
+fn test_config_cache_clear_statusline() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // The background refresh caches the snapshot
+    let mut cmd = wt_command();
+    repo.clean_cli_env(&mut cmd);
+    cmd.args(["list", "statusline", "--refresh"])
+        .current_dir(repo.root_path());
+    assert!(cmd.output().unwrap().status.success());
+
+    let output = wt_config_cache_cmd(&repo, &["clear", "statusline"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"✅ [32mCleared [1m1[22m statusline cache entry[39m");
+
+    let output = wt_config_cache_cmd(&repo, &["clear", "statusline"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"⚪ No statusline cache entries to clear");
+}
+
 #[test]
 fn test_config_cache_clear_unknown_type() {
     let repo = TestRepo::new();
@@ -133,7 +158,7 @@ fn test_config_cache_clear_unknown_type() {
     assert!(!output.status.success());
     assert_snapshot!(String::from_utf8_lossy(&output.stderr), @r"
     [1m[31merror:[0m invalid value '[1m[33munknown[0m' for '[1m[36m[CACHE_TYPE][0m'
-      [possible values: [1m[32mci[0m, [1m[32mdefault-branch[0m, [1m[32mlogs[0m]
+      [possible values: [1m[32mci[0m, [1m[32mdefault-branch[0m, [1m[32mstatusline[0m, [1m[32mlogs[0m]
 
     For more information, try '[1m[36m--help[0m'.
     ");
@@ -173,6 +198,25 @@ fn test_config_cache_clear_logs_with_files() {
     assert!(!log_dir.exists());
 }
 
+#[test]
+fn test_config_cache_clear_logs_includes_previous_runs() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let log_dir = repo.root_path().join(".git").join("wt-logs");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    std::fs::write(log_dir.join("feature-remove.log"), "latest").unwrap();
+    std::fs::write(log_dir.join("feature-remove.log.1"), "previous").unwrap();
+    std::fs::write(log_dir.join("feature-user-post-start-a.done"), "0").unwrap();
+
+    let output = wt_config_cache_cmd(&repo, &["clear", "logs"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"✅ [32mCleared [1m2[22m log files[39m");
+    assert!(!log_dir.exists());
+}
+
 #[test]
 fn test_config_cache_clear_logs_single_file() {
     let repo = TestRepo::new();
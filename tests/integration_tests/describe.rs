@@ -0,0 +1,207 @@
+use crate::common::{TestRepo, make_snapshot_cmd, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// What the mock LLM answers
+const DESCRIPTION: &str = r"Add parser\n\n## Summary\n- Parse input files\n\n## Testing\nUnit tests for the parser\n\n## Risks\nNone";
+
+/// Feature worktree with two commits ahead of main, and a mock LLM that saves
+/// the prompt it gets to `prompt.txt` next to the repo
+fn setup_feature(repo: &mut TestRepo) -> PathBuf {
+    repo.commit("Initial commit");
+    let feature = repo.add_worktree("feature");
+    for (file, message) in [
+        ("parser.rs", "Add parser"),
+        ("input.rs", "Read input files"),
+    ] {
+        fs::write(feature.join(file), "fn f() {}\n").unwrap();
+        repo.git_command(&["add", file])
+            .current_dir(&feature)
+            .output()
+            .unwrap();
+        repo.git_command(&["commit", "-m", message])
+            .current_dir(&feature)
+            .output()
+            .unwrap();
+    }
+
+    let prompt_path = prompt_path(repo);
+    repo.write_test_config(&format!(
+        r#"[commit-generation]
+command = "sh"
+args = ["-c", "cat > '{}' && printf '{DESCRIPTION}'"]
+"#,
+        prompt_path.display()
+    ));
+    feature
+}
+
+fn prompt_path(repo: &TestRepo) -> PathBuf {
+    repo.root_path().parent().unwrap().join("prompt.txt")
+}
+
+fn snapshot_describe(test_name: &str, repo: &TestRepo, args: &[&str], cwd: &Path) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut step_args = vec!["describe"];
+        step_args.extend_from_slice(args);
+        let mut cmd = make_snapshot_cmd(repo, "step", &step_args, Some(cwd));
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[test]
+fn test_step_describe() {
+    let mut repo = TestRepo::new();
+    let feature = setup_feature(&mut repo);
+
+    snapshot_describe("step_describe", &repo, &[], &feature);
+
+    // The prompt has the commits, the diff and the sections to write
+    let prompt = fs::read_to_string(prompt_path(&repo)).unwrap();
+    assert!(
+        prompt.contains("- Add parser\n- Read input files\n"),
+        "{prompt}"
+    );
+    assert!(prompt.contains("+++ b/parser.rs"), "{prompt}");
+    assert!(prompt.contains(r#"target="main""#), "{prompt}");
+    assert!(prompt.contains("## Testing"), "{prompt}");
+}
+
+#[test]
+fn test_step_describe_output_file() {
+    let mut repo = TestRepo::new();
+    let feature = setup_feature(&mut repo);
+
+    snapshot_describe(
+        "step_describe_output_file",
+        &repo,
+        &["--output", "pr.md"],
+        &feature,
+    );
+
+    let written = fs::read_to_string(feature.join("pr.md")).unwrap();
+    assert!(
+        written.starts_with("Add parser\n\n## Summary\n"),
+        "{written}"
+    );
+    assert!(written.ends_with("## Risks\nNone\n"), "{written}");
+}
+
+#[test]
+fn test_step_describe_pr() {
+    let mut repo = TestRepo::new();
+    let feature = setup_feature(&mut repo);
+
+    // A stand-in gh that records its arguments and the body it's given
+    let bin = repo.root_path().parent().unwrap().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    let record = repo.root_path().parent().unwrap().join("gh");
+    fs::write(
+        bin.join("gh"),
+        format!(
+            r#"#!/bin/sh
+record='{}'
+printf '%s\n' "$@" > "$record.args"
+while [ $# -gt 0 ]; do
+    if [ "$1" = --body-file ]; then cp "$2" "$record.body"; fi
+    shift
+done
+echo https://github.com/owner/repo/pull/1
+"#,
+            record.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(bin.join("gh"), fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = format!(
+        "{}:{}",
+        bin.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    let output = make_snapshot_cmd(&repo, "step", &["describe", "--pr"], Some(&feature))
+        .env("PATH", path)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let args = fs::read_to_string(record.with_extension("args")).unwrap();
+    let args: Vec<&str> = args.lines().collect();
+    assert_eq!(
+        args[..6],
+        ["pr", "create", "--base", "main", "--title", "Add parser"]
+    );
+    assert_eq!(args[6], "--body-file");
+    let body = fs::read_to_string(record.with_extension("body")).unwrap();
+    assert!(body.starts_with("## Summary\n"), "{body}");
+    // The scratch body file is cleaned up
+    assert!(!Path::new(args[7]).exists());
+}
+
+/// When gh fails, the saved file keeps the title along with the body
+#[test]
+fn test_step_describe_pr_failure_keeps_description() {
+    let mut repo = TestRepo::new();
+    let feature = setup_feature(&mut repo);
+
+    let bin = repo.root_path().parent().unwrap().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    fs::write(
+        bin.join("gh"),
+        "#!/bin/sh\necho 'gh: not authenticated' >&2\nexit 1\n",
+    )
+    .unwrap();
+    fs::set_permissions(bin.join("gh"), fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = format!(
+        "{}:{}",
+        bin.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    let output = make_snapshot_cmd(&repo, "step", &["describe", "--pr"], Some(&feature))
+        .env("PATH", path)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("gh pr create failed"), "{stderr}");
+
+    let git_dir = repo
+        .git_command(&[
+            "-C",
+            feature.to_str().unwrap(),
+            "rev-parse",
+            "--absolute-git-dir",
+        ])
+        .output()
+        .unwrap();
+    let git_dir = String::from_utf8_lossy(&git_dir.stdout).trim().to_string();
+    let saved = fs::read_to_string(Path::new(&git_dir).join("WT_PR_DESCRIPTION.md")).unwrap();
+    assert!(saved.starts_with("Add parser\n\n## Summary\n"), "{saved}");
+}
+
+#[test]
+fn test_step_describe_no_commits() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature = repo.add_worktree("feature");
+    repo.write_test_config(
+        r#"[commit-generation]
+command = "echo"
+"#,
+    );
+
+    snapshot_describe("step_describe_no_commits", &repo, &[], &feature);
+}
+
+#[test]
+fn test_step_describe_not_configured() {
+    let mut repo = TestRepo::new();
+    let feature = setup_feature(&mut repo);
+    repo.write_test_config("");
+
+    snapshot_describe("step_describe_not_configured", &repo, &[], &feature);
+}
@@ -214,6 +214,44 @@ fn display_llm_command_failed() {
     assert_snapshot!("llm_command_failed", err.to_string());
 }
 
+#[test]
+fn display_llm_request_failed() {
+    let err = GitError::LlmRequestFailed {
+        model: "qwen2.5-coder".into(),
+        url: "http://localhost:11434/v1/chat/completions".into(),
+        error: "HTTP 404: model 'qwen2.5-coder' not found".into(),
+    };
+
+    assert_snapshot!("llm_request_failed", err.to_string());
+}
+
+#[test]
+fn display_invalid_commit_message() {
+    let err = GitError::InvalidCommitMessage {
+        message: "Added the parser.".into(),
+        error: "the first line must look like `type(scope): subject`".into(),
+        attempts: 3,
+    };
+
+    assert_snapshot!("invalid_commit_message", err.to_string());
+}
+
+#[test]
+fn display_commit_review_not_interactive() {
+    let err = GitError::CommitReviewNotInteractive;
+
+    assert_snapshot!("commit_review_not_interactive", err.to_string());
+}
+
+#[test]
+fn display_commit_aborted() {
+    let err = GitError::CommitAborted {
+        message_path: PathBuf::from("/tmp/repo/.git/WT_COMMIT_EDITMSG"),
+    };
+
+    assert_snapshot!("commit_aborted", err.to_string());
+}
+
 #[test]
 fn display_project_config_not_found() {
     let err = GitError::ProjectConfigNotFound {
@@ -290,6 +328,17 @@ fn display_hook_command_failed_without_name() {
     assert_snapshot!("hook_command_failed_without_name", err.to_string());
 }
 
+#[test]
+fn display_hook_command_timed_out() {
+    let err = WorktrunkError::HookCommandTimedOut {
+        hook_type: HookType::PostCreate,
+        command_name: Some("install".into()),
+        elapsed: std::time::Duration::from_secs(300),
+    };
+
+    assert_snapshot!("hook_command_timed_out", err.to_string());
+}
+
 // ============================================================================
 // Integration test: verify error message includes command when git unavailable
 // ============================================================================
@@ -0,0 +1,373 @@
+//! Integration tests for `wt hook logs` and `wt hook stop`
+
+use crate::common::{
+    TestRepo, make_snapshot_cmd, setup_snapshot_settings, wait_for_file_content,
+    wait_for_file_lines,
+};
+use insta_cmd::assert_cmd_snapshot;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Matches SOURCE_DATE_EPOCH in the test environment
+const TEST_EPOCH: u64 = 1735776000;
+
+fn snapshot_logs(test_name: &str, repo: &TestRepo, args: &[&str]) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "hook", &[&["logs"], args].concat(), None);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+/// Write a log file with a fixed age so relative times are stable
+fn write_log(log_dir: &Path, file_name: &str, content: &str, age: Duration) -> PathBuf {
+    fs::create_dir_all(log_dir).unwrap();
+    let path = log_dir.join(file_name);
+    fs::write(&path, content).unwrap();
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(UNIX_EPOCH + Duration::from_secs(TEST_EPOCH) - age)
+        .unwrap();
+    path
+}
+
+fn log_dir(repo: &TestRepo) -> PathBuf {
+    repo.root_path().join(".git").join("wt-logs")
+}
+
+/// PID that can't belong to a running process
+const DEAD_PID: u32 = 999_999_999;
+
+#[test]
+fn test_hook_logs_empty() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    snapshot_logs("hook_logs_empty", &repo, &[]);
+}
+
+#[test]
+fn test_hook_logs_lists_by_branch() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let dir = log_dir(&repo);
+    write_log(
+        &dir,
+        "feature-user-post-start-npm.log",
+        "npm output\n",
+        Duration::from_secs(300),
+    );
+    write_log(
+        &dir,
+        "feature-user-post-start-npm.log.1",
+        "older npm output\n",
+        Duration::from_secs(7200),
+    );
+    write_log(
+        &dir,
+        "feature-project-post-start-dev-server.log",
+        &"x".repeat(2048),
+        Duration::from_secs(60),
+    );
+    write_log(
+        &dir,
+        "bugfix-remove.log",
+        "removed\n",
+        Duration::from_secs(86400),
+    );
+    // Completion markers are not logs
+    fs::write(dir.join("feature-user-post-start-npm.done"), "0").unwrap();
+
+    snapshot_logs("hook_logs_lists_by_branch", &repo, &[]);
+}
+
+#[test]
+fn test_hook_logs_filter_by_name_and_branch() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let dir = log_dir(&repo);
+    write_log(
+        &dir,
+        "feature-user-post-start-npm.log",
+        "feature npm\n",
+        Duration::from_secs(300),
+    );
+    write_log(
+        &dir,
+        "bugfix-user-post-start-npm.log",
+        "bugfix npm\n",
+        Duration::from_secs(300),
+    );
+    write_log(
+        &dir,
+        "feature-remove.log",
+        "removed\n",
+        Duration::from_secs(300),
+    );
+
+    snapshot_logs("hook_logs_filter", &repo, &["npm", "--branch", "feature"]);
+}
+
+#[test]
+fn test_hook_logs_shows_job_status() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let dir = log_dir(&repo);
+    for (name, job) in [
+        ("npm", "exit=0\n"),
+        ("lint", "exit=2\n"),
+        ("dev-server", ""),
+    ] {
+        write_log(
+            &dir,
+            &format!("feature-user-post-start-{name}.log"),
+            "output\n",
+            Duration::from_secs(300),
+        );
+        fs::write(
+            dir.join(format!("feature-user-post-start-{name}.job")),
+            format!("pid={DEAD_PID}\nstarted={TEST_EPOCH}\n{job}"),
+        )
+        .unwrap();
+    }
+
+    snapshot_logs("hook_logs_job_status", &repo, &[]);
+}
+
+#[test]
+fn test_hook_logs_follow_ambiguous() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let dir = log_dir(&repo);
+    write_log(
+        &dir,
+        "feature-user-post-start-npm.log",
+        "feature npm\n",
+        Duration::from_secs(300),
+    );
+    write_log(
+        &dir,
+        "bugfix-user-post-start-npm.log",
+        "bugfix npm\n",
+        Duration::from_secs(300),
+    );
+
+    snapshot_logs("hook_logs_follow_ambiguous", &repo, &["npm", "--follow"]);
+}
+
+#[test]
+fn test_hook_logs_follow_prints_new_output() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let path = write_log(
+        &log_dir(&repo),
+        "feature-user-post-start-npm.log",
+        "FIRST\n",
+        Duration::from_secs(300),
+    );
+
+    let mut child = make_snapshot_cmd(&repo, "hook", &["logs", "npm", "--follow"], None)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    thread::sleep(Duration::from_millis(500));
+    fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap()
+        .write_all(b"SECOND\n")
+        .unwrap();
+    thread::sleep(Duration::from_millis(800));
+
+    child.kill().unwrap();
+    let mut stdout = String::new();
+    child
+        .stdout
+        .take()
+        .unwrap()
+        .read_to_string(&mut stdout)
+        .unwrap();
+    child.wait().unwrap();
+
+    assert_eq!(stdout, "FIRST\nSECOND\n");
+}
+
+#[test]
+fn test_post_start_keeps_previous_runs() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[logs]
+keep = 2
+
+[post-start]
+stamp = "echo 'STAMP'"
+"#,
+    );
+
+    // Each creation of the worktree runs post-start again
+    let latest = log_dir(&repo).join("feature-user-post-start-stamp.log");
+    for _ in 0..3 {
+        let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        wait_for_file_content(&latest, Duration::from_secs(5));
+
+        let output = make_snapshot_cmd(
+            &repo,
+            "remove",
+            &["feature", "--no-background", "--force-delete"],
+            None,
+        )
+        .output()
+        .unwrap();
+        assert!(output.status.success());
+    }
+
+    let previous = log_dir(&repo).join("feature-user-post-start-stamp.log.1");
+    assert_eq!(fs::read_to_string(previous).unwrap(), "STAMP\n");
+    assert!(
+        !log_dir(&repo)
+            .join("feature-user-post-start-stamp.log.2")
+            .exists()
+    );
+}
+
+#[test]
+fn test_hook_stop_not_running() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    let dir = log_dir(&repo);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("feature-user-post-start-npm.job"),
+        format!("pid={DEAD_PID}\nstarted={TEST_EPOCH}\nexit=0\n"),
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd =
+            make_snapshot_cmd(&repo, "hook", &["stop", "npm", "--branch", "feature"], None);
+        assert_cmd_snapshot!("hook_stop_not_running", cmd);
+    });
+}
+
+#[cfg(unix)]
+#[test]
+fn test_hook_stop_kills_running_command() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-start]
+server = "echo started; sleep 60"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let job = log_dir(&repo).join("feature-user-post-start-server.job");
+    wait_for_file_lines(&job, 2, Duration::from_secs(5));
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(
+            &repo,
+            "hook",
+            &["stop", "server", "--branch", "feature"],
+            None,
+        );
+        assert_cmd_snapshot!("hook_stop_running", cmd);
+    });
+
+    // The job no longer counts as running
+    let start = std::time::Instant::now();
+    loop {
+        let output = make_snapshot_cmd(&repo, "hook", &["logs", "--branch", "feature"], None)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("stopped") {
+            break;
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "job still reported as running:\n{stderr}"
+        );
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Clearing logs keeps the files of a running job, which stays stoppable
+#[cfg(unix)]
+#[test]
+fn test_cache_clear_logs_keeps_running_job() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-start]
+server = "echo started; sleep 60"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let job = log_dir(&repo).join("feature-user-post-start-server.job");
+    wait_for_file_lines(&job, 2, Duration::from_secs(5));
+    let finished = write_log(
+        &log_dir(&repo),
+        "feature-user-post-start-lint.job",
+        "exit=0\n",
+        Duration::ZERO,
+    );
+
+    let output = make_snapshot_cmd(&repo, "config", &["cache", "clear", "logs"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(job.exists(), "running job should be kept");
+    assert!(
+        log_dir(&repo)
+            .join("feature-user-post-start-server.log")
+            .exists()
+    );
+    assert!(!finished.exists(), "finished job should be cleared");
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "hook",
+        &["stop", "server", "--branch", "feature"],
+        None,
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success(), "{output:?}");
+}
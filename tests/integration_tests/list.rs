@@ -390,6 +390,32 @@ fn test_list_json_with_metadata() {
     snapshot_list_json("json_with_metadata", &repo);
 }
 
+#[test]
+fn test_list_background_jobs() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.add_worktree("feature");
+    repo.add_worktree("bugfix");
+
+    // Job files as written by detached post-start commands; the PID is never running
+    let log_dir = repo.root_path().join(".git").join("wt-logs");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    for (file, exit) in [
+        ("feature-user-post-start-npm.job", "exit=0\n"),
+        ("feature-user-post-start-lint.job", "exit=1\n"),
+        ("feature-project-post-start-build.job", "exit=0\n"),
+        ("bugfix-user-post-start-server.job", ""),
+    ] {
+        std::fs::write(
+            log_dir.join(file),
+            format!("pid=999999999\nstarted=1735776000\n{exit}"),
+        )
+        .unwrap();
+    }
+
+    snapshot_list("background_jobs", &repo);
+}
+
 /// Test that committed_trees_match is true when a branch has commits ahead but identical tree content.
 /// This tests the merge commit scenario where content matches main even with different commit history.
 #[test]
@@ -3527,6 +3527,176 @@ fn test_step_commit_nothing_to_commit() {
     );
 }
 
+#[test]
+fn test_step_commit_validate_retries() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    fs::write(repo.root_path().join("parser.rs"), "fn parse() {}").unwrap();
+
+    // The mock LLM answers in the wrong format first, then correctly, saving each prompt
+    let dir = repo.root_path().parent().unwrap();
+    let script = dir.join("llm.sh");
+    fs::write(
+        &script,
+        format!(
+            r#"#!/bin/sh
+if [ -f '{dir}/first-prompt.txt' ]; then
+  cat > '{dir}/retry-prompt.txt'
+  echo 'feat: Add parser'
+else
+  cat > '{dir}/first-prompt.txt'
+  echo 'Added parser'
+fi
+"#,
+            dir = dir.display()
+        ),
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    repo.write_test_config(&format!(
+        r#"[commit-generation]
+command = "{}"
+validate = "conventional"
+"#,
+        script.display()
+    ));
+
+    snapshot_step_commit_with_env("step_commit_validate_retries", &repo, &[], None, &[]);
+
+    let retry_prompt = fs::read_to_string(dir.join("retry-prompt.txt")).unwrap();
+    assert!(
+        retry_prompt.contains("Your previous message was rejected because the first line must look like `type(scope): subject`:\n\nAdded parser"),
+        "{retry_prompt}"
+    );
+}
+
+#[test]
+fn test_step_commit_validate_gives_up() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    fs::write(repo.root_path().join("parser.rs"), "fn parse() {}").unwrap();
+    repo.write_test_config(
+        r#"[commit-generation]
+command = "sh"
+args = ["-c", "cat >/dev/null && echo 'Added parser'"]
+validate = '[A-Z]+-[0-9]+: .+'
+max-attempts = 2
+"#,
+    );
+
+    snapshot_step_commit_with_env("step_commit_validate_gives_up", &repo, &[], None, &[]);
+}
+
+#[test]
+fn test_step_commit_truncates_large_diff() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // A lockfile bump and a large source change, over a 2KB budget
+    let lockfile: String = (0..500)
+        .map(|i| format!("dep-{i} = \"1.0.{i}\"\n"))
+        .collect();
+    fs::write(repo.root_path().join("Cargo.lock"), lockfile).unwrap();
+    let source: String = (0..200).map(|i| format!("fn f{i}() {{}}\n")).collect();
+    fs::write(repo.root_path().join("lib.rs"), source).unwrap();
+    fs::write(repo.root_path().join("notes.txt"), "short note\n").unwrap();
+
+    // The mock LLM saves the prompt it gets
+    let prompt_path = repo.root_path().parent().unwrap().join("prompt.txt");
+    repo.write_test_config(&format!(
+        r#"[commit-generation]
+command = "sh"
+args = ["-c", "cat > '{}' && echo 'chore: bump dependencies'"]
+max-diff-size = "2KB"
+template = """{{% if truncated %}}TRUNCATED
+{{{{ diff_stat }}}}
+FILES: {{{{ changed_files | join(", ") }}}}
+{{% endif %}}{{{{ git_diff }}}}"""
+"#,
+        prompt_path.display()
+    ));
+
+    let output = make_snapshot_cmd(&repo, "step", &["commit"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let prompt = fs::read_to_string(&prompt_path).unwrap();
+    assert!(prompt.starts_with("TRUNCATED\n"), "{prompt}");
+    assert!(prompt.contains("Cargo.lock | 500 +++"), "{prompt}");
+    assert!(
+        prompt.contains("FILES: Cargo.lock, lib.rs, notes.txt"),
+        "{prompt}"
+    );
+    // The lockfile is only in the summary; small files stay whole, large ones are cut
+    assert!(!prompt.contains("dep-1 = "), "{prompt}");
+    assert!(prompt.contains("+short note\n"), "{prompt}");
+    assert!(prompt.contains("+fn f0() {}"), "{prompt}");
+    assert!(!prompt.contains("+fn f199() {}"), "{prompt}");
+    assert!(prompt.contains("more lines]"), "{prompt}");
+    assert!(prompt.len() < 2500, "{}", prompt.len());
+}
+
+#[test]
+fn test_step_commit_edit_not_interactive() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    // Without a TTY the review can't prompt; the message is kept for the next review
+    snapshot_step_commit_with_env(
+        "step_commit_edit_not_interactive",
+        &repo,
+        &["--edit"],
+        None,
+        &[
+            ("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo"),
+            ("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: add file"),
+        ],
+    );
+    assert_eq!(
+        fs::read_to_string(repo.root_path().join(".git/WT_COMMIT_EDITMSG")).unwrap(),
+        "feat: add file\n"
+    );
+}
+
+#[test]
+fn test_step_commit_edit_ignores_message_saved_at_other_head() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let message_path = repo.root_path().join(".git/WT_COMMIT_EDITMSG");
+    let review = |message: &str| {
+        let output = make_snapshot_cmd(&repo, "step", &["commit", "--edit"], None)
+            .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+            .env("WORKTRUNK_COMMIT_GENERATION__ARGS", message)
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{output:?}");
+    };
+
+    fs::write(repo.root_path().join("file1.txt"), "content 1").unwrap();
+    review("feat: add file");
+    assert_eq!(
+        fs::read_to_string(&message_path).unwrap(),
+        "feat: add file\n"
+    );
+
+    // Once HEAD moves, the saved message no longer describes the changes
+    repo.commit("Commit the file by hand");
+    fs::write(repo.root_path().join("file2.txt"), "content 2").unwrap();
+    review("feat: add another file");
+    assert_eq!(
+        fs::read_to_string(&message_path).unwrap(),
+        "feat: add another file\n"
+    );
+    // A squash review keeps its own message
+    assert!(!repo.root_path().join(".git/WT_SQUASH_EDITMSG").exists());
+}
+
 // =============================================================================
 // Error message snapshot tests
 // =============================================================================
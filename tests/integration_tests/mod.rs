@@ -10,6 +10,9 @@ pub mod approval_ui;
 pub mod approvals;
 pub mod bare_repository;
 pub mod column_alignment_verification;
+#[cfg(feature = "llm-http")]
+pub mod commit_generation_http;
+pub mod commit_review_pty;
 pub mod completion;
 pub mod completion_validation;
 pub mod config_cache;
@@ -19,11 +22,13 @@ pub mod config_show_theme;
 pub mod config_var;
 pub mod configure_shell;
 pub mod default_branch;
+pub mod describe;
 pub mod directives;
 pub mod e2e_shell;
 pub mod e2e_shell_post_start;
 pub mod git_error_display;
 pub mod help;
+pub mod hook_logs;
 pub mod hook_show;
 pub mod init;
 pub mod internal_flag;
@@ -41,6 +46,7 @@ pub mod security;
 pub mod select;
 pub mod shell_wrapper;
 pub mod spacing_edge_cases;
+pub mod sparse;
 pub mod statusline;
 pub mod switch;
 pub mod user_hooks;
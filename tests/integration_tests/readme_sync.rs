@@ -78,10 +78,20 @@ static SQUASH_TEMPLATE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// Regex to find DEFAULT_PR_TEMPLATE marker
+static PR_TEMPLATE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?s)(# <!-- DEFAULT_PR_TEMPLATE_START -->\n).*?(# <!-- DEFAULT_PR_TEMPLATE_END -->)",
+    )
+    .unwrap()
+});
+
 /// Regex to extract Rust raw string constants (single pound)
 static RUST_RAW_STRING_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r##"(?s)const (DEFAULT_TEMPLATE|DEFAULT_SQUASH_TEMPLATE): &str = r#"(.*?)"#;"##)
-        .unwrap()
+    Regex::new(
+        r##"(?s)const (DEFAULT_TEMPLATE|DEFAULT_SQUASH_TEMPLATE|DEFAULT_PR_TEMPLATE): &str = r#"(.*?)"#;"##,
+    )
+    .unwrap()
 });
 
 /// Regex to convert Zola internal links to full URLs
@@ -791,6 +801,10 @@ fn test_config_example_templates_are_in_sync() {
         templates.contains_key("DEFAULT_SQUASH_TEMPLATE"),
         "DEFAULT_SQUASH_TEMPLATE not found in src/llm.rs"
     );
+    assert!(
+        templates.contains_key("DEFAULT_PR_TEMPLATE"),
+        "DEFAULT_PR_TEMPLATE not found in src/llm.rs"
+    );
 
     let mut updated_content = config_content.clone();
     let mut updated_count = 0;
@@ -827,6 +841,7 @@ fn test_config_example_templates_are_in_sync() {
         "DEFAULT_SQUASH_TEMPLATE",
         "squash-template",
     );
+    replace_template(&PR_TEMPLATE_PATTERN, "DEFAULT_PR_TEMPLATE", "pr-template");
 
     if updated_count > 0 {
         fs::write(&config_path, &updated_content).unwrap();
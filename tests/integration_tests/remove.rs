@@ -1173,3 +1173,169 @@ approved-commands = ["echo 'branch={{{{ branch }}}}' > {branch_path}"]
         "{{ branch }} should expand to empty string for detached HEAD worktrees"
     );
 }
+
+/// Test that removal stops background commands still running in the worktree.
+#[cfg(unix)]
+#[test]
+fn test_remove_stops_background_processes() {
+    use crate::common::{make_snapshot_cmd, wait_for_file_lines};
+
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-start]
+server = "sleep 60"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let job = repo
+        .root_path()
+        .join(".git/wt-logs/feature-user-post-start-server.job");
+    wait_for_file_lines(&job, 2, Duration::from_secs(5));
+    let pid = std::fs::read_to_string(&job)
+        .unwrap()
+        .lines()
+        .find_map(|line| line.strip_prefix("pid=").map(str::to_string))
+        .unwrap();
+
+    snapshot_remove(
+        "remove_stops_background_processes",
+        &repo,
+        &["--no-background", "feature"],
+        None,
+    );
+
+    let status = Command::new("ps")
+        .args(["-o", "stat=", "-p", &pid])
+        .output()
+        .unwrap();
+    let stat = String::from_utf8_lossy(&status.stdout);
+    assert!(
+        stat.trim().is_empty() || stat.trim().starts_with('Z'),
+        "background command should have been stopped (state {stat})"
+    );
+}
+
+/// Test that removal only lists processes wt didn't start, unless `--stop-processes` is given.
+#[cfg(unix)]
+#[test]
+fn test_remove_leaves_other_processes_running() {
+    use crate::common::make_snapshot_cmd;
+
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature = repo.add_worktree("feature");
+    let other = repo.add_worktree("other");
+
+    let mut editor = Command::new("sleep")
+        .arg("60")
+        .current_dir(&feature)
+        .spawn()
+        .unwrap();
+    let output = make_snapshot_cmd(&repo, "remove", &["--no-background", "feature"], None)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Processes still running in worktree")
+            && stderr.contains(&format!("sleep ({})", editor.id())),
+        "should list the process: {stderr}"
+    );
+    assert!(
+        editor.try_wait().unwrap().is_none(),
+        "process wt didn't start should be left running"
+    );
+    editor.kill().unwrap();
+    editor.wait().unwrap();
+
+    let mut editor = Command::new("sleep")
+        .arg("60")
+        .current_dir(&other)
+        .spawn()
+        .unwrap();
+    let output = make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--no-background", "--stop-processes", "other"],
+        None,
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Stopped processes running in worktree"),
+        "{stderr}"
+    );
+    let status = editor.wait().unwrap();
+    assert!(
+        !status.success(),
+        "--stop-processes should stop the process"
+    );
+}
+
+/// Test that `--no-stop-jobs` leaves background commands running.
+#[cfg(unix)]
+#[test]
+fn test_remove_no_stop_jobs() {
+    use crate::common::{make_snapshot_cmd, wait_for_file_lines};
+
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-start]
+server = "sleep 60"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let job = repo
+        .root_path()
+        .join(".git/wt-logs/feature-user-post-start-server.job");
+    wait_for_file_lines(&job, 2, Duration::from_secs(5));
+    let pgid = std::fs::read_to_string(&job)
+        .unwrap()
+        .lines()
+        .find_map(|line| line.strip_prefix("pgid=").map(|p| p.trim().to_string()))
+        .unwrap();
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--no-background", "--no-stop-jobs", "feature"],
+        None,
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(!stderr.contains("Stopped"), "{stderr}");
+
+    let alive = Command::new("kill")
+        .args(["-0", "--", &format!("-{pgid}")])
+        .status()
+        .unwrap();
+    assert!(
+        alive.success(),
+        "background command should still be running"
+    );
+    let _ = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{pgid}")])
+        .status();
+}
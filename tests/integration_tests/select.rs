@@ -610,3 +610,216 @@ fn test_new_feature() {
     let normalized = normalize_output(&screen);
     assert_snapshot!("select_preview_main_diff", normalized);
 }
+
+/// Tab marks several worktrees; alt-r removes them all and prints a summary
+#[test]
+fn test_select_remove_marked_worktrees() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_one = repo.add_worktree("feature-one");
+    let feature_two = repo.add_worktree("feature-two");
+
+    let env_vars = repo.test_env_vars();
+    let (raw_output, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[
+            ("feature", None),
+            ("\t", None), // Mark both matches
+            ("\t", None),
+            ("\x1br", Some("Remove summary (2 selected)")), // alt-r
+        ],
+    );
+    assert_eq!(exit_code, 0);
+
+    let screen = render_terminal_screen(&raw_output);
+    assert!(screen.contains("feature-one removed"), "{screen}");
+    assert!(screen.contains("feature-two removed"), "{screen}");
+
+    // Removal runs in the background
+    let start = Instant::now();
+    while (feature_one.exists() || feature_two.exists()) && start.elapsed() < READY_TIMEOUT {
+        std::thread::sleep(POLL_INTERVAL);
+    }
+    assert!(!feature_one.exists());
+    assert!(!feature_two.exists());
+    assert!(repo.root_path().exists());
+}
+
+/// alt-c creates a worktree named after the query
+#[test]
+fn test_select_create_from_query() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.add_worktree("feature-one");
+
+    let env_vars = repo.test_env_vars();
+    let (raw_output, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[("brand-new", None), ("\x1bc", Some("Created new worktree"))], // alt-c
+    );
+    assert_eq!(exit_code, 0);
+
+    let screen = render_terminal_screen(&raw_output);
+    assert!(screen.contains("brand-new"), "{screen}");
+
+    let output = repo
+        .git_command(&["worktree", "list", "--porcelain"])
+        .output()
+        .unwrap();
+    let worktrees = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        worktrees.contains("branch refs/heads/brand-new"),
+        "{worktrees}"
+    );
+}
+
+/// Enter with no matching worktree exits without creating one
+#[test]
+fn test_select_enter_without_matches() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.add_worktree("feature-one");
+
+    let env_vars = repo.test_env_vars();
+    let (_, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[("brand-new", None), ("\r", None)],
+    );
+    assert_eq!(exit_code, 0);
+
+    let output = repo
+        .git_command(&["branch", "--list", "brand-new"])
+        .output()
+        .unwrap();
+    assert!(output.stdout.is_empty(), "no branch should be created");
+    assert!(
+        !repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join("repo.brand-new")
+            .exists()
+    );
+}
+
+/// Tab 4 shows each CI check and the PR link (from the CI cache `wt list` shares)
+#[test]
+fn test_select_preview_panel_ci() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_path = repo.add_worktree("feature");
+
+    let head = String::from_utf8(
+        repo.git_command(&["-C", feature_path.to_str().unwrap(), "rev-parse", "HEAD"])
+            .output()
+            .unwrap()
+            .stdout,
+    )
+    .unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let cache_json = format!(
+        r#"{{"status":{{"ci_status":"failed","source":"pullrequest","is_stale":false,"url":"https://github.com/owner/repo/pull/7","checks":[{{"name":"build","status":"passed"}},{{"name":"unit-tests","status":"failed"}}]}},"checked_at":{now},"head":"{}"}}"#,
+        head.trim()
+    );
+    repo.git_command(&["config", "worktrunk.ci.feature", &cache_json])
+        .output()
+        .unwrap();
+
+    let env_vars = repo.test_env_vars();
+    let (raw_output, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[("feature", None), ("4", Some("unit-tests")), ("\x1b", None)],
+    );
+    assert_valid_abort_exit_code(exit_code);
+
+    let screen = render_terminal_screen(&raw_output);
+    assert!(screen.contains("CI failed (pull request)"), "{screen}");
+    assert!(
+        screen.contains("https://github.com/owner/repo/pull/7"),
+        "{screen}"
+    );
+    assert!(screen.contains("build"), "{screen}");
+}
+
+/// Tab 5 shows the end of the branch's post-start logs
+#[test]
+fn test_select_preview_panel_logs() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.add_worktree("feature");
+
+    let log_dir = repo.root_path().join(".git/wt-logs");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    let log: String = (1..=50).map(|i| format!("install step {i}\n")).collect();
+    std::fs::write(log_dir.join("feature-project-post-start-install.log"), log).unwrap();
+    std::fs::write(log_dir.join("feature-remove.log"), "not a post-start log\n").unwrap();
+
+    let env_vars = repo.test_env_vars();
+    let (raw_output, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[
+            ("feature", None),
+            ("5", Some("install step 50")),
+            ("\x1b", None),
+        ],
+    );
+    assert_valid_abort_exit_code(exit_code);
+
+    let screen = render_terminal_screen(&raw_output);
+    assert!(screen.contains("project-post-start-install"), "{screen}");
+    // Only the tail fits
+    assert!(!screen.contains("install step 1\n"), "{screen}");
+    assert!(!screen.contains("not a post-start log"), "{screen}");
+}
+
+/// `[select]` config sets the first preview tab and rebinds keys
+#[test]
+fn test_select_configured() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.add_worktree("feature");
+    repo.write_test_config(
+        r#"
+[select]
+preview = "log"
+layout = "right"
+
+[select.keys]
+remove = "ctrl-x"
+"#,
+    );
+
+    let env_vars = repo.test_env_vars();
+    let (raw_output, exit_code) = exec_in_pty_with_input_expectations(
+        get_cargo_bin("wt").to_str().unwrap(),
+        &["select"],
+        repo.root_path(),
+        &env_vars,
+        &[
+            ("feature", Some("* ")), // Log tab without pressing 2
+            ("\x1b", None),
+        ],
+    );
+    assert_valid_abort_exit_code(exit_code);
+
+    let screen = render_terminal_screen(&raw_output);
+    assert!(screen.contains("ctrl-x: remove"), "{screen}");
+}
@@ -0,0 +1,311 @@
+use crate::common::{TestRepo, make_snapshot_cmd, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use std::path::{Path, PathBuf};
+
+/// Repo with a few top-level directories, as in a monorepo, and a committed
+/// project config with `sparse`
+fn setup_monorepo(project_config: &str) -> TestRepo {
+    let repo = TestRepo::new();
+    for path in [
+        "services/api/main.rs",
+        "services/web/index.js",
+        "libs/common/lib.rs",
+    ] {
+        let path = repo.root_path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "content").unwrap();
+    }
+    repo.write_project_config(project_config);
+    repo.commit("Initial commit");
+    repo
+}
+
+/// Create a worktree with `wt switch --create`, returning its path
+fn create_worktree(repo: &TestRepo, branch: &str) -> PathBuf {
+    let output = make_snapshot_cmd(repo, "switch", &["--create", branch], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    repo.root_path()
+        .parent()
+        .unwrap()
+        .join(format!("repo.{}", branch.replace('/', "-")))
+}
+
+fn snapshot_sparse(test_name: &str, repo: &TestRepo, args: &[&str], cwd: Option<&Path>) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut step_args = vec!["sparse"];
+        step_args.extend_from_slice(args);
+        let mut cmd = make_snapshot_cmd(repo, "step", &step_args, cwd);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+/// Top-level entries of a worktree, sorted, without `.git`
+fn checked_out(path: &Path) -> Vec<String> {
+    let mut entries: Vec<String> = std::fs::read_dir(path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name != ".git")
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn sparse_list(path: &Path) -> Vec<String> {
+    let output = std::process::Command::new("git")
+        .args(["sparse-checkout", "list"])
+        .current_dir(path)
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_switch_create_sparse() {
+    let repo = setup_monorepo(
+        r#"[sparse]
+paths = ["services/api"]
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None);
+        assert_cmd_snapshot!("switch_create_sparse", cmd);
+    });
+
+    let worktree = repo.root_path().parent().unwrap().join("repo.feature");
+    // Top-level files are always checked out
+    assert_eq!(checked_out(&worktree), [".config", "file.txt", "services"]);
+    assert_eq!(checked_out(&worktree.join("services")), ["api"]);
+    assert_eq!(sparse_list(&worktree), [".config", "services/api"]);
+}
+
+/// A failed checkout removes the half-created worktree and its new branch
+#[cfg(unix)]
+#[test]
+fn test_switch_create_sparse_checkout_fails() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let repo = setup_monorepo(
+        r#"[sparse]
+paths = ["services/api"]
+"#,
+    );
+    // post-checkout's exit status becomes `git checkout`'s
+    let hook = repo.root_path().join(".git/hooks/post-checkout");
+    std::fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+    std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let worktree = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(!worktree.exists(), "worktree should be rolled back");
+    let branch = repo
+        .git_command(&["rev-parse", "--verify", "--quiet", "refs/heads/feature"])
+        .output()
+        .unwrap();
+    assert!(!branch.status.success(), "new branch should be deleted");
+}
+
+/// Switching to a remote branch creates the local tracking branch, which the
+/// rollback deletes as well
+#[cfg(unix)]
+#[test]
+fn test_switch_remote_sparse_checkout_fails() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut repo = setup_monorepo(
+        r#"[sparse]
+paths = ["services/api"]
+"#,
+    );
+    repo.setup_remote("main");
+    repo.git_command(&["push", "-q", "origin", "HEAD:refs/heads/remote-feature"])
+        .output()
+        .unwrap();
+    repo.git_command(&["fetch", "-q", "origin"])
+        .output()
+        .unwrap();
+    let hook = repo.root_path().join(".git/hooks/post-checkout");
+    std::fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+    std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let output = make_snapshot_cmd(&repo, "switch", &["origin/remote-feature"], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let worktree = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("repo.remote-feature");
+    assert!(!worktree.exists(), "worktree should be rolled back");
+    let branch = repo
+        .git_command(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            "refs/heads/remote-feature",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        !branch.status.success(),
+        "tracking branch should be deleted"
+    );
+}
+
+#[test]
+fn test_switch_create_sparse_branch_pattern() {
+    let repo = setup_monorepo(
+        r#"[sparse]
+paths = ["services/api"]
+
+[sparse.branches]
+"web/*" = ["services/web", "libs/common"]
+"release-*" = []
+"#,
+    );
+
+    let worktree = create_worktree(&repo, "web/login");
+    assert_eq!(
+        checked_out(&worktree),
+        [".config", "file.txt", "libs", "services"]
+    );
+    assert_eq!(checked_out(&worktree.join("services")), ["web"]);
+
+    // An empty list is a full checkout
+    let worktree = create_worktree(&repo, "release-1.0");
+    assert_eq!(
+        checked_out(&worktree),
+        [".config", "file.txt", "libs", "services"]
+    );
+}
+
+#[test]
+fn test_switch_create_sparse_user_config() {
+    let repo = setup_monorepo(
+        r#"[sparse]
+paths = ["services/api"]
+"#,
+    );
+    // The user's per-project patterns take precedence
+    let project_id = repo.root_path().file_name().unwrap().to_str().unwrap();
+    repo.write_test_config(&format!(
+        r#"[projects."{project_id}".sparse]
+paths = ["libs/common"]
+"#
+    ));
+
+    let worktree = create_worktree(&repo, "feature");
+    assert_eq!(checked_out(&worktree), [".config", "file.txt", "libs"]);
+}
+
+#[test]
+fn test_step_sparse() {
+    let repo = setup_monorepo(
+        r#"[sparse]
+paths = ["services/api"]
+"#,
+    );
+    let worktree = create_worktree(&repo, "feature");
+
+    snapshot_sparse("step_sparse_show", &repo, &[], Some(&worktree));
+
+    snapshot_sparse(
+        "step_sparse_add",
+        &repo,
+        &["add", "libs/common"],
+        Some(&worktree),
+    );
+    assert_eq!(
+        checked_out(&worktree),
+        [".config", "file.txt", "libs", "services"]
+    );
+
+    snapshot_sparse(
+        "step_sparse_remove",
+        &repo,
+        &["remove", "services/api"],
+        Some(&worktree),
+    );
+    assert_eq!(checked_out(&worktree), [".config", "file.txt", "libs"]);
+
+    snapshot_sparse(
+        "step_sparse_remove_last",
+        &repo,
+        &["remove", "libs/common"],
+        Some(&worktree),
+    );
+
+    snapshot_sparse("step_sparse_reset", &repo, &["reset"], Some(&worktree));
+    assert_eq!(sparse_list(&worktree), [".config", "services/api"]);
+
+    snapshot_sparse("step_sparse_disable", &repo, &["disable"], Some(&worktree));
+    assert_eq!(
+        checked_out(&worktree),
+        [".config", "file.txt", "libs", "services"]
+    );
+
+    snapshot_sparse("step_sparse_show_full", &repo, &[], Some(&worktree));
+}
+
+#[test]
+fn test_step_sparse_add_full_checkout() {
+    let repo = setup_monorepo("");
+
+    snapshot_sparse("step_sparse_add_full", &repo, &["add", "libs"], None);
+}
+
+#[test]
+fn test_list_sparse() {
+    let mut repo = setup_monorepo("");
+    repo.add_worktree("full");
+    let sparse = repo.add_worktree("sparse");
+    repo.git_command(&["sparse-checkout", "set", "--cone", "libs/common"])
+        .current_dir(&sparse)
+        .output()
+        .unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+        assert_cmd_snapshot!("list_sparse", cmd);
+    });
+
+    let output = make_snapshot_cmd(&repo, "list", &["--format=json"], None)
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let sparse_flags: Vec<(String, bool)> = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| {
+            (
+                item["branch"].as_str().unwrap().to_string(),
+                item["worktree"]["sparse"].as_bool().unwrap_or(false),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sparse_flags,
+        [
+            ("main".to_string(), false),
+            ("full".to_string(), false),
+            ("sparse".to_string(), true)
+        ]
+    );
+}
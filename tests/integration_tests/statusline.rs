@@ -206,6 +206,188 @@ fn test_statusline_claude_code_with_model() {
     });
 }
 
+#[test]
+fn test_statusline_claude_code_omits_branch_in_worktree() {
+    let repo = setup_repo_with_commits_ahead();
+    let feature_path = repo.worktree_path("feature");
+
+    let json = format!(
+        r#"{{"workspace": {{"current_dir": "{}"}}}}"#,
+        feature_path.display()
+    );
+
+    // The directory already shows the branch, so it isn't repeated
+    let output = run_statusline_from_dir(&repo, &["--claude-code"], Some(&json), feature_path);
+    claude_code_snapshot_settings(&repo).bind(|| {
+        assert_snapshot!(output, @"[PATH].feature  [2m↑[22m  [32m↑2[0m  ^[32m+2[0m");
+    });
+}
+
+#[test]
+fn test_statusline_claude_code_custom_worktree_path() {
+    let repo = setup_basic_repo();
+    repo.write_test_config(r#"worktree-path = ".worktrees/{{ branch }}""#);
+
+    let nested = repo.root_path().join(".worktrees/feature");
+    repo.git_command(&["worktree", "add", "-b", "feature", nested.to_str().unwrap()])
+        .output()
+        .unwrap();
+    let nested = nested.canonicalize().unwrap();
+
+    let json = format!(
+        r#"{{"workspace": {{"current_dir": "{}"}}}}"#,
+        nested.display()
+    );
+
+    // `.worktrees/feature` is where the template puts `feature`, so the branch is omitted
+    let output = run_statusline_from_dir(&repo, &["--claude-code"], Some(&json), &nested);
+    let mut settings = claude_code_snapshot_settings(&repo);
+    // The nested path abbreviates the repo directory too
+    settings.add_filter(r"(?m)^.*/feature", "[PATH]/feature");
+    settings.bind(|| {
+        assert_snapshot!(output, @"[PATH]/feature  [2m_[22m");
+    });
+}
+
+#[test]
+fn test_statusline_template() {
+    let repo = setup_repo_with_commits_ahead();
+    repo.write_test_config(
+        r#"
+[statusline]
+template = "{{ branch }} +{{ ahead }}/-{{ behind }}{% if ci %} {{ ci }}{% endif %}{% if model %} [{{ model }}]{% endif %}"
+"#,
+    );
+    let feature_path = repo.worktree_path("feature");
+
+    let output = run_statusline_from_dir(&repo, &[], None, feature_path);
+    assert_snapshot!(output, @"feature +2/-0");
+
+    let json = format!(
+        r#"{{"workspace": {{"current_dir": "{}"}}, "model": {{"display_name": "Haiku"}}}}"#,
+        feature_path.display()
+    );
+    let output = run_statusline_from_dir(&repo, &["--claude-code"], Some(&json), feature_path);
+    assert_snapshot!(output, @"[0m feature +2/-0 [Haiku]");
+}
+
+// --- Agent Context Tests ---
+
+#[test]
+fn test_statusline_context_from_config() {
+    let repo = setup_basic_repo();
+    repo.write_test_config(
+        r#"
+[statusline.context.my-agent]
+dir = "$.session.roots[0].path"
+model = "session.agent.model"
+"#,
+    );
+
+    let json = format!(
+        r#"{{"session": {{"roots": [{{"path": "{}"}}], "agent": {{"model": "Sonnet"}}}}}}"#,
+        repo.root_path().display()
+    );
+
+    let output = run_statusline(&repo, &["--context", "my-agent"], Some(&json));
+    claude_code_snapshot_settings(&repo).bind(|| {
+        assert_snapshot!(output, @"[PATH]  main  \x1b[2m^\x1b[22m  | Sonnet");
+    });
+}
+
+#[test]
+fn test_statusline_context_unknown() {
+    let repo = setup_basic_repo();
+
+    let output = run_statusline(&repo, &["--context", "nope"], Some("{}"));
+    assert_snapshot!(output, @"❌ [31mUnknown statusline context: nope. Available: claude-code[39m");
+}
+
+// --- Cached Mode Tests ---
+
+#[test]
+fn test_statusline_cached_reuses_last_run() {
+    let repo = setup_repo_with_commits_ahead();
+    let feature_path = repo.worktree_path("feature");
+
+    // A plain render doesn't write the cache; the background refresh does
+    run_statusline_from_dir(&repo, &[], None, feature_path);
+    assert!(!repo.root_path().join(".git/wt-cache/statusline").exists());
+    let live = run_statusline_from_dir(&repo, &["--refresh"], None, feature_path);
+    // Uncommitted changes don't show until the snapshot is refreshed
+    std::fs::write(feature_path.join("new.txt"), "new").unwrap();
+    let cached = run_statusline_from_dir(&repo, &["--cached"], None, feature_path);
+    assert_eq!(cached, live);
+    assert_snapshot!(cached, @"feature  \x1b[2m↑\x1b[22m  \x1b[32m↑2\x1b[0m  ^\x1b[32m+2\x1b[0m");
+}
+
+#[test]
+fn test_statusline_cached_refreshes_in_background() {
+    let repo = setup_basic_repo();
+
+    // Nothing cached yet: print nothing, and refresh in the background
+    let output = run_statusline(&repo, &["--cached"], None);
+    assert_eq!(output, "");
+
+    let cached = (0..300).any(|_| {
+        let found = repo
+            .root_path()
+            .join(".git/wt-cache/statusline/main.json")
+            .exists();
+        if !found {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        found
+    });
+    assert!(cached, "background refresh should cache the statusline");
+
+    let output = run_statusline(&repo, &["--cached"], None);
+    assert_snapshot!(output, @"main  \x1b[2m^\x1b[22m");
+}
+
+#[test]
+fn test_statusline_cache_prunes_deleted_branches() {
+    let repo = setup_basic_repo();
+    let cache_dir = repo.root_path().join(".git/wt-cache/statusline");
+
+    repo.git_command(&["checkout", "-q", "-b", "feature/old"])
+        .output()
+        .unwrap();
+    run_statusline(&repo, &["--refresh"], None);
+    assert!(cache_dir.join("feature-2Fold.json").exists());
+
+    repo.git_command(&["checkout", "-q", "main"])
+        .output()
+        .unwrap();
+    repo.git_command(&["branch", "-q", "-D", "feature/old"])
+        .output()
+        .unwrap();
+    run_statusline(&repo, &["--refresh"], None);
+    assert!(cache_dir.join("main.json").exists());
+    assert!(
+        !cache_dir.join("feature-2Fold.json").exists(),
+        "snapshot of a deleted branch should be pruned"
+    );
+}
+
+#[test]
+fn test_statusline_format_json() {
+    let repo = setup_repo_with_commits_ahead();
+    let feature_path = repo.worktree_path("feature");
+
+    for args in [
+        &["--format", "json"][..],
+        &["--refresh", "--format", "json"],
+        &["--cached", "--format", "json"],
+    ] {
+        let output = run_statusline_from_dir(&repo, args, None, feature_path);
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["branch"], "feature");
+        assert_eq!(json["main"]["ahead"], 2);
+        assert_eq!(json["symbols"], "↑");
+    }
+}
+
 // --- Directive Mode Tests ---
 
 #[test]
@@ -45,6 +45,7 @@ fn snapshot_switch_with_home(
     settings.bind(|| {
         let mut cmd = make_snapshot_cmd_with_global_flags(repo, "switch", args, None, global_flags);
         cmd.env("HOME", home);
+        repo.configure_mock_commands(&mut cmd);
         assert_cmd_snapshot!(test_name, cmd);
     });
 }
@@ -693,3 +694,313 @@ fn test_switch_missing_argument_shows_hints() {
     // Run switch with no arguments - should show clap error plus hints
     snapshot_switch("switch_missing_argument_hints", &repo, &[]);
 }
+
+// ============================================================================
+// pr:<n> / mr:<n>
+// ============================================================================
+
+/// Mock `gh`/`glab` that prints `json` for `pr view` / `mr view` and fails otherwise
+fn mock_review_tool(repo: &mut TestRepo, tool: &str, json: &str) {
+    let subcommand = if tool == "gh" { "pr" } else { "mr" };
+    repo.setup_mock_command(
+        tool,
+        &format!(
+            r#"#!/bin/sh
+if [ "$1" = "{subcommand}" ] && [ "$2" = "view" ]; then
+    echo '{json}'
+    exit 0
+fi
+echo "unexpected: $*" >&2
+exit 1
+"#
+        ),
+    );
+}
+
+/// Push a commit to `refspec` on origin without keeping a local branch
+fn push_remote_commit(repo: &TestRepo, refspec: &str) {
+    repo.git_command(&["checkout", "-q", "-b", "tmp-push"])
+        .output()
+        .unwrap();
+    std::fs::write(repo.root_path().join("pr.txt"), refspec).unwrap();
+    repo.git_command(&["add", "pr.txt"]).output().unwrap();
+    repo.git_command(&["commit", "-q", "-m", "PR commit"])
+        .output()
+        .unwrap();
+    let output = repo
+        .git_command(&["push", "-q", "origin", &format!("HEAD:{refspec}")])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    repo.git_command(&["checkout", "-q", "main"])
+        .output()
+        .unwrap();
+    repo.git_command(&["branch", "-q", "-D", "tmp-push"])
+        .output()
+        .unwrap();
+}
+
+fn git_output(repo: &TestRepo, args: &[&str]) -> String {
+    let output = repo.git_command(args).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn test_switch_pr_same_repo() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/heads/fix-parser");
+    mock_review_tool(
+        &mut repo,
+        "gh",
+        r#"{"headRefName":"fix-parser","headRepositoryOwner":{"login":"owner"},"isCrossRepository":false,"url":"https://github.com/owner/repo/pull/101"}"#,
+    );
+
+    snapshot_switch("switch_pr_same_repo", &repo, &["pr:101"]);
+
+    assert_eq!(
+        git_output(
+            &repo,
+            &["rev-parse", "--abbrev-ref", "fix-parser@{upstream}"]
+        ),
+        "origin/fix-parser"
+    );
+}
+
+#[test]
+fn test_switch_pr_fork_without_remote() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/pull/102/head");
+    mock_review_tool(
+        &mut repo,
+        "gh",
+        r#"{"headRefName":"patch-1","headRepositoryOwner":{"login":"contributor"},"isCrossRepository":true,"url":"https://github.com/owner/repo/pull/102"}"#,
+    );
+
+    snapshot_switch("switch_pr_fork_without_remote", &repo, &["pr:102"]);
+
+    // `git pull` follows the PR's head ref
+    assert_eq!(
+        git_output(&repo, &["config", "branch.contributor/patch-1.merge"]),
+        "refs/pull/102/head"
+    );
+    assert_eq!(
+        git_output(&repo, &["config", "branch.contributor/patch-1.remote"]),
+        "origin"
+    );
+}
+
+#[test]
+fn test_switch_pr_fork_remote() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+
+    // A fork remote with a GitHub URL, redirected to a local bare repository
+    let fork_path = repo.root_path().parent().unwrap().join("fork.git");
+    repo.git_command(&["init", "-q", "--bare", fork_path.to_str().unwrap()])
+        .output()
+        .unwrap();
+    let fork_url = "https://github.com/contributor/repo.git";
+    repo.git_command(&["remote", "add", "contributor", fork_url])
+        .output()
+        .unwrap();
+    repo.git_command(&[
+        "config",
+        &format!("url.{}.insteadOf", fork_path.display()),
+        fork_url,
+    ])
+    .output()
+    .unwrap();
+    repo.git_command(&["push", "-q", "contributor", "main:refs/heads/patch-2"])
+        .output()
+        .unwrap();
+
+    mock_review_tool(
+        &mut repo,
+        "gh",
+        r#"{"headRefName":"patch-2","headRepositoryOwner":{"login":"Contributor"},"isCrossRepository":true,"url":"https://github.com/owner/repo/pull/103"}"#,
+    );
+
+    snapshot_switch("switch_pr_fork_remote", &repo, &["pr:103"]);
+
+    assert_eq!(
+        git_output(
+            &repo,
+            &[
+                "rev-parse",
+                "--abbrev-ref",
+                "Contributor/patch-2@{upstream}"
+            ]
+        ),
+        "contributor/patch-2"
+    );
+}
+
+#[test]
+fn test_switch_mr_same_project() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/heads/docs-update");
+    mock_review_tool(
+        &mut repo,
+        "glab",
+        r#"{"iid":7,"source_branch":"docs-update","source_project_id":1,"target_project_id":1,"author":{"username":"someone"}}"#,
+    );
+
+    snapshot_switch("switch_mr_same_project", &repo, &["mr:7"]);
+
+    assert_eq!(
+        git_output(
+            &repo,
+            &["rev-parse", "--abbrev-ref", "docs-update@{upstream}"]
+        ),
+        "origin/docs-update"
+    );
+}
+
+#[test]
+fn test_switch_pr_not_found() {
+    let mut repo = setup_switch_repo();
+    repo.setup_mock_command(
+        "gh",
+        "#!/bin/sh\necho 'GraphQL: Could not resolve to a PullRequest with the number of 999.' >&2\nexit 1\n",
+    );
+
+    snapshot_switch("switch_pr_not_found", &repo, &["pr:999"]);
+}
+
+#[test]
+fn test_switch_pr_with_create() {
+    let repo = setup_switch_repo();
+
+    snapshot_switch("switch_pr_with_create", &repo, &["--create", "pr:101"]);
+}
+
+// ============================================================================
+// Remote branches
+// ============================================================================
+
+#[test]
+fn test_switch_remote_ref() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/heads/remote-feature");
+
+    snapshot_switch("switch_remote_ref", &repo, &["origin/remote-feature"]);
+
+    assert_eq!(
+        git_output(
+            &repo,
+            &["rev-parse", "--abbrev-ref", "remote-feature@{upstream}"]
+        ),
+        "origin/remote-feature"
+    );
+}
+
+#[test]
+fn test_switch_remote_ref_existing_local_branch() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/heads/remote-feature");
+    // A local branch of the same name, not tracking the remote
+    repo.git_command(&["branch", "remote-feature", "main"])
+        .output()
+        .unwrap();
+
+    snapshot_switch(
+        "switch_remote_ref_existing_local",
+        &repo,
+        &["origin/remote-feature"],
+    );
+}
+
+#[test]
+fn test_switch_remote_ref_not_found() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+
+    snapshot_switch("switch_remote_ref_not_found", &repo, &["origin/missing"]);
+}
+
+#[test]
+fn test_switch_branch_on_multiple_remotes() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    repo.setup_custom_remote("upstream", "main");
+    push_remote_commit(&repo, "refs/heads/shared");
+    repo.git_command(&["push", "-q", "upstream", "origin/shared:refs/heads/shared"])
+        .output()
+        .unwrap();
+
+    snapshot_switch("switch_branch_on_multiple_remotes", &repo, &["shared"]);
+
+    // Naming the remote picks it
+    snapshot_switch(
+        "switch_branch_on_multiple_remotes_explicit",
+        &repo,
+        &["upstream/shared"],
+    );
+    assert_eq!(
+        git_output(&repo, &["rev-parse", "--abbrev-ref", "shared@{upstream}"]),
+        "upstream/shared"
+    );
+}
+
+#[test]
+fn test_switch_branch_on_multiple_remotes_default_remote() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    repo.setup_custom_remote("upstream", "main");
+    push_remote_commit(&repo, "refs/heads/shared");
+    repo.git_command(&["push", "-q", "upstream", "origin/shared:refs/heads/shared"])
+        .output()
+        .unwrap();
+    repo.git_command(&["config", "checkout.defaultRemote", "upstream"])
+        .output()
+        .unwrap();
+
+    snapshot_switch(
+        "switch_branch_on_multiple_remotes_default",
+        &repo,
+        &["shared"],
+    );
+    assert_eq!(
+        git_output(&repo, &["rev-parse", "--abbrev-ref", "shared@{upstream}"]),
+        "upstream/shared"
+    );
+}
+
+#[test]
+fn test_switch_fetch() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/heads/fresh");
+    // As if someone else pushed it: the remote-tracking ref isn't there yet
+    repo.git_command(&["update-ref", "-d", "refs/remotes/origin/fresh"])
+        .output()
+        .unwrap();
+
+    snapshot_switch("switch_fetch", &repo, &["--fetch", "fresh"]);
+
+    assert_eq!(
+        git_output(&repo, &["rev-parse", "--abbrev-ref", "fresh@{upstream}"]),
+        "origin/fresh"
+    );
+}
+
+#[test]
+fn test_switch_fetch_remote_ref() {
+    let mut repo = setup_switch_repo();
+    repo.setup_remote("main");
+    push_remote_commit(&repo, "refs/heads/fresh");
+    repo.git_command(&["update-ref", "-d", "refs/remotes/origin/fresh"])
+        .output()
+        .unwrap();
+
+    snapshot_switch(
+        "switch_fetch_remote_ref",
+        &repo,
+        &["--fetch", "origin/fresh"],
+    );
+}
@@ -405,6 +405,141 @@ check = "echo 'USER_PRE_MERGE' > user_premerge_marker.txt"
     );
 }
 
+// ============================================================================
+// User Pre-Push Hook Tests
+// ============================================================================
+
+/// Feature worktree with one commit ahead of main
+fn setup_feature_ahead(repo: &mut TestRepo) -> std::path::PathBuf {
+    let feature_wt = repo.add_worktree("feature");
+    fs::write(feature_wt.join("feature.txt"), "feature content").unwrap();
+
+    let mut cmd = Command::new("git");
+    repo.configure_git_cmd(&mut cmd);
+    cmd.current_dir(&feature_wt)
+        .args(["add", "."])
+        .output()
+        .unwrap();
+
+    let mut cmd = Command::new("git");
+    repo.configure_git_cmd(&mut cmd);
+    cmd.current_dir(&feature_wt)
+        .args(["commit", "-m", "Add feature"])
+        .output()
+        .unwrap();
+
+    feature_wt
+}
+
+fn rev_parse(repo: &TestRepo, rev: &str) -> String {
+    let output = repo.git_command(&["rev-parse", rev]).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn test_user_pre_push_hook_template_variables() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = setup_feature_ahead(&mut repo);
+    let main_sha = rev_parse(&repo, "main");
+    let feature_sha = rev_parse(&repo, "feature");
+
+    repo.write_test_config(
+        r#"[pre-push]
+vars = "echo 'target={{ target }} count={{ commit_count }} range={{ commit_range }}' > pre_push.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "step", &["push", "main"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let contents = fs::read_to_string(feature_wt.join("pre_push.txt")).unwrap();
+    assert_eq!(
+        contents.trim(),
+        format!("target=main count=1 range={main_sha}..{feature_sha}")
+    );
+    assert_eq!(rev_parse(&repo, "main"), feature_sha);
+}
+
+#[test]
+fn test_user_pre_push_failure_blocks_push() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = setup_feature_ahead(&mut repo);
+    let main_sha = rev_parse(&repo, "main");
+
+    repo.write_test_config(
+        r#"[pre-push]
+check = "exit 1"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["push", "main"], Some(&feature_wt));
+        assert_cmd_snapshot!("user_pre_push_failure", cmd);
+    });
+
+    // The target branch wasn't updated
+    assert_eq!(rev_parse(&repo, "main"), main_sha);
+}
+
+#[test]
+fn test_user_pre_push_skipped_with_no_verify() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = setup_feature_ahead(&mut repo);
+
+    repo.write_test_config(
+        r#"[pre-push]
+check = "exit 1"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["push", "main", "--no-verify"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success());
+    assert_eq!(rev_parse(&repo, "main"), rev_parse(&repo, "feature"));
+}
+
+#[test]
+fn test_user_pre_push_runs_during_merge() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.setup_remote("main");
+    let feature_wt = setup_feature_ahead(&mut repo);
+
+    // Runs after pre-merge, while main still points at the old commit
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+check = "echo pre-merge > hooks.txt"
+
+[pre-push]
+check = "test $(git rev-list --count {{ commit_range }}) = {{ commit_count }} && echo 'pre-push {{ target }}' >> hooks.txt"
+"#,
+    );
+
+    snapshot_merge(
+        "user_pre_push_during_merge",
+        &repo,
+        &["main", "--force", "--no-remove"],
+        Some(&feature_wt),
+    );
+
+    let contents = fs::read_to_string(feature_wt.join("hooks.txt")).unwrap();
+    assert_eq!(contents, "pre-merge\npre-push main\n");
+}
+
 // ============================================================================
 // User Post-Merge Hook Tests
 // ============================================================================
@@ -572,6 +707,293 @@ block = "exit 1"
     );
 }
 
+#[test]
+fn test_user_post_remove_hook_executes() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = repo.add_worktree("feature");
+
+    // Runs in the main worktree, after the worktree directory is gone
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-remove]
+cleanup = "test ! -e {{ worktree }} && echo 'removed {{ branch }} at {{ worktree_name }}' > post_remove.txt"
+"#,
+    );
+
+    snapshot_remove(
+        "user_post_remove_executes",
+        &repo,
+        &["feature", "--force-delete", "--no-background"],
+        Some(repo.root_path()),
+    );
+
+    assert!(!feature_wt.exists());
+    let contents = fs::read_to_string(repo.root_path().join("post_remove.txt")).unwrap();
+    assert_eq!(contents.trim(), "removed feature at repo.feature");
+}
+
+#[test]
+fn test_user_post_remove_hook_sanitizes_branch() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let _feature_wt = repo.add_worktree("feature/auth");
+
+    // Same `{{ branch }}` as every other hook: safe for file names
+    repo.write_test_config(
+        r#"[post-remove]
+cleanup = "echo '{{ branch }}' > post_remove.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["feature/auth", "--force-delete", "--no-background"],
+        Some(repo.root_path()),
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success());
+    let contents = fs::read_to_string(repo.root_path().join("post_remove.txt")).unwrap();
+    assert_eq!(contents.trim(), "feature-auth");
+}
+
+#[test]
+fn test_user_post_remove_hook_waits_for_background_removal() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let _feature_wt = repo.add_worktree("feature");
+
+    repo.write_test_config(
+        r#"[post-remove]
+cleanup = "test ! -e {{ worktree }} && echo 'POST_REMOVE_RAN' > post_remove.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["feature", "--force-delete"],
+        Some(repo.root_path()),
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success());
+
+    let marker = repo.root_path().join("post_remove.txt");
+    wait_for_file_content(&marker, Duration::from_secs(10));
+    assert_eq!(
+        fs::read_to_string(&marker).unwrap().trim(),
+        "POST_REMOVE_RAN"
+    );
+}
+
+#[test]
+fn test_user_post_remove_skipped_with_no_verify() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = repo.add_worktree("feature");
+
+    repo.write_test_config(
+        r#"[post-remove]
+cleanup = "echo 'POST_REMOVE_RAN' > post_remove.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "remove",
+        &[
+            "feature",
+            "--force-delete",
+            "--no-background",
+            "--no-verify",
+        ],
+        Some(repo.root_path()),
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success());
+    assert!(!feature_wt.exists());
+    assert!(!repo.root_path().join("post_remove.txt").exists());
+}
+
+// ============================================================================
+// User Pre-Create Hook Tests
+// ============================================================================
+
+#[test]
+fn test_user_pre_create_hook_blocks_creation() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-create]
+naming = "echo {{ branch }} | grep -q '^feature-'"
+"#,
+    );
+
+    snapshot_switch("user_pre_create_blocks", &repo, &["--create", "bad-name"]);
+
+    // git worktree add never ran: neither the worktree nor the branch exists
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.bad-name");
+    assert!(!worktree_path.exists());
+    let output = repo
+        .git_command(&["branch", "--list", "bad-name"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).trim().is_empty());
+
+    // A name matching the rule goes through
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature-ok"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(
+        repo.root_path()
+            .parent()
+            .unwrap()
+            .join("repo.feature-ok")
+            .exists()
+    );
+}
+
+#[test]
+fn test_user_pre_create_hook_template_variables() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // Runs in the current worktree, since the new one doesn't exist yet
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-create]
+vars = "echo 'branch={{ branch }} name={{ worktree_name }} base={{ base }}' > pre_create.txt && test ! -e {{ worktree }}"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let contents = fs::read_to_string(repo.root_path().join("pre_create.txt")).unwrap();
+    assert_eq!(
+        contents.trim(),
+        "branch=feature name=repo.feature base=main"
+    );
+}
+
+// ============================================================================
+// User Switch Hook Tests
+// ============================================================================
+
+#[test]
+fn test_user_switch_hooks_execute() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = repo.add_worktree("feature");
+
+    // pre-switch runs in the worktree being left, post-switch in the one switched to
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-switch]
+leave = "echo 'from={{ previous_branch }} to={{ new_branch }} in={{ branch }}' > pre_switch.txt"
+
+[post-switch]
+enter = "echo 'from={{ previous_branch }} to={{ new_branch }} in={{ branch }}' > post_switch.txt"
+"#,
+    );
+
+    snapshot_switch("user_switch_hooks_execute", &repo, &["feature"]);
+
+    let pre = fs::read_to_string(repo.root_path().join("pre_switch.txt")).unwrap();
+    assert_eq!(pre.trim(), "from=main to=feature in=main");
+    let post = fs::read_to_string(feature_wt.join("post_switch.txt")).unwrap();
+    assert_eq!(post.trim(), "from=main to=feature in=feature");
+}
+
+#[test]
+fn test_user_switch_hooks_worktree_variables() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    let feature_wt = repo.add_worktree("feature");
+
+    repo.write_test_config(
+        r#"[post-switch]
+paths = "echo '{{ previous_worktree }}' > paths.txt && echo '{{ new_worktree }}' >> paths.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let paths = fs::read_to_string(feature_wt.join("paths.txt")).unwrap();
+    let paths: Vec<&str> = paths.lines().collect();
+    assert_eq!(
+        fs::canonicalize(paths[0]).unwrap(),
+        fs::canonicalize(repo.root_path()).unwrap()
+    );
+    assert_eq!(
+        fs::canonicalize(paths[1]).unwrap(),
+        fs::canonicalize(&feature_wt).unwrap()
+    );
+}
+
+#[test]
+fn test_user_pre_switch_failure_blocks_switch() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-switch]
+block = "exit 1"
+
+[post-switch]
+enter = "echo 'POST_SWITCH_RAN' > post_switch.txt"
+"#,
+    );
+
+    snapshot_switch("user_pre_switch_failure", &repo, &["--create", "feature"]);
+
+    // The switch was aborted before the worktree was created
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(
+        !worktree_path.exists(),
+        "Worktree should not be created when pre-switch hook fails"
+    );
+}
+
+#[test]
+fn test_user_switch_hooks_skipped_when_already_at_worktree() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"[pre-switch]
+block = "exit 1"
+
+[post-switch]
+enter = "echo 'POST_SWITCH_RAN' > post_switch.txt"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["main"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!repo.root_path().join("post_switch.txt").exists());
+}
+
 // ============================================================================
 // User Pre-Commit Hook Tests
 // ============================================================================
@@ -727,3 +1149,499 @@ approved-commands = ["echo 'PROJECT_POST_START' > project_bg.txt"]
         "Project post-start should have run"
     );
 }
+
+// ============================================================================
+// Parallel Hook Tests
+// ============================================================================
+
+/// Helper for `wt hook <type>` snapshots
+fn snapshot_hook(test_name: &str, repo: &TestRepo, args: &[&str]) {
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "hook", args, None);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[test]
+fn test_user_pre_merge_parallel_runs_concurrently() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // Each command waits for the other's marker, so they only both succeed
+    // when running at the same time
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+parallel = true
+first = "touch first.txt; for i in $(seq 50); do [ -f second.txt ] && break; sleep 0.1; done; [ -f second.txt ] && echo 'FIRST_SAW_SECOND'"
+second = "touch second.txt; for i in $(seq 50); do [ -f first.txt ] && break; sleep 0.1; done; [ -f first.txt ] && echo 'SECOND_SAW_FIRST'"
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_parallel", &repo, &["pre-merge"]);
+}
+
+#[test]
+fn test_user_pre_merge_parallel_per_command() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // Only adjacent parallel commands form a batch; `after` waits for both
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+lint = { command = "echo 'LINT'", parallel = true }
+test = { command = "echo 'TEST'", parallel = true }
+after = "echo 'AFTER'"
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_parallel_per_command", &repo, &["pre-merge"]);
+}
+
+#[test]
+fn test_user_pre_merge_parallel_failure() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // The failing command is reported fail-fast; `after` never runs
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+ok = { command = "echo 'OK_RAN'", parallel = true }
+broken = { command = "echo 'BROKEN_OUTPUT'; exit 3", parallel = true }
+after = "echo 'AFTER_RAN' > after.txt"
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_parallel_failure", &repo, &["pre-merge"]);
+
+    assert!(
+        !repo.root_path().join("after.txt").exists(),
+        "Commands after a failed parallel batch should not run"
+    );
+}
+
+#[test]
+fn test_user_pre_merge_parallel_background_process_does_not_hang() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // The backgrounded sleep inherits the capture pipes and outlives the hook
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+parallel = true
+server = "sleep 1000 & echo $! > server.pid; echo 'SERVER_STARTED'"
+lint = "echo 'LINT'"
+"#,
+    );
+
+    snapshot_hook(
+        "user_pre_merge_parallel_background_process",
+        &repo,
+        &["pre-merge"],
+    );
+
+    let pid = std::fs::read_to_string(repo.root_path().join("server.pid")).unwrap();
+    let _ = std::process::Command::new("kill").arg(pid.trim()).status();
+}
+
+// ============================================================================
+// Hook Dependency (`needs`) Tests
+// ============================================================================
+
+#[test]
+fn test_user_pre_merge_needs_orders_commands() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // `build` is declared first but needs `install`
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+build = { command = "[ -f installed.txt ] && echo 'BUILD_AFTER_INSTALL'", needs = ["install"] }
+install = "sleep 0.2; touch installed.txt; echo 'INSTALL'"
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_needs", &repo, &["pre-merge"]);
+}
+
+#[test]
+fn test_user_pre_merge_needs_failure_skips_dependents() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+install = "echo 'INSTALL_FAILED'; exit 2"
+build = { command = "echo 'BUILD_RAN' > build.txt", needs = ["install"] }
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_needs_failure", &repo, &["pre-merge"]);
+
+    assert!(
+        !repo.root_path().join("build.txt").exists(),
+        "A command should not run when a command it needs failed"
+    );
+}
+
+#[test]
+fn test_user_post_create_needs_failure_warns_skipped() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // post-create is best-effort: the failure and the skip are both warnings
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-create]
+install = "exit 1"
+build = { command = "echo 'BUILD_RAN'", needs = ["install"] }
+"#,
+    );
+
+    snapshot_switch(
+        "user_post_create_needs_failure",
+        &repo,
+        &["--create", "feature"],
+    );
+}
+
+#[test]
+fn test_user_post_start_needs_waits_in_background() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-start]
+serve = { command = "cat installed.txt > served.txt", needs = ["install"] }
+install = "sleep 0.5; echo 'INSTALLED' > installed.txt"
+"#,
+    );
+
+    snapshot_switch("user_post_start_needs", &repo, &["--create", "feature"]);
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    wait_for_file_content(&worktree_path.join("served.txt"), Duration::from_secs(5));
+    let contents = fs::read_to_string(worktree_path.join("served.txt")).unwrap();
+    assert_eq!(contents.trim(), "INSTALLED");
+}
+
+#[test]
+fn test_user_post_start_needs_dependency_killed() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // `install` kills its own process group, so it never writes a completion marker
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-start]
+serve = { command = "echo 'SERVED' > served.txt", needs = ["install"] }
+install = "kill -9 0"
+"#,
+    );
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    // `serve` gives up on it instead of waiting forever
+    let log_dir = repo.root_path().join(".git/wt-logs");
+    let done = log_dir.join("feature-user-post-start-serve.done");
+    wait_for_file_content(&done, Duration::from_secs(10));
+    assert_eq!(fs::read_to_string(&done).unwrap().trim(), "1");
+    let log = fs::read_to_string(log_dir.join("feature-user-post-start-serve.log")).unwrap();
+    assert!(
+        log.contains("Skipped: user-post-start-install stopped before finishing"),
+        "{log}"
+    );
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(!worktree_path.join("served.txt").exists());
+}
+
+#[test]
+fn test_needs_unknown_command_is_config_error() {
+    let repo = TestRepo::new();
+    repo.write_project_config(
+        r#"[pre-merge]
+build = { command = "cargo build", needs = ["instal"] }
+install = "npm ci"
+"#,
+    );
+    repo.commit("Add project config");
+
+    snapshot_hook("needs_unknown_command", &repo, &["pre-merge", "--force"]);
+}
+
+#[test]
+fn test_needs_cycle_is_config_error() {
+    let repo = TestRepo::new();
+    repo.write_project_config(
+        r#"[pre-merge]
+a = { command = "echo a", needs = ["b"] }
+b = { command = "echo b", needs = ["a"] }
+"#,
+    );
+    repo.commit("Add project config");
+
+    snapshot_hook("needs_cycle", &repo, &["pre-merge", "--force"]);
+}
+
+// ============================================================================
+// Timeout and Retry Tests
+// ============================================================================
+
+#[test]
+fn test_user_pre_merge_timeout_aborts() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // The background child must die with the command, or it would write the marker
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+hang = { command = "(sleep 2; touch leaked.txt) & sleep 30", timeout = 1 }
+after = "echo 'AFTER_RAN' > after.txt"
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_timeout", &repo, &["pre-merge"]);
+
+    thread::sleep(Duration::from_secs(2) + SLEEP_FOR_ABSENCE_CHECK);
+    assert!(
+        !repo.root_path().join("leaked.txt").exists(),
+        "Processes started by a timed-out command should be killed"
+    );
+    assert!(
+        !repo.root_path().join("after.txt").exists(),
+        "Commands after a timed-out fail-fast command should not run"
+    );
+}
+
+#[test]
+fn test_user_post_create_timeout_warns() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-create]
+hang = { command = "sleep 30", timeout = "1s" }
+after = "echo 'AFTER_RAN' > after.txt"
+"#,
+    );
+
+    snapshot_switch("user_post_create_timeout", &repo, &["--create", "feature"]);
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(
+        worktree_path.join("after.txt").exists(),
+        "post-create should continue after a timeout"
+    );
+}
+
+#[test]
+fn test_signal_after_timed_hook_stops_worktrunk() {
+    use std::os::unix::process::ExitStatusExt;
+
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // `quick` finishes within its timeout; `slow` (no timeout) runs in worktrunk's
+    // own process group, so only worktrunk gets the signal
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+quick = { command = "true", timeout = 10 }
+slow = "touch slow.txt; sleep 5"
+"#,
+    );
+
+    let mut child = make_snapshot_cmd(&repo, "hook", &["pre-merge"], None)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    wait_for_file(&repo.root_path().join("slow.txt"), Duration::from_secs(5));
+
+    Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    let start = std::time::Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(3),
+            "SIGTERM should stop worktrunk after a timed hook has finished"
+        );
+        thread::sleep(Duration::from_millis(20));
+    };
+    assert_eq!(status.signal(), Some(15), "{status:?}");
+}
+
+#[test]
+fn test_user_pre_merge_retries() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    // Fails on the first two attempts, succeeds on the third
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+flaky = { command = "echo x >> attempts.txt; [ $(wc -l < attempts.txt) -ge 3 ]", retries = 2 }
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_retries", &repo, &["pre-merge"]);
+
+    let attempts = fs::read_to_string(repo.root_path().join("attempts.txt")).unwrap();
+    assert_eq!(attempts.lines().count(), 3);
+}
+
+#[test]
+fn test_user_pre_merge_retries_exhausted() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+broken = { command = "echo x >> attempts.txt; exit 1", retries = 1 }
+"#,
+    );
+
+    snapshot_hook("user_pre_merge_retries_exhausted", &repo, &["pre-merge"]);
+
+    let attempts = fs::read_to_string(repo.root_path().join("attempts.txt")).unwrap();
+    assert_eq!(attempts.lines().count(), 2);
+}
+
+// ============================================================================
+// Conditional Hook (`if`) Tests
+// ============================================================================
+
+#[test]
+fn test_user_pre_merge_condition_on_changed_files() {
+    let mut repo = TestRepo::new();
+    repo.commit("Initial commit");
+    repo.setup_remote("main");
+
+    // Feature branch adds a migration
+    let feature_wt = repo.add_worktree("feature");
+    fs::create_dir_all(feature_wt.join("migrations")).unwrap();
+    fs::write(feature_wt.join("migrations/001.sql"), "CREATE TABLE t;").unwrap();
+
+    let mut cmd = Command::new("git");
+    repo.configure_git_cmd(&mut cmd);
+    cmd.current_dir(&feature_wt)
+        .args(["add", "."])
+        .output()
+        .unwrap();
+
+    let mut cmd = Command::new("git");
+    repo.configure_git_cmd(&mut cmd);
+    cmd.current_dir(&feature_wt)
+        .args(["commit", "-m", "Add migration"])
+        .output()
+        .unwrap();
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+migrate = { command = "echo 'MIGRATED' > migrated.txt", if = "changed_files | select('startingwith', 'migrations/')" }
+exact = { command = "echo 'EXACT' > exact.txt", if = "'migrations/001.sql' in changed_files" }
+substring = { command = "echo 'SUBSTRING' > substring.txt", if = "'001' in changed_files" }
+docs = { command = "echo 'DOCS' > docs.txt", if = "changed_files | select('startingwith', 'docs/')" }
+"#,
+    );
+
+    snapshot_merge(
+        "user_pre_merge_condition_changed_files",
+        &repo,
+        &["main", "--force", "--no-remove"],
+        Some(&feature_wt),
+    );
+
+    assert!(
+        feature_wt.join("migrated.txt").exists(),
+        "Command whose condition holds should run"
+    );
+    assert!(feature_wt.join("exact.txt").exists());
+    assert!(
+        !feature_wt.join("substring.txt").exists(),
+        "`in` checks for a whole path"
+    );
+    assert!(
+        !feature_wt.join("docs.txt").exists(),
+        "Command whose condition is false should be skipped"
+    );
+}
+
+#[test]
+fn test_user_hook_condition_on_branch() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[post-create]
+deploy = { command = "echo 'DEPLOY' > deploy.txt", if = "branch is not startingwith('hotfix/')" }
+notify = { command = "echo 'NOTIFY' > notify.txt", if = "branch is startingwith('hotfix/')" }
+"#,
+    );
+
+    snapshot_switch(
+        "user_hook_condition_on_branch",
+        &repo,
+        &["--create", "hotfix/urgent"],
+    );
+
+    let worktree_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("repo.hotfix-urgent");
+    assert!(!worktree_path.join("deploy.txt").exists());
+    assert!(worktree_path.join("notify.txt").exists());
+}
+
+#[test]
+fn test_user_hook_condition_syntax_error() {
+    let repo = TestRepo::new();
+    repo.commit("Initial commit");
+
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+
+[pre-merge]
+broken = { command = "echo hi", if = "branch ==" }
+"#,
+    );
+
+    snapshot_hook("user_hook_condition_syntax_error", &repo, &["pre-merge"]);
+}